@@ -1,11 +1,14 @@
 use std::ffi::OsString;
+use std::str::FromStr;
 
-use anyhow::Context as _;
+use anyhow::{anyhow, Context as _};
 
 use ethers::prelude::Chain;
 use ethers::signers::{HDPath, Ledger};
 
-use radicle_common::args::{Args, Error, Help};
+use radicle_common::args::{self, Args, Error, Help};
+use radicle_common::ethereum;
+use radicle_common::ethereum::coins_bip32::path::DerivationPath;
 use radicle_common::tokio;
 use radicle_terminal as term;
 
@@ -16,19 +19,87 @@ pub const HELP: Help = Help {
     usage: r#"
 Usage
 
-    rad account [--testnet]
+    rad account [--chain <name|id>] [--hdpath <scheme>] [--count <n>]
 
 Options
 
-    --testnet  Use the Ethereum "Rinkeby" testnet (default: false)
+    --chain <name|id>   Chain to use: mainnet, goerli, sepolia or a chain id (default: mainnet)
+    --testnet           Use the deprecated Ethereum "Rinkeby" testnet (default: false)
+    --hdpath <scheme>   Derivation scheme of the listed addresses (default: ledger-live)
+    --count <n>         Number of addresses to list (default: 9)
+
+Derivation schemes
+
+    ledger-live         m/44'/60'/<n>'/0/0
+    legacy              m/44'/60'/0'/<n>
+    <path>              A custom derivation path, eg. m/44'/60'/0'/0, to which /<n> is appended
 "#,
 };
 
+/// Default number of addresses to list.
+pub const DEFAULT_COUNT: usize = 9;
+
+/// Account derivation scheme.
+#[derive(Debug, Clone)]
+pub enum Scheme {
+    /// Derivation scheme used by Ledger Live.
+    LedgerLive,
+    /// Legacy derivation scheme, used by older Ledger software and MyEtherWallet.
+    Legacy,
+    /// Custom derivation path, to which the account index is appended.
+    Custom(DerivationPath),
+}
+
+impl Default for Scheme {
+    fn default() -> Self {
+        Self::LedgerLive
+    }
+}
+
+impl Scheme {
+    /// Get the derivation path of the account at the given index.
+    pub fn path(&self, index: usize) -> HDPath {
+        match self {
+            Self::LedgerLive => HDPath::LedgerLive(index),
+            Self::Legacy => HDPath::Legacy(index),
+            Self::Custom(path) => HDPath::Other(format!("{}/{}", path.derivation_string(), index)),
+        }
+    }
+}
+
+impl FromStr for Scheme {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ledger-live" => Ok(Self::LedgerLive),
+            "legacy" => Ok(Self::Legacy),
+            path => DerivationPath::from_str(path)
+                .map(Self::Custom)
+                .map_err(|_| anyhow!("invalid derivation scheme or path '{}'", path)),
+        }
+    }
+}
+
 /// Work with Ethereum accounts.
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct Options {
-    /// Use the Ethereum "Rinkeby" testnet (default: false)
-    pub testnet: bool,
+    /// Chain id to use (default: mainnet).
+    pub chain: u64,
+    /// Derivation scheme of the listed addresses.
+    pub scheme: Scheme,
+    /// Number of addresses to list.
+    pub count: usize,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            chain: ethereum::MAINNET_CHAIN_ID,
+            scheme: Scheme::default(),
+            count: DEFAULT_COUNT,
+        }
+    }
 }
 
 impl Args for Options {
@@ -36,12 +107,33 @@ impl Args for Options {
         use lexopt::prelude::*;
 
         let mut parser = lexopt::Parser::from_args(args);
-        let mut testnet = false;
+        let mut options = Options::default();
 
         while let Some(arg) = parser.next()? {
             match arg {
                 Long("testnet") => {
-                    testnet = true;
+                    options.chain = Chain::Rinkeby.into();
+                }
+                Long("chain") => {
+                    let value = parser.value()?;
+                    let value = value
+                        .to_str()
+                        .ok_or_else(|| anyhow!("the value specified for '--chain' is not UTF-8"))?;
+
+                    options.chain = ethereum::parse_chain(value)?;
+                }
+                Long("hdpath") => {
+                    let value = parser.value()?;
+                    let value = value.to_str().ok_or_else(|| {
+                        anyhow!("the value specified for '--hdpath' is not UTF-8")
+                    })?;
+
+                    options.scheme = Scheme::from_str(value)?;
+                }
+                Long("count") => {
+                    let value = parser.value()?;
+
+                    options.count = args::parse_value("count", value)?;
                 }
                 Long("help") => {
                     return Err(Error::Help.into());
@@ -50,7 +142,7 @@ impl Args for Options {
             }
         }
 
-        Ok((Options { testnet }, vec![]))
+        Ok((options, vec![]))
     }
 }
 
@@ -58,18 +150,12 @@ pub fn run(opts: Options, _ctx: impl term::Context) -> anyhow::Result<()> {
     let rt = tokio::runtime::Runtime::new()?;
 
     rt.block_on(async {
-        let chain_id: u64 = if opts.testnet {
-            Chain::Rinkeby.into()
-        } else {
-            Chain::Mainnet.into()
-        };
-
-        let ledger = Ledger::new(HDPath::LedgerLive(0), chain_id)
+        let ledger = Ledger::new(opts.scheme.path(0), opts.chain)
             .await
             .context("couldn't connect to Ledger device")?;
 
-        for i in 0..=8 {
-            let path = HDPath::LedgerLive(i);
+        for i in 0..opts.count {
+            let path = opts.scheme.path(i);
 
             println!(
                 "{} {:?}",
@@ -16,6 +16,8 @@ use ethers::{
     prelude::{builders::ContractCall, Bytes, JsonRpcClient, Signer, SignerMiddleware, U256},
     providers::{Http, Provider},
     signers::{HDPath, Ledger, LocalWallet},
+    types::TxHash,
+    utils::to_checksum,
 };
 
 use ethers::prelude::Middleware;
@@ -40,25 +42,61 @@ pub struct Options {
     pub ledger_hdpath: Option<DerivationPath>,
     /// Keystore file containing encrypted private key (default: none).
     pub keystore: Option<PathBuf>,
+    /// Safe Transaction Service URL, overriding the network default.
+    pub safe_service_url: Option<String>,
+    /// Contract owner of the Safe to propose the anchor on behalf of (EIP-1271).
+    pub contract_owner: Option<Address>,
     /// Execute a dry run.
     pub dry_run: bool,
+    /// Only verify that the commit is anchored, without sending a transaction.
+    pub verify: bool,
 }
 
 const PROJECT_COMMIT_ANCHOR: u32 = 0x0;
 const ORG_ABI: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/abis/OrgV1.json"));
+const SAFE_ABI: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/abis/Safe.json"));
 
 /// Ethereum network.
 #[derive(Debug)]
 enum Network {
     Homestead,
-    Rinkeby,
+    Goerli,
+    Sepolia,
+    Gnosis,
+    Polygon,
 }
 
 impl Network {
+    /// Default Safe Transaction Service URL of the network.
     const fn safe_transaction_url(&self) -> &'static str {
         match self {
-            Self::Homestead => "https://safe-transaction.gnosis.io",
-            Self::Rinkeby => "https://safe-transaction.rinkeby.gnosis.io",
+            Self::Homestead => "https://safe-transaction-mainnet.safe.global/api",
+            Self::Goerli => "https://safe-transaction-goerli.safe.global/api",
+            Self::Sepolia => "https://safe-transaction-sepolia.safe.global/api",
+            Self::Gnosis => "https://safe-transaction-gnosis-chain.safe.global/api",
+            Self::Polygon => "https://safe-transaction-polygon.safe.global/api",
+        }
+    }
+
+    /// Block explorer URL of the network.
+    const fn explorer_url(&self) -> &'static str {
+        match self {
+            Self::Homestead => "https://etherscan.io",
+            Self::Goerli => "https://goerli.etherscan.io",
+            Self::Sepolia => "https://sepolia.etherscan.io",
+            Self::Gnosis => "https://gnosisscan.io",
+            Self::Polygon => "https://polygonscan.com",
+        }
+    }
+
+    /// Short name of the network, as used by the Safe web app.
+    const fn short_name(&self) -> &'static str {
+        match self {
+            Self::Homestead => "eth",
+            Self::Goerli => "gor",
+            Self::Sepolia => "sep",
+            Self::Gnosis => "gno",
+            Self::Polygon => "matic",
         }
     }
 }
@@ -69,7 +107,10 @@ impl TryFrom<u64> for Network {
     fn try_from(other: u64) -> Result<Self, ()> {
         match other {
             1 => Ok(Self::Homestead),
-            4 => Ok(Self::Rinkeby),
+            5 => Ok(Self::Goerli),
+            100 => Ok(Self::Gnosis),
+            137 => Ok(Self::Polygon),
+            11155111 => Ok(Self::Sepolia),
             _ => Err(()),
         }
     }
@@ -102,6 +143,10 @@ pub async fn run(opts: Options) -> anyhow::Result<()> {
         Provider::<Http>::try_from(opts.rpc_url.as_str()).context("JSON-RPC URL parsing failed")?;
     let chain_id = provider.get_chainid().await?.as_u64();
 
+    if opts.verify {
+        return verify(opts, provider).await;
+    }
+
     if let Some(keypath) = &opts.keystore {
         use colored::*;
 
@@ -128,6 +173,80 @@ pub async fn run(opts: Options) -> anyhow::Result<()> {
     }
 }
 
+/// The project id, as a `bytes32`.
+fn project_id(project: &Urn) -> [u8; 32] {
+    let bytes = project.id.as_bytes();
+    let mut padded = vec![0; 12];
+
+    padded.extend(bytes);
+    padded.try_into().unwrap()
+}
+
+/// The anchor hash of a commit, as a `bytes` in multihash format.
+fn commit_hash(commit: &str) -> anyhow::Result<Bytes> {
+    if commit.len() != 40 {
+        bail!("Invalid SHA-1 commit specified");
+    }
+    let bytes = (0..commit.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&commit[i..i + 2], 16))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let digest: Sha1Digest<multihash::U20> = Sha1Digest::wrap(&bytes)?;
+    let commit = Code::multihash_from_digest(&digest);
+
+    Ok(commit.to_bytes().into())
+}
+
+/// Check that the given commit is the current anchor of the project, without
+/// sending any transaction.
+async fn verify<P: 'static + JsonRpcClient + Clone>(
+    opts: Options,
+    provider: Provider<P>,
+) -> anyhow::Result<()> {
+    let abi: Abi = serde_json::from_str(ORG_ABI)?;
+    let project = opts.project;
+    let commit = opts.commit;
+    let id = project_id(&project);
+    let hash = commit_hash(&commit)?;
+
+    log::info!("Verifying..");
+    log::info!("Radicle ID {}", project);
+    log::info!("Org {:?}", opts.org);
+    log::info!("Anchor hash {}", commit);
+
+    let contract = Contract::new(opts.org, abi, provider);
+    let (tag, anchored): (u32, Bytes) = contract.method("anchors", id)?.call().await?;
+
+    if anchored.is_empty() {
+        bail!("project {} is not anchored in org {:?}", project, opts.org);
+    }
+    if tag != PROJECT_COMMIT_ANCHOR {
+        bail!(
+            "project {} has an anchor of unknown type ({:#x})",
+            project,
+            tag
+        );
+    }
+    if anchored != hash {
+        // Skip the two-byte multihash prefix to get the commit hash.
+        let current = anchored
+            .iter()
+            .skip(2)
+            .map(|b| format!("{:02x}", b))
+            .collect::<String>();
+
+        bail!(
+            "project {} is anchored at a different commit ({})",
+            project,
+            current
+        );
+    }
+    println!("Project {} is anchored at commit {}", project, commit);
+
+    Ok(())
+}
+
 async fn anchor<P: 'static + JsonRpcClient + Clone, S: 'static + Signer>(
     opts: Options,
     provider: Provider<P>,
@@ -137,8 +256,15 @@ async fn anchor<P: 'static + JsonRpcClient + Clone, S: 'static + Signer>(
     let project = opts.project;
     let commit = opts.commit;
     let chain_id = signer.chain_id();
-    let network =
-        Network::try_from(chain_id).map_err(|_| anyhow!("unsupported chain id '{}'", chain_id))?;
+    let network = Network::try_from(chain_id).ok();
+    let safe_service_url = match (&opts.safe_service_url, &network) {
+        (Some(url), _) => url.as_str(),
+        (None, Some(network)) => network.safe_transaction_url(),
+        (None, None) => bail!(
+            "unsupported chain id '{}', specify a service URL with '--safe-service-url'",
+            chain_id
+        ),
+    };
 
     log::info!("Anchoring..");
     log::info!("Chain ID {} ({:?})", chain_id, network);
@@ -152,7 +278,9 @@ async fn anchor<P: 'static + JsonRpcClient + Clone, S: 'static + Signer>(
     let org_owner: Address = contract.method("owner", ())?.call().await?;
     log::info!("Org owner {:#?}", org_owner);
 
-    let safe_client = safe::Client::new(network.safe_transaction_url());
+    log::info!("Safe Transaction Service {}", safe_service_url);
+
+    let safe_client = safe::Client::new(safe_service_url);
     let safe = match safe_client.get_safe(org_owner) {
         Ok(safe) => Some(safe),
         Err(err) if err.is_not_found() => None,
@@ -161,31 +289,9 @@ async fn anchor<P: 'static + JsonRpcClient + Clone, S: 'static + Signer>(
         }
     };
 
-    // The project id, as a `bytes32`.
-    let id: [u8; 32] = {
-        let bytes = project.id.as_bytes();
-        let mut padded = vec![0; 12];
-
-        padded.extend(bytes);
-        padded.try_into().unwrap()
-    };
-    // The anchor tag as a `uint32`.
+    let id = project_id(&project);
     let tag: u32 = PROJECT_COMMIT_ANCHOR;
-    // The anchor hash as a `bytes` in multihash format.
-    let hash: Bytes = {
-        if commit.len() != 40 {
-            bail!("Invalid SHA-1 commit specified");
-        }
-        let bytes = (0..commit.len())
-            .step_by(2)
-            .map(|i| u8::from_str_radix(&commit[i..i + 2], 16))
-            .collect::<Result<Vec<_>, _>>()?;
-
-        let digest: Sha1Digest<multihash::U20> = Sha1Digest::wrap(&bytes)?;
-        let commit = Code::multihash_from_digest(&digest);
-
-        commit.to_bytes().into()
-    };
+    let hash = commit_hash(&commit)?;
 
     if opts.dry_run {
         return Ok(());
@@ -197,46 +303,108 @@ async fn anchor<P: 'static + JsonRpcClient + Clone, S: 'static + Signer>(
         let call = contract.method::<_, ()>("anchor", (id, tag, hash))?;
         let data = call.calldata().unwrap();
 
-        anchor_safe(opts.org, data, &safe, &signer).await
+        let origin = format!("radicle-cli anchor {}", project);
+        let safe_tx_hash = anchor_safe(
+            opts.org,
+            data,
+            origin,
+            opts.contract_owner,
+            &safe,
+            provider,
+            &signer,
+        )
+        .await?;
+
+        println!("Safe transaction hash: {:?}", safe_tx_hash);
+        if let Some(network) = &network {
+            println!(
+                "View it at https://app.safe.global/transactions/queue?safe={}:{}",
+                network.short_name(),
+                to_checksum(&org_owner, None),
+            );
+        }
+        println!("The anchor will be recorded once the Safe owners execute the transaction.");
     } else {
         let signer = SignerMiddleware::new(provider, signer);
         let contract = Contract::new(opts.org, abi, signer);
         let call = contract.method::<_, ()>("anchor", (id, tag, hash))?;
+        let tx_hash = anchor_eoa(call).await?;
 
-        anchor_eoa(call).await
+        println!("Transaction hash: {:?}", tx_hash);
+        if let Some(network) = &network {
+            println!("View it at {}/tx/{:?}", network.explorer_url(), tx_hash);
+        }
     }
+    Ok(())
 }
 
-async fn anchor_safe<S: Signer + 'static>(
+async fn anchor_safe<P: 'static + JsonRpcClient + Clone, S: Signer + 'static>(
     to: Address,
     data: Bytes,
+    origin: String,
+    contract_owner: Option<Address>,
     safe: &safe::Safe<'_>,
+    provider: Provider<P>,
     signer: &S,
-) -> anyhow::Result<()> {
-    let safe_tx = safe.create_transaction(to, U256::zero(), data, safe::Operation::Call);
-    let signed_tx = safe_tx
-        .sign(signer)
-        .await
-        .map_err(Error::<S::Error>::Signer)?;
+) -> anyhow::Result<TxHash> {
+    let safe_tx = safe
+        .create_transaction(to, U256::zero(), data, safe::Operation::Call)
+        .with_origin(origin);
+    let signed_tx = if let Some(owner) = contract_owner {
+        if !safe.owners.contains(&owner) {
+            bail!("{:?} is not an owner of the Safe", owner);
+        }
+        log::info!("Proposing on behalf of contract owner {:?}", owner);
+
+        // The transaction service validates pre-validated signatures when the transaction
+        // is proposed, so the owner must have approved the hash on-chain beforehand.
+        let safe_tx_hash = safe_tx.hash(signer.chain_id());
+        let abi: Abi = serde_json::from_str(SAFE_ABI)?;
+        let contract = Contract::new(safe.address(), abi, provider);
+        let approved: U256 = contract
+            .method("approvedHashes", (owner, safe_tx_hash))?
+            .call()
+            .await?;
+
+        if approved.is_zero() {
+            bail!(
+                "transaction hash {:?} has not been approved by {:?}; the owner must call \
+                `approveHash({:?})` on the Safe at {:?} before the anchor can be proposed",
+                safe_tx_hash,
+                owner,
+                safe_tx_hash,
+                safe.address(),
+            );
+        }
+        safe_tx.sign_prevalidated(owner, signer.chain_id())
+    } else {
+        safe_tx
+            .sign(signer)
+            .await
+            .map_err(Error::<S::Error>::Signer)?
+    };
+    let safe_tx_hash = signed_tx.safe_tx_hash();
+
+    log::info!("Proposing Safe transaction {:?}..", safe_tx_hash);
 
     safe.propose(signed_tx)?;
 
-    Ok(())
+    Ok(safe_tx_hash)
 }
 
 async fn anchor_eoa<M: Middleware + 'static, D: Detokenize>(
     call: ContractCall<M, D>,
-) -> anyhow::Result<()> {
+) -> anyhow::Result<TxHash> {
     log::info!("Sending transaction..");
 
-    let result = loop {
+    let (tx_hash, result) = loop {
         let pending = call.send().await?;
         let tx_hash = *pending;
 
         log::info!("Waiting for transaction {:?} to be included..", tx_hash);
 
         if let Some(result) = pending.await? {
-            break result;
+            break (tx_hash, result);
         } else {
             log::info!("Transaction {} dropped, retrying..", tx_hash);
         }
@@ -248,5 +416,22 @@ async fn anchor_eoa<M: Middleware + 'static, D: Detokenize>(
         result.block_hash.unwrap(),
     );
 
-    Ok(())
+    Ok(tx_hash)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_safe_transaction_url() {
+        let client = safe::Client::new(Network::Homestead.safe_transaction_url());
+        let address = Address::from_str("0xb535CEd5f003e00CfF2424892D4885b139019F1d").unwrap();
+
+        assert_eq!(
+            client.safe_url(address),
+            "https://safe-transaction-mainnet.safe.global/api/v1/safes/0xb535CEd5f003e00CfF2424892D4885b139019F1d/"
+        );
+    }
 }
@@ -35,7 +35,10 @@ fn parse_options() -> anyhow::Result<Command> {
     let mut rpc_url: Option<String> = None;
     let mut keystore: Option<PathBuf> = None;
     let mut ledger_hdpath: Option<DerivationPath> = None;
+    let mut safe_service_url: Option<String> = None;
+    let mut contract_owner: Option<Address> = None;
     let mut dry_run = false;
+    let mut verify = false;
 
     while let Some(arg) = parser.next()? {
         match arg {
@@ -67,6 +70,27 @@ fn parse_options() -> anyhow::Result<Command> {
             Long("ledger-hdpath") => {
                 ledger_hdpath = Some(parser.value()?.parse()?);
             }
+            Long("safe-service-url") => {
+                safe_service_url = Some(parser.value()?.to_string_lossy().to_string());
+            }
+            Long("contract-owner") => {
+                contract_owner = Some(
+                    parser
+                        .value()?
+                        .parse()
+                        .context("invalid value specified for '--contract-owner'")?,
+                );
+            }
+            Long("verify") => {
+                verify = true;
+                project = Some(
+                    parser
+                        .value()?
+                        .parse()
+                        .context("invalid project specified for '--verify'")?,
+                );
+                commit = Some(parser.value()?.to_string_lossy().to_string());
+            }
             Long("dry-run") => {
                 dry_run = true;
             }
@@ -100,6 +124,10 @@ fn parse_options() -> anyhow::Result<Command> {
         })?
     };
 
+    let safe_service_url = safe_service_url
+        .or_else(|| env::var("SAFE_SERVICE_URL").ok())
+        .and_then(|url| if url.is_empty() { None } else { Some(url) });
+
     let ledger_hdpath = ledger_hdpath.or_else(|| {
         env::var("ETH_HDPATH")
             .ok()
@@ -115,7 +143,10 @@ fn parse_options() -> anyhow::Result<Command> {
             rpc_url,
             ledger_hdpath,
             keystore,
+            safe_service_url,
+            contract_owner,
             dry_run,
+            verify,
         },
         verbose,
     })
@@ -1,9 +1,10 @@
 #![allow(clippy::or_fun_call)]
 use std::ffi::OsString;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 use anyhow::Context as _;
-use radicle_common::signer::ToSigner;
+use radicle_common::signer::{self, ToSigner};
 
 use librad::PeerId;
 
@@ -19,6 +20,10 @@ pub const HELP: Help = Help {
 Usage
 
     rad auth [--init | --active] [<options>...] [<peer-id>]
+    rad auth --change-passphrase [--stdin]
+    rad auth --export <file> [--stdin]
+    rad auth --import <file> [--stdin]
+    rad auth --list
 
     A passphrase may be given via the environment variable `RAD_PASSPHRASE` or
     via the standard input stream if `--stdin` is used. Using one of these
@@ -27,10 +32,27 @@ Usage
     If `--init` is used, a name may be given via the `--name` option. Using
     this disables the input prompt.
 
+    If `--change-passphrase` is used with `--stdin`, the current passphrase
+    is read from the first line, and the new passphrase from the second.
+
+    `--export` writes the active profile's key, still encrypted with its
+    passphrase, to a file that can be imported on another device with
+    `--import`. Keep this file private.
+
+    With the hardware signer backend (`rad --signer hardware`), signing goes
+    through the agent socket configured under `[signer]` in the profile
+    configuration, and the passphrase-protected keystore is never used.
+    `rad` does not provision keys onto devices: the agent must already hold
+    the profile's Ed25519 key, ie. the key shown by `rad self --ssh-key`.
+
 Options
 
     --init                  Initialize a new identity
     --active                Authenticate with the currently active profile
+    --change-passphrase     Change the passphrase of the active profile
+    --export <file>         Export the active profile's key to a file
+    --import <file>         Import a profile from a file created with `--export`
+    --list                  List all local profiles
     --stdin                 Read passphrase from stdin (default: false)
     --name <name>           Use given name (default: none)
     --help                  Print help
@@ -41,6 +63,10 @@ Options
 pub struct Options {
     pub init: bool,
     pub active: bool,
+    pub change_passphrase: bool,
+    pub export: Option<PathBuf>,
+    pub import: Option<PathBuf>,
+    pub list: bool,
     pub stdin: bool,
     pub name: Option<String>,
     pub peer_id: Option<PeerId>,
@@ -52,6 +78,10 @@ impl Args for Options {
 
         let mut init = false;
         let mut active = false;
+        let mut change_passphrase = false;
+        let mut export = None;
+        let mut import = None;
+        let mut list = false;
         let mut stdin = false;
         let mut name = None;
         let mut peer_id = None;
@@ -65,6 +95,18 @@ impl Args for Options {
                 Long("active") => {
                     active = true;
                 }
+                Long("change-passphrase") => {
+                    change_passphrase = true;
+                }
+                Long("export") if export.is_none() => {
+                    export = Some(PathBuf::from(parser.value()?));
+                }
+                Long("import") if import.is_none() => {
+                    import = Some(PathBuf::from(parser.value()?));
+                }
+                Long("list") => {
+                    list = true;
+                }
                 Long("stdin") => {
                     stdin = true;
                 }
@@ -96,6 +138,10 @@ impl Args for Options {
             Options {
                 init,
                 active,
+                change_passphrase,
+                export,
+                import,
+                list,
                 stdin,
                 name,
                 peer_id,
@@ -111,7 +157,15 @@ pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
         _ => vec![],
     };
 
-    if options.init || profiles.is_empty() {
+    if options.list {
+        list(&profiles, ctx)
+    } else if options.change_passphrase {
+        change_passphrase(options, ctx)
+    } else if let Some(path) = &options.export {
+        export(path, options.stdin, ctx)
+    } else if let Some(path) = &options.import {
+        import(path, options.stdin)
+    } else if options.init || profiles.is_empty() {
         if options.peer_id.is_some() {
             anyhow::bail!("you may not specify a peer id when initializing a new identity");
         }
@@ -127,11 +181,9 @@ pub fn init(options: Options) -> anyhow::Result<()> {
     let sock = keys::ssh_auth_sock();
     let home = profile::home();
 
-    if git::check_version().is_err() {
-        term::warning(&format!(
-            "Your git version is unsupported, please upgrade to {} or later",
-            git::VERSION_REQUIRED,
-        ));
+    if let Err(err) = git::check_version() {
+        term::warning(&format!("Unsupported git environment: {}", err));
+        term::tip!("Run `rad doctor` to diagnose your environment.");
         term::blank();
     }
 
@@ -249,6 +301,22 @@ pub fn authenticate(
     }
 
     let profile = selection;
+    if signer::backend(profile)? == signer::Backend::Hardware {
+        // Nb. The keystore is never unlocked with the hardware signer, and no key is added
+        // to its agent: the device must already hold the radicle key.
+        let sock = keys::agent(profile)?;
+        if !keys::is_ready(profile, sock)? {
+            return Err(Error::WithHint {
+                err: anyhow::anyhow!("the hardware signer does not hold your radicle key"),
+                hint: "Load the profile's Ed25519 key onto the device, see `rad self --ssh-key`.",
+            }
+            .into());
+        }
+        term::success!("Signing key available on the hardware signer");
+
+        return Ok(());
+    }
+
     if let Ok(sock) = keys::ssh_auth_sock() {
         if !keys::is_ready(profile, sock.clone())? {
             term::warning("Adding your radicle key to ssh-agent...");
@@ -274,6 +342,131 @@ pub fn authenticate(
     Ok(())
 }
 
+pub fn list(profiles: &[profile::Profile], ctx: impl term::Context) -> anyhow::Result<()> {
+    if profiles.is_empty() {
+        term::info!("No profiles found. To create one, run `rad auth --init`.");
+        return Ok(());
+    }
+    let active = ctx.profile().ok();
+    let mut table = term::Table::default();
+
+    for profile in profiles {
+        let storage = profile::read_only(profile)?;
+        let name = storage
+            .config()
+            .ok()
+            .and_then(|c| c.user_name().ok())
+            .unwrap_or_default();
+        let marker = if active.as_ref().map(|p| p.id()) == Some(profile.id()) {
+            term::format::positive("*")
+        } else {
+            String::from(" ")
+        };
+
+        table.push([
+            marker,
+            term::format::tertiary(profile.id()),
+            term::format::highlight(storage.peer_id()),
+            term::format::bold(name),
+        ]);
+    }
+    table.render();
+
+    Ok(())
+}
+
+pub fn change_passphrase(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
+    let profile = ctx.profile()?;
+
+    term::headline(&format!(
+        "🌱 Changing passphrase of {}",
+        term::display::Identity::new(&profile).styled()
+    ));
+
+    let (old, new) = if options.stdin {
+        let old = term::secret_stdin()?;
+        let new = term::secret_stdin()?;
+
+        if new.unsecure().is_empty() {
+            anyhow::bail!("the new passphrase cannot be empty");
+        }
+        (old, new)
+    } else {
+        (
+            term::secret_input_with_prompt("Current passphrase"),
+            term::secret_input_with_confirmation_prompt("New passphrase"),
+        )
+    };
+
+    let spinner = term::spinner("Re-encrypting key...");
+    keys::change_passphrase(&profile, old, new)?;
+    spinner.finish();
+
+    term::success!("Passphrase changed");
+
+    if let Ok(sock) = keys::ssh_auth_sock() {
+        if keys::is_ready(&profile, sock)? {
+            term::tip!("The key in ssh-agent is unaffected; it remains unlocked until removed.");
+        }
+    }
+
+    Ok(())
+}
+
+pub fn export(path: &Path, stdin: bool, ctx: impl term::Context) -> anyhow::Result<()> {
+    let profile = ctx.profile()?;
+
+    term::headline(&format!(
+        "🌱 Exporting {}",
+        term::display::Identity::new(&profile).styled()
+    ));
+
+    let passphrase = term::read_passphrase(stdin, false)?;
+    let spinner = term::spinner("Exporting key...");
+    keys::export(&profile, passphrase, path)?;
+    spinner.finish();
+
+    term::success!(
+        "Key exported to {}",
+        term::format::highlight(path.display())
+    );
+    term::tip!("This file is protected by your passphrase only. Keep it private.");
+
+    Ok(())
+}
+
+pub fn import(path: &Path, stdin: bool) -> anyhow::Result<()> {
+    term::headline(&format!(
+        "🌱 Importing profile from {}",
+        term::format::highlight(path.display())
+    ));
+
+    let passphrase = term::read_passphrase(stdin, false)?;
+    let spinner = term::spinner("Importing key...");
+    let (profile, bundle) = keys::import(&profile::home(), path, passphrase)?;
+    spinner.finish();
+
+    term::success!(
+        "Profile {} created and activated.",
+        term::format::highlight(profile.id())
+    );
+    term::info!(
+        "Your radicle Peer ID is {}.",
+        term::format::highlight(bundle.peer_id)
+    );
+
+    term::blank();
+    term::tip!("To add your radicle key to ssh-agent, run `rad auth`.");
+    if let Some(urn) = bundle.urn {
+        term::tip!(
+            "To fetch your identity, run {}.",
+            term::format::secondary(format!("`rad sync {} --fetch`", urn))
+        );
+    }
+
+    Ok(())
+}
+
 fn sanitize_name(name: String) -> anyhow::Result<String> {
     if name.contains(char::is_whitespace) {
         anyhow::bail!("Name cannot contain whitespaces");
@@ -293,6 +486,10 @@ mod tests {
     fn create_auth_options(name: &str) -> Options {
         Options {
             active: false,
+            change_passphrase: false,
+            export: None,
+            import: None,
+            list: false,
             init: true,
             stdin: false,
             name: Some(name.to_owned()),
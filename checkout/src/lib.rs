@@ -5,6 +5,7 @@ use anyhow::anyhow;
 use anyhow::Context as _;
 
 use librad::git::Urn;
+use librad::PeerId;
 
 use radicle_common::args::{Args, Error, Help};
 use radicle_common::Interactive;
@@ -18,17 +19,24 @@ pub const HELP: Help = Help {
     usage: r#"
 Usage
 
-    rad checkout <urn> [<option>...]
+    rad checkout <urn> [<path>] [<option>...]
+
+    If <path> is not specified, the project name is used as the checkout directory.
 
 Options
 
-    --no-confirm    Don't ask for confirmation during checkout
-    --help          Print help
+    --peer <peer-id>    Checkout the given peer's view of the project
+    --no-confirm        Don't ask for confirmation during checkout
+    --help              Print help
 "#,
 };
 
 pub struct Options {
     pub urn: Urn,
+    /// Peer whose view of the project should be checked out.
+    pub peer: Option<PeerId>,
+    /// Directory to checkout the project into. Defaults to the project name.
+    pub path: Option<PathBuf>,
     pub interactive: Interactive,
 }
 
@@ -39,6 +47,8 @@ impl Args for Options {
 
         let mut parser = lexopt::Parser::from_args(args);
         let mut urn = None;
+        let mut path = None;
+        let mut peer = None;
         let mut interactive = Interactive::Yes;
 
         while let Some(arg) = parser.next()? {
@@ -46,6 +56,14 @@ impl Args for Options {
                 Long("no-confirm") => {
                     interactive = Interactive::No;
                 }
+                Long("peer") if peer.is_none() => {
+                    let val = parser.value()?;
+                    let val = val.to_string_lossy();
+                    let val =
+                        PeerId::from_str(&val).context(format!("invalid peer id '{}'", val))?;
+
+                    peer = Some(val);
+                }
                 Long("help") => return Err(Error::Help.into()),
                 Value(val) if urn.is_none() => {
                     let val = val.to_string_lossy();
@@ -53,6 +71,9 @@ impl Args for Options {
 
                     urn = Some(val);
                 }
+                Value(val) if path.is_none() => {
+                    path = Some(PathBuf::from(val));
+                }
                 _ => return Err(anyhow::anyhow!(arg.unexpected())),
             }
         }
@@ -60,6 +81,8 @@ impl Args for Options {
         Ok((
             Options {
                 urn: urn.ok_or_else(|| anyhow!("a project URN to checkout must be provided"))?,
+                peer,
+                path,
                 interactive,
             },
             vec![],
@@ -84,7 +107,10 @@ pub fn execute(options: Options, profile: &profile::Profile) -> anyhow::Result<P
     let storage = keys::storage(profile, signer.clone())?;
     let project = project::get(&storage, &options.urn)?
         .context("project could not be found in local storage")?;
-    let path = PathBuf::from(project.name.clone());
+    let path = options
+        .path
+        .clone()
+        .unwrap_or_else(|| PathBuf::from(project.name.clone()));
     let interactive = options.interactive;
 
     if path.exists() {
@@ -97,13 +123,30 @@ pub fn execute(options: Options, profile: &profile::Profile) -> anyhow::Result<P
         project.name,
     ));
 
+    // If a peer was specified, we checkout that peer's head.
     // If we have a local head, we should checkout our local "fork", so we don't specify
     // a peer.
     // If we *don't* have a local head, we have to checkout a delegate's head. If there is
     // only one delegate, the choice is easy.
-    let peer = if project::get_local_head(&storage, &options.urn, &project.default_branch)?
-        .is_some()
-    {
+    let peer = if let Some(peer) = options.peer {
+        if project::get_remote_head(&storage, &options.urn, &peer, &project.default_branch)
+            .ok()
+            .flatten()
+            .is_none()
+        {
+            anyhow::bail!(
+                "{} branch of peer {} was not found in local storage",
+                project.default_branch,
+                peer
+            );
+        }
+        term::success!(
+            "Remote {} branch found via {}...",
+            project.default_branch,
+            term::format::highlight(peer)
+        );
+        Some(peer)
+    } else if project::get_local_head(&storage, &options.urn, &project.default_branch)?.is_some() {
         term::success!("Local {} branch found...", project.default_branch);
         None
     } else {
@@ -118,7 +161,41 @@ pub fn execute(options: Options, profile: &profile::Profile) -> anyhow::Result<P
                 );
                 Some(*d)
             }
-            [_,_,..] => anyhow::bail!("project has more than one delegate, please specify which one you would like to checkout"),
+            [_, _, ..] if interactive.yes() => {
+                let mut choices = Vec::new();
+                for peer in &delegates {
+                    let choice = match project::person(&storage, project.urn.clone(), peer)? {
+                        Some(person) => format!(
+                            "{} {}",
+                            person.subject().name,
+                            term::format::dim(fmt::peer(peer))
+                        ),
+                        None => peer.default_encoding(),
+                    };
+                    choices.push(choice);
+                }
+                let selected = term::select_with_prompt(
+                    "Which delegate would you like to checkout?",
+                    &choices,
+                    &choices[0],
+                )
+                .ok_or_else(|| anyhow!("no delegate selected, aborting checkout"))?;
+                let index = choices
+                    .iter()
+                    .position(|c| c == selected)
+                    .expect("the selected delegate is one of the choices");
+                let delegate = delegates[index];
+
+                term::success!(
+                    "Remote {} branch found via {}...",
+                    project.default_branch,
+                    term::format::highlight(delegate)
+                );
+                Some(*delegate)
+            }
+            [_, _, ..] => anyhow::bail!(
+                "project has more than one delegate, please specify which one you would like to checkout with `--peer`"
+            ),
         }
     };
 
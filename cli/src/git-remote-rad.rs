@@ -6,7 +6,7 @@ use librad::profile::{LnkHome, LNK_HOME};
 use link_identities::git::Urn;
 use radicle_git_helpers::remote_helper;
 
-use radicle_common::{keys, profile, signer::ToSigner as _};
+use radicle_common::{keys, profile, signer, signer::ToSigner as _};
 
 use anyhow::anyhow;
 #[cfg(feature = "ethereum")]
@@ -142,7 +142,10 @@ fn run(remote: Remote) -> anyhow::Result<()> {
         }
         Remote::Project { urn: _urn } => {
             let profile = profile::default()?;
-            let signer = if let Ok(sock) = keys::ssh_auth_sock() {
+            let signer = if signer::backend(&profile)? == signer::Backend::Hardware {
+                // Hardware keys never fall back to the keystore.
+                keys::agent(&profile)?.to_signer(&profile)?
+            } else if let Ok(sock) = keys::agent(&profile) {
                 sock.to_signer(&profile)?
             } else if let Ok(pass) = env::var(keys::RAD_PASSPHRASE) {
                 keys::load_secret_key(&profile, SecUtf8::from(pass))?.to_signer(&profile)?
@@ -3,6 +3,7 @@ use std::{io::ErrorKind, iter, process};
 
 use anyhow::anyhow;
 use rad_help::*;
+use radicle_common::exit::ExitCode;
 use radicle_common::profile;
 use radicle_terminal as term;
 
@@ -20,12 +21,13 @@ enum Command {
 
 fn main() {
     match parse_args().map_err(Some).and_then(run) {
-        Ok(_) => process::exit(0),
+        Ok(_) => process::exit(ExitCode::Success.code()),
         Err(err) => {
             if let Some(err) = err {
                 radicle_terminal::error(&format!("Error: rad: {}", err));
+                process::exit(ExitCode::from(&err).code());
             }
-            process::exit(1);
+            process::exit(ExitCode::Failure.code());
         }
     }
 }
@@ -35,6 +37,8 @@ fn parse_args() -> anyhow::Result<Command> {
 
     let mut parser = lexopt::Parser::from_env();
     let mut command = None;
+    let mut color = term::color::Mode::default();
+    let mut verbosity: u8 = 0;
 
     while let Some(arg) = parser.next()? {
         match arg {
@@ -44,6 +48,31 @@ fn parse_args() -> anyhow::Result<Command> {
             Long("version") => {
                 command = Some(Command::Version);
             }
+            Long("quiet") | Short('q') if command.is_none() => {
+                term::set_quiet(true);
+            }
+            Long("verbose") | Short('v') if command.is_none() => {
+                verbosity = verbosity.saturating_add(1);
+            }
+            Long("offline") if command.is_none() => {
+                radicle_common::sync::set_offline(true);
+            }
+            Long("signer") if command.is_none() => {
+                let value = parser.value()?;
+                let value = value
+                    .to_str()
+                    .ok_or_else(|| anyhow!("signer specified is not UTF-8"))?;
+
+                radicle_common::signer::set_backend(value.parse()?);
+            }
+            Long("color") if command.is_none() => {
+                let value = parser.value()?;
+                let value = value
+                    .to_str()
+                    .ok_or_else(|| anyhow!("color mode specified is not UTF-8"))?;
+
+                color = value.parse()?;
+            }
             Value(val) if command.is_none() => {
                 if val == *"." {
                     command = Some(Command::Other(vec![OsString::from("inspect")]));
@@ -59,6 +88,9 @@ fn parse_args() -> anyhow::Result<Command> {
         }
     }
 
+    term::color::set_mode(color);
+    radicle_common::logger::set_verbosity(verbosity);
+
     Ok(command.unwrap_or_else(|| Command::Other(vec![])))
 }
 
@@ -143,6 +175,30 @@ fn run_other(exe: &str, args: &[OsString]) -> Result<(), Option<anyhow::Error>>
                 args.to_vec(),
             );
         }
+        "completions" => {
+            term::run_command_args::<completions::Options, _>(
+                completions::HELP,
+                "Completions",
+                completions::run,
+                args.to_vec(),
+            );
+        }
+        "config" => {
+            term::run_command_args::<rad_config::Options, _>(
+                rad_config::HELP,
+                "Config",
+                rad_config::run,
+                args.to_vec(),
+            );
+        }
+        "doctor" => {
+            term::run_command_args::<rad_doctor::Options, _>(
+                rad_doctor::HELP,
+                "Doctor",
+                rad_doctor::run,
+                args.to_vec(),
+            );
+        }
         "edit" => {
             term::run_command_args::<rad_edit::Options, _>(
                 rad_edit::HELP,
@@ -201,6 +257,14 @@ fn run_other(exe: &str, args: &[OsString]) -> Result<(), Option<anyhow::Error>>
                 args.to_vec(),
             );
         }
+        "key" => {
+            term::run_command_args::<rad_key::Options, _>(
+                rad_key::HELP,
+                "Key",
+                rad_key::run,
+                args.to_vec(),
+            );
+        }
         "ls" => {
             term::run_command_args::<rad_ls::Options, _>(
                 rad_ls::HELP,
@@ -265,6 +329,15 @@ fn run_other(exe: &str, args: &[OsString]) -> Result<(), Option<anyhow::Error>>
                 args.to_vec(),
             );
         }
+        #[cfg(feature = "ethereum")]
+        "reward" => {
+            term::run_command_args::<rad_reward::Options, _>(
+                rad_reward::HELP,
+                "Command",
+                rad_reward::run,
+                args.to_vec(),
+            );
+        }
         "rm" => {
             term::run_command_args::<rad_rm::Options, _>(
                 rad_rm::HELP,
@@ -312,12 +385,15 @@ fn run_other(exe: &str, args: &[OsString]) -> Result<(), Option<anyhow::Error>>
             match status {
                 Ok(status) => {
                     if !status.success() {
-                        return Err(None);
+                        // Forward the exit code of external commands as-is.
+                        process::exit(status.code().unwrap_or(ExitCode::Failure.code()));
                     }
                 }
                 Err(err) => {
                     if let ErrorKind::NotFound = err.kind() {
-                        return Err(Some(anyhow!("command `{}` not found", exe)));
+                        // An unknown command is a usage error.
+                        term::error(&format!("Error: rad: command `{}` not found", exe));
+                        process::exit(ExitCode::Usage.code());
                     } else {
                         return Err(Some(err.into()));
                     }
@@ -6,8 +6,10 @@ use std::str::FromStr;
 use anyhow::anyhow;
 use librad::git::tracking;
 use librad::git::Urn;
+use librad::PeerId;
 use url::Url;
 
+use radicle_common::args;
 use radicle_common::args::{Args, Error, Help};
 use radicle_common::seed;
 use radicle_common::Interactive;
@@ -21,13 +23,16 @@ pub const HELP: Help = Help {
     usage: r#"
 Usage
 
-    rad clone <urn | url> [--seed <addr>] [<option>...]
+    rad clone <urn | url> [--seed <addr>] [--peer <peer-id>] [<option>...]
 
 Options
 
-    --no-confirm    Don't ask for confirmation during clone
-    --seed <addr>   Seed to clone from
-    --help          Print help
+    --no-confirm        Don't ask for confirmation during clone
+    --seed <addr>       Seed to clone from
+    --peer <peer-id>    Checkout the given peer's view of the project
+    --depth <n>         Create a shallow clone with the given number of commits
+                        (only applies to git URLs)
+    --help              Print help
 
 "#,
 };
@@ -41,6 +46,8 @@ enum Origin {
 #[derive(Debug)]
 pub struct Options {
     origin: Origin,
+    peer: Option<PeerId>,
+    depth: Option<u32>,
     interactive: Interactive,
 }
 
@@ -52,12 +59,31 @@ impl Args for Options {
         let mut origin: Option<Origin> = None;
         let mut interactive = Interactive::Yes;
         let mut seed = None;
+        let mut peer = None;
+        let mut depth = None;
 
         while let Some(arg) = parser.next()? {
             match arg {
                 Long("seed") if seed.is_none() => {
                     seed = Some(seed::parse_value(&mut parser)?);
                 }
+                Long("peer") if peer.is_none() => {
+                    let value = parser.value()?;
+                    let value = value.to_string_lossy();
+                    let value = PeerId::from_str(&value)
+                        .map_err(|_| anyhow!("invalid peer id specified: '{}'", value))?;
+
+                    peer = Some(value);
+                }
+                Long("depth") if depth.is_none() => {
+                    let value = parser.value()?;
+                    let value = args::parse_value("depth", value)?;
+
+                    if value == 0 {
+                        anyhow::bail!("invalid depth specified: must be at least 1");
+                    }
+                    depth = Some(value);
+                }
                 Long("no-confirm") => {
                     interactive = Interactive::No;
                 }
@@ -70,23 +96,18 @@ impl Args for Options {
                         Ok(urn) => {
                             origin = Some(Origin::Radicle(identity::Origin::from_urn(urn)));
                         }
-                        Err(_) => {
-                            match Url::parse(&val) {
-                                Ok(_) if seed.is_some() => {
-                                    anyhow::bail!("`--seed` cannot be specified when a URL is given as origin");
-                                }
-                                Ok(url) if url.scheme() == project::URL_SCHEME => {
-                                    let o = identity::Origin::try_from(url)?;
-                                    origin = Some(Origin::Radicle(o));
-                                }
-                                Ok(url) => {
-                                    origin = Some(Origin::Git(url));
-                                }
-                                Err(err) => {
-                                    return Err(err.into());
-                                }
+                        Err(_) => match Url::parse(&val) {
+                            Ok(url) if url.scheme() == project::URL_SCHEME => {
+                                let o = identity::Origin::try_from(url)?;
+                                origin = Some(Origin::Radicle(o));
                             }
-                        }
+                            Ok(url) => {
+                                origin = Some(Origin::Git(url));
+                            }
+                            Err(err) => {
+                                return Err(err.into());
+                            }
+                        },
                     }
                 }
                 _ => return Err(anyhow!(arg.unexpected())),
@@ -96,15 +117,37 @@ impl Args for Options {
             anyhow!("to clone, a URN or URL must be provided; see `rad clone --help`")
         })?;
 
-        let origin = if let Origin::Radicle(identity::Origin { urn, seed: None }) = origin {
-            Origin::Radicle(identity::Origin { urn, seed })
-        } else {
-            origin
+        let origin = match (origin, seed) {
+            (
+                Origin::Radicle(identity::Origin {
+                    urn,
+                    seed: None,
+                    host: None,
+                }),
+                seed,
+            ) => Origin::Radicle(identity::Origin {
+                urn,
+                seed,
+                host: None,
+            }),
+            (origin, None) => origin,
+            (_, Some(_)) => {
+                anyhow::bail!("`--seed` cannot be specified when a URL is given as origin");
+            }
         };
 
+        if let (Origin::Git(_), Some(_)) = (&origin, peer) {
+            anyhow::bail!("`--peer` cannot be specified when cloning a git repository");
+        }
+        if let (Origin::Radicle(_), Some(_)) = (&origin, depth) {
+            anyhow::bail!("`--depth` can only be specified when cloning a git repository");
+        }
+
         Ok((
             Options {
                 origin,
+                peer,
+                depth,
                 interactive,
             },
             vec![],
@@ -115,11 +158,13 @@ impl Args for Options {
 pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
     match options.origin {
         Origin::Radicle(origin) => {
-            clone_project(origin.urn, origin.seed, options.interactive, ctx)?;
+            let seed = origin.resolve_seed()?;
+
+            clone_project(origin.urn, seed, options.peer, options.interactive, ctx)?;
         }
         Origin::Git(url) => {
             let profile = ctx.profile()?;
-            clone_repository(url, &profile)?;
+            clone_repository(url, options.depth, &profile)?;
         }
     }
     Ok(())
@@ -128,16 +173,32 @@ pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
 pub fn clone_project(
     urn: Urn,
     seed: Option<sync::Seed<String>>,
+    peer: Option<PeerId>,
     interactive: Interactive,
     ctx: impl term::Context,
 ) -> anyhow::Result<()> {
     let profile = ctx.profile()?;
+    let signer = term::signer(&profile)?;
+    let storage = keys::storage(&profile, signer)?;
+    let cfg = tracking::config::Config::default();
+
+    // Track the requested peer before syncing, so that their refs are fetched.
+    if let Some(peer) = peer {
+        tracking::track(
+            &storage,
+            &urn,
+            Some(peer),
+            cfg.clone(),
+            tracking::policy::Track::Any,
+        )??;
+    }
 
     rad_sync::run(
         rad_sync::Options {
             origin: Some(identity::Origin {
                 urn: urn.clone(),
                 seed,
+                host: None,
             }),
             verbose: true,
             ..rad_sync::Options::default()
@@ -147,14 +208,13 @@ pub fn clone_project(
     let path = rad_checkout::execute(
         rad_checkout::Options {
             urn: urn.clone(),
+            peer,
+            path: None,
             interactive,
         },
         &profile,
     )?;
 
-    let signer = term::signer(&profile)?;
-    let storage = keys::storage(&profile, signer)?;
-    let cfg = tracking::config::Config::default();
     let project = project::get(&storage, &urn)?
         .ok_or_else(|| anyhow!("couldn't load project {} from local state", urn))?;
 
@@ -178,7 +238,11 @@ pub fn clone_project(
     Ok(())
 }
 
-pub fn clone_repository(url: Url, profile: &profile::Profile) -> anyhow::Result<()> {
+pub fn clone_repository(
+    url: Url,
+    depth: Option<u32>,
+    profile: &profile::Profile,
+) -> anyhow::Result<()> {
     let proj = url
         .path_segments()
         .ok_or(anyhow!("couldn't get segments of URL"))?
@@ -187,12 +251,40 @@ pub fn clone_repository(url: Url, profile: &profile::Profile) -> anyhow::Result<
     let proj = proj.strip_suffix(".git").unwrap_or(proj);
     let destination = std::env::current_dir()?.join(proj);
 
-    let spinner = term::spinner(&format!(
+    let message = format!(
         "Cloning git repository {}...",
         term::format::highlight(&url)
-    ));
-    git::clone(url.as_str(), &destination)?;
-    spinner.finish();
+    );
+    let mut credentials = None;
+
+    loop {
+        let mut spinner = term::spinner(&message);
+        let result = git::clone(
+            url.as_str(),
+            &destination,
+            depth,
+            credentials.as_ref(),
+            |progress| {
+                spinner.message(format!("{} {}", message, term::format::dim(progress)));
+            },
+        );
+        spinner.message(&message);
+
+        match result {
+            Ok(()) => {
+                spinner.finish();
+                break;
+            }
+            // Only prompt once, if authentication with the defaults failed.
+            Err(err) if git::is_auth_error(&err) && credentials.is_none() => {
+                spinner.failed();
+                term::warning("Authentication failed, please provide credentials");
+
+                credentials = Some(prompt_credentials(&url)?);
+            }
+            Err(err) => return Err(spinner.error(err)),
+        }
+    }
 
     if term::confirm(format!(
         "Initialize new 🌱 project in {}?",
@@ -207,10 +299,29 @@ pub fn clone_repository(url: Url, profile: &profile::Profile) -> anyhow::Result<
     Ok(())
 }
 
+/// Prompt for credentials to authenticate with a private git remote.
+fn prompt_credentials(url: &Url) -> anyhow::Result<git::Credentials> {
+    if url.scheme() == "ssh" {
+        let default = std::env::var("HOME")
+            .ok()
+            .map(|home| format!("{}/.ssh/id_ed25519", home));
+        let key: String = term::text_input("SSH key file", default)?;
+
+        return Ok(git::Credentials::SshKey(key.into()));
+    }
+    let username = if url.username().is_empty() {
+        term::text_input("Username", None)?
+    } else {
+        url.username().to_owned()
+    };
+    let password = term::secret_input_with_prompt("Password or token");
+
+    Ok(git::Credentials::Password { username, password })
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use librad::PeerId;
 
     #[test]
     fn test_args_ok() {
@@ -264,6 +375,21 @@ mod test {
                 "--seed",
                 "whyb5to4rshftx4apgmu9s6wnsp4ddmp1mz6ijh4qqey7fb8wrpawxa@illow.radicle.garden",
             ],
+            vec![
+                "rad://pine.radicle.garden/hnrkfbrd7y9674d8ow8uioki16fniwcyoz67y",
+                "--seed",
+                "hyb5to4rshftx4apgmu9s6wnsp4ddmp1mz6ijh4qqey7fb8wrpawxa@willow.radicle.garden",
+            ],
+            vec![
+                "--seed",
+                "hyb5to4rshftx4apgmu9s6wnsp4ddmp1mz6ijh4qqey7fb8wrpawxa@willow.radicle.garden",
+                "https://willow.radicle.garden/hnrkfbrd7y9674d8ow8uioki16fniwcyoz67y.git",
+            ],
+            vec![
+                "https://willow.radicle.garden/hnrkfbrd7y9674d8ow8uioki16fniwcyoz67y.git",
+                "--depth",
+                "0",
+            ],
         ];
 
         for args in tests {
@@ -1,13 +1,15 @@
 #![allow(clippy::or_fun_call)]
 use std::ffi::OsString;
+use std::path::PathBuf;
 use std::str::FromStr;
+use std::{fs, io};
 
 use anyhow::anyhow;
 
 use radicle_common::args::{Args, Error, Help};
 use radicle_common::{
     cobs::{self, issue, patch, CommentId},
-    keys, project,
+    keys, project, Urn,
 };
 use radicle_terminal as term;
 use radicle_terminal::patch::Comment;
@@ -20,11 +22,15 @@ pub const HELP: Help = Help {
 Usage
 
     rad comment <id> [-m <text>] [--reply-to <index>]
+    rad comment <id> --message-file <path> [--reply-to <index>]
+    rad comment <id> --edit <index> [-m <text>]
 
 Options
 
-    -m, --message               Comment message
+    -m, --message               Comment message, or `-` to read it from stdin
+        --message-file <path>   Read the comment message from a file
         --reply-to <index>      Index of comment writing a reply for
+        --edit <index>          Index of your own comment to edit
         --help                  Print help
 "#,
 };
@@ -34,6 +40,7 @@ pub struct Options {
     pub id: cobs::Identifier,
     pub message: Comment,
     pub reply_index: Option<CommentId>,
+    pub edit_index: Option<CommentId>,
 }
 
 impl Args for Options {
@@ -44,6 +51,7 @@ impl Args for Options {
         let mut id: Option<cobs::Identifier> = None;
         let mut message = Comment::default();
         let mut reply_index: Option<CommentId> = None;
+        let mut edit_index: Option<CommentId> = None;
 
         while let Some(arg) = parser.next()? {
             match arg {
@@ -52,7 +60,19 @@ impl Args for Options {
                 }
                 Long("message") | Short('m') => {
                     let txt: String = parser.value()?.to_string_lossy().into();
-                    message.append(&txt);
+
+                    if txt == "-" {
+                        message.append_from(io::stdin())?;
+                    } else {
+                        message.append(&txt);
+                    }
+                }
+                Long("message-file") => {
+                    let path = PathBuf::from(parser.value()?);
+                    let file = fs::File::open(&path)
+                        .map_err(|e| anyhow!("couldn't open '{}': {}", path.display(), e))?;
+
+                    message.append_from(file)?;
                 }
                 Long("reply-to") => {
                     let idx = parser
@@ -62,6 +82,14 @@ impl Args for Options {
 
                     reply_index = Some(CommentId::from(idx));
                 }
+                Long("edit") => {
+                    let idx = parser
+                        .value()?
+                        .parse::<usize>()
+                        .map_err(|_| anyhow!("index for `--edit` can't be parsed as usize"))?;
+
+                    edit_index = Some(CommentId::from(idx));
+                }
                 Value(val) if id.is_none() => {
                     let val = val
                         .to_str()
@@ -78,11 +106,16 @@ impl Args for Options {
             }
         }
 
+        if reply_index.is_some() && edit_index.is_some() {
+            anyhow::bail!("`--reply-to` and `--edit` can't be used together");
+        }
+
         Ok((
             Options {
                 id: id.ok_or_else(|| anyhow!("an object id must be provided"))?,
                 message,
                 reply_index,
+                edit_index,
             },
             vec![],
         ))
@@ -93,10 +126,14 @@ pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
     let profile = ctx.profile()?;
     let signer = term::signer(&profile)?;
     let storage = keys::storage(&profile, signer)?;
-    let (project, _) = project::cwd()?;
+    let (project, _) = project::cwd().map_err(Error::from)?;
     let cobs = cobs::store(&profile, &storage)?;
     let cob_id = options.id;
 
+    if let Some(edit_index) = options.edit_index {
+        return edit(&cobs, &project, &cob_id, edit_index, options.message);
+    }
+
     let message = options.message.get("Enter a comment message...");
     if message.is_empty() {
         return Ok(());
@@ -123,3 +160,70 @@ pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Edit an existing comment on an issue or patch.
+fn edit(
+    cobs: &cobs::Store,
+    project: &Urn,
+    cob_id: &cobs::Identifier,
+    index: CommentId,
+    message: Comment,
+) -> anyhow::Result<()> {
+    let whoami = cobs.whoami.urn();
+    let ix = usize::from(index);
+
+    if let Some(id) = cobs.resolve_id::<issue::Issue>(project, cob_id)? {
+        let issue = cobs
+            .issues()
+            .get(project, &id)?
+            .ok_or_else(|| anyhow!("Couldn't load issue {}", id))?;
+        let comment = issue
+            .comments()
+            .get(ix)
+            .ok_or_else(|| anyhow!("Comment {} was not found", ix))?;
+        if comment.author.urn() != &whoami {
+            anyhow::bail!("Only the author of a comment can edit it");
+        }
+        let body = edit_message(&comment.body, message)?;
+
+        cobs.issues().edit_comment(project, &id, index, &body)?;
+    } else if let Some((id, patch)) = cobs.resolve::<patch::Patch>(project, cob_id)? {
+        let (version, revision) = patch.latest();
+        let comment = revision
+            .discussion
+            .get(ix)
+            .ok_or_else(|| anyhow!("Comment {} was not found", ix))?;
+        if comment.author.urn() != &whoami {
+            anyhow::bail!("Only the author of a comment can edit it");
+        }
+        let body = edit_message(&comment.body, message)?;
+
+        cobs.patches()
+            .edit_comment(project, &id, version, index, &body)?;
+    } else {
+        anyhow::bail!("Couldn't find issue or patch {}", cob_id);
+    }
+    term::success!("Comment {} updated", ix);
+
+    Ok(())
+}
+
+/// Get the new body of an edited comment, opening the editor with the existing body
+/// if no message was supplied.
+fn edit_message(body: &str, message: Comment) -> anyhow::Result<String> {
+    let body = match message {
+        Comment::Edit => term::Editor::new()
+            .require_save(true)
+            .trim_newlines(true)
+            .extension(".markdown")
+            .edit(body)?
+            .ok_or_else(|| anyhow!("Operation aborted!"))?,
+        other => other.get(""),
+    };
+    let body = body.trim();
+
+    if body.is_empty() {
+        anyhow::bail!("Comment can't be empty");
+    }
+    Ok(body.to_owned())
+}
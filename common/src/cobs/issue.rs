@@ -233,8 +233,8 @@ impl<'a> IssueStore<'a> {
         cobs::create(history, project, &self.whoami, self.store)
     }
 
-    pub fn remove(&self, _project: &Urn, _issue_id: &IssueId) -> Result<(), Error> {
-        todo!()
+    pub fn remove(&self, project: &Urn, issue_id: &IssueId) -> Result<(), Error> {
+        self.store.remove::<Issue>(project, issue_id)
     }
 
     pub fn comment(&self, project: &Urn, issue_id: &IssueId, body: &str) -> Result<IssueId, Error> {
@@ -301,6 +301,32 @@ impl<'a> IssueStore<'a> {
         Ok(())
     }
 
+    pub fn unlabel(
+        &self,
+        project: &Urn,
+        issue_id: &IssueId,
+        labels: &[Label],
+    ) -> Result<(), Error> {
+        let author = self.whoami.urn();
+        let mut issue = self.get_raw(project, issue_id)?.unwrap();
+        let changes = events::unlabel(&mut issue, &author, labels)?;
+        let _cob = self
+            .store
+            .update(
+                &self.whoami,
+                project,
+                UpdateObjectSpec {
+                    object_id: *issue_id,
+                    typename: TYPENAME.clone(),
+                    message: Some("Remove label".to_owned()),
+                    changes,
+                },
+            )
+            .unwrap();
+
+        Ok(())
+    }
+
     pub fn react(
         &self,
         project: &Urn,
@@ -356,6 +382,35 @@ impl<'a> IssueStore<'a> {
         Ok(())
     }
 
+    /// Edit the body of a comment. Only the comment author can do this.
+    pub fn edit_comment(
+        &self,
+        project: &Urn,
+        issue_id: &IssueId,
+        comment_id: CommentId,
+        body: &str,
+    ) -> Result<(), Error> {
+        let author = self.author();
+        let mut issue = self.get_raw(project, issue_id)?.unwrap();
+        let changes = events::edit_comment(&mut issue, comment_id, &author, body)?;
+
+        let _cob = self
+            .store
+            .update(
+                &self.whoami,
+                project,
+                UpdateObjectSpec {
+                    object_id: *issue_id,
+                    typename: TYPENAME.clone(),
+                    message: Some("Edit comment".to_owned()),
+                    changes,
+                },
+            )
+            .unwrap();
+
+        Ok(())
+    }
+
     pub fn all(&self, project: &Urn) -> Result<Vec<(IssueId, Issue)>, Error> {
         let cobs = self.store.list(project, &TYPENAME)?;
 
@@ -566,6 +621,31 @@ mod events {
         Ok(EntryContents::Automerge(change))
     }
 
+    pub fn unlabel(
+        issue: &mut Automerge,
+        _author: &Urn,
+        labels: &[Label],
+    ) -> Result<EntryContents, AutomergeError> {
+        issue
+            .transact_with::<_, _, AutomergeError, _, ()>(
+                |_| CommitOptions::default().with_message("Unlabel issue".to_owned()),
+                |tx| {
+                    let (_, obj_id) = tx.get(ObjId::Root, "issue")?.unwrap();
+                    let (_, labels_id) = tx.get(&obj_id, "labels")?.unwrap();
+
+                    for label in labels {
+                        tx.delete(&labels_id, label.name().trim())?;
+                    }
+                    Ok(())
+                },
+            )
+            .map_err(|failure| failure.error)?;
+
+        let change = issue.get_last_local_change().unwrap().raw_bytes().to_vec();
+
+        Ok(EntryContents::Automerge(change))
+    }
+
     pub fn reply(
         issue: &mut Automerge,
         comment_id: CommentId,
@@ -602,6 +682,39 @@ mod events {
         Ok(EntryContents::Automerge(change))
     }
 
+    pub fn edit_comment(
+        issue: &mut Automerge,
+        comment_id: CommentId,
+        author: &Author,
+        body: &str,
+    ) -> Result<EntryContents, Error> {
+        issue
+            .transact_with::<_, _, Error, _, ()>(
+                |_| CommitOptions::default().with_message("Edit comment".to_owned()),
+                |tx| {
+                    let ix = usize::from(comment_id);
+                    let (_, obj_id) = tx.get(ObjId::Root, "issue")?.unwrap();
+                    let (_, discussion_id) = tx.get(&obj_id, "discussion")?.unwrap();
+                    let (_, comment_id) = tx
+                        .get(&discussion_id, ix)?
+                        .ok_or(Error::CommentNotFound(ix))?;
+                    let (comment_author, _) = tx.get(&comment_id, "author")?.unwrap();
+
+                    if comment_author.to_str() != Some(author.urn().to_string().as_str()) {
+                        return Err(Error::NotCommentAuthor);
+                    }
+                    tx.put(&comment_id, "body", body.trim())?;
+
+                    Ok(())
+                },
+            )
+            .map_err(|failure| failure.error)?;
+
+        let change = issue.get_last_local_change().unwrap().raw_bytes().to_vec();
+
+        Ok(EntryContents::Automerge(change))
+    }
+
     pub fn react(
         issue: &mut Automerge,
         comment_id: CommentId,
@@ -780,6 +893,34 @@ mod test {
         assert!(labels.contains(&wontfix_label));
     }
 
+    #[test]
+    fn test_issue_unlabel() {
+        let (storage, profile, whoami, project) = test::setup::profile();
+        let cobs = Store::new(whoami, profile.paths(), &storage);
+        let issues = cobs.issues();
+        let project = project.urn();
+        let bug_label = Label::new("bug").unwrap();
+        let wontfix_label = Label::new("wontfix").unwrap();
+        let issue_id = issues
+            .create(
+                &project,
+                "My first issue",
+                "Blah blah blah.",
+                &[bug_label.clone(), wontfix_label.clone()],
+            )
+            .unwrap();
+
+        issues
+            .unlabel(&project, &issue_id, &[wontfix_label.clone()])
+            .unwrap();
+
+        let issue = issues.get(&project, &issue_id).unwrap().unwrap();
+        let labels = issue.labels();
+
+        assert!(labels.contains(&bug_label));
+        assert!(!labels.contains(&wontfix_label));
+    }
+
     #[test]
     fn test_issue_comment() {
         let (storage, profile, whoami, project) = test::setup::profile();
@@ -810,6 +951,32 @@ mod test {
         assert!(c1.timestamp >= now);
     }
 
+    #[test]
+    fn test_issue_edit_comment() {
+        let (storage, profile, whoami, project) = test::setup::profile();
+        let cobs = Store::new(whoami, profile.paths(), &storage);
+        let issues = cobs.issues();
+        let issue_id = issues
+            .create(&project.urn(), "My first issue", "Blah blah blah.", &[])
+            .unwrap();
+
+        issues
+            .comment(&project.urn(), &issue_id, "Ho ho ho.")
+            .unwrap();
+        issues
+            .edit_comment(&project.urn(), &issue_id, CommentId::from(0), "Ha ha ha.")
+            .unwrap();
+
+        let issue = issues.get(&project.urn(), &issue_id).unwrap().unwrap();
+        assert_eq!(issue.comments().len(), 1);
+        assert_eq!(&issue.comments()[0].body, "Ha ha ha.");
+
+        assert!(matches!(
+            issues.edit_comment(&project.urn(), &issue_id, CommentId::from(1), "Hi."),
+            Err(Error::CommentNotFound(1))
+        ));
+    }
+
     #[test]
     fn test_issue_resolve() {
         let (storage, profile, whoami, project) = test::setup::profile();
@@ -84,6 +84,9 @@ where
     pub author: Author,
     /// Title of the patch.
     pub title: String,
+    /// Description of the patch, if it was edited with [`PatchStore::edit`].
+    /// Otherwise, the description is the comment of the latest revision.
+    pub description: Option<String>,
     /// Current state of the patch.
     pub state: State,
     /// Target this patch is meant to be merged in.
@@ -122,7 +125,24 @@ impl Patch {
     }
 
     pub fn description(&self) -> &str {
-        self.latest().1.description()
+        self.description
+            .as_deref()
+            .unwrap_or_else(|| self.latest().1.description())
+    }
+
+    /// Reactions to the patch, aggregated over all revisions and their discussions.
+    pub fn reactions(&self) -> HashMap<Reaction, usize> {
+        let mut reactions = HashMap::new();
+
+        for revision in self.revisions.iter() {
+            let comments = std::iter::once(&revision.comment.reactions)
+                .chain(revision.discussion.iter().map(|c| &c.reactions));
+
+            for (reaction, count) in comments.flatten() {
+                *reactions.entry(*reaction).or_insert(0) += count;
+            }
+        }
+        reactions
     }
 
     pub fn resolve<S: AsRef<ReadOnly>>(&mut self, storage: &S) -> Result<(), ResolveError> {
@@ -152,6 +172,11 @@ impl TryFrom<Document<'_>> for Patch {
     fn try_from(doc: Document) -> Result<Self, Self::Error> {
         let (_obj, obj_id) = doc.get(automerge::ObjId::Root, "patch")?;
         let title = doc.val(&obj_id, "title")?;
+        let description = match doc.val(&obj_id, "description") {
+            Ok(description) => Some(description),
+            Err(DocumentError::PropertyNotFound(_)) => None,
+            Err(err) => return Err(err),
+        };
         let author = doc.val(&obj_id, "author")?;
         let peer = doc.val(&obj_id, "peer")?;
         let state = doc.val(&obj_id, "state")?;
@@ -166,6 +191,7 @@ impl TryFrom<Document<'_>> for Patch {
         Ok(Self {
             author,
             title,
+            description,
             state,
             target,
             labels,
@@ -225,6 +251,10 @@ impl<'a> PatchStore<'a> {
         self.store.resolve_id::<Patch>(project, identifier)
     }
 
+    pub fn remove(&self, project: &Urn, patch_id: &PatchId) -> Result<(), Error> {
+        self.store.remove::<Patch>(project, patch_id)
+    }
+
     pub fn create(
         &self,
         project: &Urn,
@@ -312,6 +342,31 @@ impl<'a> PatchStore<'a> {
         Ok(revision_ix)
     }
 
+    /// Edit the title and/or description of a patch. Revisions are not affected.
+    pub fn edit(
+        &self,
+        project: &Urn,
+        patch_id: &PatchId,
+        title: Option<&str>,
+        description: Option<&str>,
+    ) -> Result<(), Error> {
+        let mut patch = self
+            .get_raw(project, patch_id)?
+            .ok_or(Error::NotFound(*patch_id))?;
+        let changes = events::edit(&mut patch, title, description)?;
+
+        cobs::update(
+            *patch_id,
+            project,
+            "Edit patch",
+            changes,
+            &self.whoami,
+            self.store,
+        )?;
+
+        Ok(())
+    }
+
     pub fn reply(
         &self,
         project: &Urn,
@@ -348,6 +403,61 @@ impl<'a> PatchStore<'a> {
         Ok(())
     }
 
+    /// Edit the body of a comment. Only the comment author can do this.
+    pub fn edit_comment(
+        &self,
+        project: &Urn,
+        patch_id: &PatchId,
+        revision_ix: RevisionIx,
+        comment_id: CommentId,
+        body: &str,
+    ) -> Result<(), Error> {
+        let author = self.author();
+        let mut patch = self.get_raw(project, patch_id)?.unwrap();
+        let changes = events::edit_comment(&mut patch, revision_ix, comment_id, &author, body)?;
+
+        let _cob = self
+            .store
+            .update(
+                &self.whoami,
+                project,
+                UpdateObjectSpec {
+                    object_id: *patch_id,
+                    typename: TYPENAME.clone(),
+                    message: Some("Edit comment".to_owned()),
+                    changes,
+                },
+            )
+            .unwrap();
+
+        Ok(())
+    }
+
+    /// React to a revision, or to a comment in its discussion.
+    pub fn react(
+        &self,
+        project: &Urn,
+        patch_id: &PatchId,
+        revision_ix: RevisionIx,
+        comment_id: CommentId,
+        reaction: Reaction,
+    ) -> Result<(), Error> {
+        let author = self.whoami.urn();
+        let mut patch = self.get_raw(project, patch_id)?.unwrap();
+        let changes = events::react(&mut patch, revision_ix, comment_id, &author, &[reaction])?;
+
+        cobs::update(
+            *patch_id,
+            project,
+            "React",
+            changes,
+            &self.whoami,
+            self.store,
+        )?;
+
+        Ok(())
+    }
+
     pub fn review(
         &self,
         project: &Urn,
@@ -969,6 +1079,33 @@ mod events {
         Ok((revision_ix, EntryContents::Automerge(change)))
     }
 
+    pub fn edit(
+        patch: &mut Automerge,
+        title: Option<&str>,
+        description: Option<&str>,
+    ) -> Result<EntryContents, AutomergeError> {
+        patch
+            .transact_with::<_, _, AutomergeError, _, ()>(
+                |_| CommitOptions::default().with_message("Edit patch".to_owned()),
+                |tx| {
+                    let (_, obj_id) = tx.get(ObjId::Root, "patch")?.unwrap();
+
+                    if let Some(title) = title {
+                        tx.put(&obj_id, "title", title.trim())?;
+                    }
+                    if let Some(description) = description {
+                        tx.put(&obj_id, "description", description.trim())?;
+                    }
+                    Ok(())
+                },
+            )
+            .map_err(|failure| failure.error)?;
+
+        let change = patch.get_last_local_change().unwrap().raw_bytes().to_vec();
+
+        Ok(EntryContents::Automerge(change))
+    }
+
     pub fn reply(
         patch: &mut Automerge,
         revision_ix: RevisionIx,
@@ -1008,6 +1145,42 @@ mod events {
         Ok(EntryContents::Automerge(change))
     }
 
+    pub fn edit_comment(
+        patch: &mut Automerge,
+        revision_ix: RevisionIx,
+        comment_id: CommentId,
+        author: &Author,
+        body: &str,
+    ) -> Result<EntryContents, Error> {
+        patch
+            .transact_with::<_, _, Error, _, ()>(
+                |_| CommitOptions::default().with_message("Edit comment".to_owned()),
+                |tx| {
+                    let ix = usize::from(comment_id);
+                    let (_, obj_id) = tx.get(ObjId::Root, "patch")?.unwrap();
+                    let (_, revisions_id) = tx.get(&obj_id, "revisions")?.unwrap();
+                    let (_, revision_id) = tx.get(&revisions_id, revision_ix)?.unwrap();
+                    let (_, discussion_id) = tx.get(&revision_id, "discussion")?.unwrap();
+                    let (_, comment_id) = tx
+                        .get(&discussion_id, ix)?
+                        .ok_or(Error::CommentNotFound(ix))?;
+                    let (comment_author, _) = tx.get(&comment_id, "author")?.unwrap();
+
+                    if comment_author.to_str() != Some(author.urn().to_string().as_str()) {
+                        return Err(Error::NotCommentAuthor);
+                    }
+                    tx.put(&comment_id, "body", body.trim())?;
+
+                    Ok(())
+                },
+            )
+            .map_err(|failure| failure.error)?;
+
+        let change = patch.get_last_local_change().unwrap().raw_bytes().to_vec();
+
+        Ok(EntryContents::Automerge(change))
+    }
+
     pub fn review(
         patch: &mut Automerge,
         revision_ix: RevisionIx,
@@ -1037,6 +1210,53 @@ mod events {
         Ok(((), EntryContents::Automerge(change)))
     }
 
+    pub fn react(
+        patch: &mut Automerge,
+        revision_ix: RevisionIx,
+        comment_id: CommentId,
+        author: &Urn,
+        reactions: &[Reaction],
+    ) -> Result<EntryContents, Error> {
+        patch
+            .transact_with::<_, _, Error, _, ()>(
+                |_| CommitOptions::default().with_message("React".to_owned()),
+                |tx| {
+                    let (_, obj_id) = tx.get(ObjId::Root, "patch")?.unwrap();
+                    let (_, revisions_id) = tx.get(&obj_id, "revisions")?.unwrap();
+                    let (_, revision_id) = tx.get(&revisions_id, revision_ix)?.unwrap();
+                    let (_, discussion_id) = tx.get(&revision_id, "discussion")?.unwrap();
+                    let (_, comment_id) = if comment_id == CommentId::root() {
+                        tx.get(&revision_id, "comment")?.unwrap()
+                    } else {
+                        let ix = usize::from(comment_id) - 1;
+
+                        tx.get(&discussion_id, ix)?
+                            .ok_or(Error::CommentNotFound(ix))?
+                    };
+                    let (_, reactions_id) = tx.get(&comment_id, "reactions")?.unwrap();
+
+                    for reaction in reactions {
+                        let key = reaction.emoji.to_string();
+                        let reaction_id = if let Some((_, reaction_id)) =
+                            tx.get(&reactions_id, key)?
+                        {
+                            reaction_id
+                        } else {
+                            tx.put_object(&reactions_id, reaction.emoji.to_string(), ObjType::Map)?
+                        };
+                        tx.put(&reaction_id, author.to_string(), true)?;
+                    }
+
+                    Ok(())
+                },
+            )
+            .map_err(|failure| failure.error)?;
+
+        let change = patch.get_last_local_change().unwrap().raw_bytes().to_vec();
+
+        Ok(EntryContents::Automerge(change))
+    }
+
     pub fn merge(
         patch: &mut Automerge,
         revision_ix: RevisionIx,
@@ -1143,6 +1363,45 @@ mod test {
         assert_eq!(merges[0].commit, base);
     }
 
+    #[test]
+    fn test_patch_react() {
+        let (storage, profile, whoami, project) = test::setup::profile();
+        let cobs = Store::new(whoami, profile.paths(), &storage);
+        let patches = cobs.patches();
+        let target = MergeTarget::Upstream;
+        let oid = git::Oid::from(git2::Oid::zero());
+        let base = git::Oid::from_str("cb18e95ada2bb38aadd8e6cef0963ce37a87add3").unwrap();
+        let project = &project.urn();
+        let patch_id = patches
+            .create(
+                project,
+                "My first patch",
+                "Blah blah blah.",
+                target,
+                base,
+                oid,
+                &[],
+            )
+            .unwrap();
+
+        patches.comment(project, &patch_id, 0, "Nice.").unwrap();
+
+        let reaction = Reaction::new('🥳').unwrap();
+        patches
+            .react(project, &patch_id, 0, CommentId::root(), reaction)
+            .unwrap();
+        patches
+            .react(project, &patch_id, 0, CommentId::from(1), reaction)
+            .unwrap();
+
+        let patch = patches.get(project, &patch_id).unwrap().unwrap();
+        let revision = patch.revisions.head.clone();
+
+        assert_eq!(revision.comment.reactions[&reaction], 1);
+        assert_eq!(revision.discussion[0].reactions[&reaction], 1);
+        assert_eq!(patch.reactions()[&reaction], 2);
+    }
+
     #[test]
     fn test_patch_review() {
         let (storage, profile, whoami, project) = test::setup::profile();
@@ -1221,4 +1480,48 @@ mod test {
         assert_eq!(revision.oid, rev1_oid);
         assert_eq!(revision.description(), "I've made changes.");
     }
+
+    #[test]
+    fn test_patch_edit() {
+        let (storage, profile, whoami, project) = test::setup::profile();
+        let cobs = Store::new(whoami, profile.paths(), &storage);
+        let patches = cobs.patches();
+        let target = MergeTarget::Upstream;
+        let base = git::Oid::from_str("af08e95ada2bb38aadd8e6cef0963ce37a87add3").unwrap();
+        let rev0_oid = git::Oid::from_str("518d5069f94c03427f694bb494ac1cd7d1339380").unwrap();
+        let rev1_oid = git::Oid::from_str("cb18e95ada2bb38aadd8e6cef0963ce37a87add3").unwrap();
+        let project = &project.urn();
+        let patch_id = patches
+            .create(
+                project,
+                "My frist patch",
+                "Blah blah blah.",
+                target,
+                base,
+                rev0_oid,
+                &[],
+            )
+            .unwrap();
+
+        patches
+            .edit(project, &patch_id, Some("My first patch"), None)
+            .unwrap();
+
+        let patch = patches.get(project, &patch_id).unwrap().unwrap();
+        assert_eq!(patch.title, "My first patch");
+        assert_eq!(patch.description(), "Blah blah blah.");
+
+        patches
+            .update(project, &patch_id, "I've made changes.", base, rev1_oid)
+            .unwrap();
+        patches
+            .edit(project, &patch_id, None, Some("Fixes everything."))
+            .unwrap();
+
+        let patch = patches.get(project, &patch_id).unwrap().unwrap();
+        assert_eq!(patch.title, "My first patch");
+        assert_eq!(patch.description(), "Fixes everything.");
+        assert_eq!(patch.version(), 1);
+        assert_eq!(patch.latest().1.description(), "I've made changes.");
+    }
 }
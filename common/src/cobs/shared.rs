@@ -5,9 +5,9 @@ use std::convert::{Infallible, TryFrom};
 use std::fmt;
 use std::hash::Hash;
 use std::ops::Deref;
+use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::Arc;
-use std::time;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use automerge::transaction::Transactable;
@@ -39,6 +39,14 @@ pub enum Error {
     Retrieve(#[from] collaborative_objects::error::Retrieve),
     #[error(transparent)]
     Automerge(#[from] AutomergeError),
+    #[error(transparent)]
+    Git(#[from] git2::Error),
+    #[error("object {0} was not found")]
+    NotFound(ObjectId),
+    #[error("comment {0} was not found")]
+    CommentNotFound(usize),
+    #[error("only the author of a comment can edit it")]
+    NotCommentAuthor,
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -103,6 +111,7 @@ pub struct Store<'a> {
     pub peer_id: PeerId,
 
     store: CollaborativeObjects<'a>,
+    monorepo: PathBuf,
 }
 
 impl<'a> Deref for Store<'a> {
@@ -117,11 +126,13 @@ impl<'a> Store<'a> {
     pub fn new(whoami: LocalIdentity, paths: &Paths, storage: &'a Storage) -> Self {
         let store = storage.collaborative_objects(Some(paths.cob_cache_dir().to_path_buf()));
         let peer_id = *storage.peer_id();
+        let monorepo = storage.as_ref().path().to_path_buf();
 
         Self {
             store,
             whoami,
             peer_id,
+            monorepo,
         }
     }
 
@@ -141,6 +152,27 @@ impl<'a> Store<'a> {
         user::UserStore::new(self)
     }
 
+    /// Remove the local copy of an object. Copies held by tracked peers are not affected.
+    pub fn remove<T: Cob>(&self, namespace: &Urn, id: &ObjectId) -> Result<(), Error> {
+        let repo = git2::Repository::open_bare(&self.monorepo)?;
+        let name = format!(
+            "refs/namespaces/{}/refs/cobs/{}/{}",
+            namespace.encode_id(),
+            T::type_name(),
+            id
+        );
+        let mut reference = repo.find_reference(&name).map_err(|e| {
+            if e.code() == git2::ErrorCode::NotFound {
+                Error::NotFound(*id)
+            } else {
+                Error::Git(e)
+            }
+        })?;
+        reference.delete()?;
+
+        Ok(())
+    }
+
     pub fn get<T: Cob>(&self, namespace: &Urn, id: &ObjectId) -> anyhow::Result<Option<T>> {
         let cob = self.store.retrieve(namespace, T::type_name(), id)?;
 
@@ -530,12 +562,9 @@ impl Timestamp {
 }
 
 impl fmt::Display for Timestamp {
+    /// Formats the timestamp relative to now, see [`crate::fmt::relative`].
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let fmt = timeago::Formatter::new();
-        let now = Timestamp::now();
-        let duration = time::Duration::from_secs(now.seconds - self.seconds);
-
-        write!(f, "{}", fmt.convert(duration))
+        write!(f, "{}", crate::fmt::relative(self))
     }
 }
 
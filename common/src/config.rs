@@ -13,6 +13,7 @@ use url::{Host, Url};
 use crate::seed::{
     Address, Protocol, DEFAULT_SEED_API_PORT, DEFAULT_SEED_GIT_PORT, DEFAULT_SEED_P2P_PORT,
 };
+use crate::signer::SignerConfig;
 use crate::sync::Seed;
 
 pub const DEFAULT_SEEDS: &[(&str, &str)] = &[
@@ -58,9 +59,46 @@ impl TryFrom<SeedConfig> for Seed<String> {
     }
 }
 
+impl TryFrom<Seed<String>> for SeedConfig {
+    type Error = anyhow::Error;
+
+    fn try_from(seed: Seed<String>) -> Result<Self, Self::Error> {
+        let (host, port) = match seed.addrs.rsplit_once(':') {
+            Some((host, port)) => (host, Some(port.parse::<u16>()?)),
+            None => (seed.addrs.as_str(), None),
+        };
+        let host = Host::parse(host)?;
+
+        let mut p2p: Url = Address::new(
+            host.clone(),
+            Protocol::Link {
+                peer: Some(seed.peer),
+            },
+        )
+        .into();
+        let mut git: Url = Address::new(host.clone(), Protocol::Git { local: false }).into();
+        let mut api: Url = Address::new(host.clone(), Protocol::Api { local: false }).into();
+
+        p2p.set_port(Some(port.unwrap_or(DEFAULT_SEED_P2P_PORT)))
+            .ok();
+        git.set_port(Some(DEFAULT_SEED_GIT_PORT)).ok();
+        api.set_port(Some(DEFAULT_SEED_API_PORT)).ok();
+
+        Ok(SeedConfig {
+            name: seed.label.or_else(|| Some(host.to_string())),
+            p2p,
+            git,
+            api,
+        })
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Config {
     pub seed: Vec<SeedConfig>,
+    /// Signer configuration. Uses ssh-agent if not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signer: Option<SignerConfig>,
 }
 
 impl Default for Config {
@@ -93,6 +131,7 @@ impl Default for Config {
                     }
                 })
                 .collect(),
+            signer: None,
         }
     }
 }
@@ -29,6 +29,9 @@ use crate::args;
 /// Radicle's ENS domain.
 pub const RADICLE_DOMAIN: &str = ".radicle.eth";
 
+/// Chain id of the Ethereum mainnet.
+pub const MAINNET_CHAIN_ID: u64 = 1;
+
 pub const SIGNER_OPTIONS: &str = r#"
     --ledger-hdpath <hdpath>     Account derivation path when using a Ledger hardware device
     --keystore <file>            Keystore file containing encrypted private key (default: none)
@@ -37,11 +40,13 @@ pub const SIGNER_OPTIONS: &str = r#"
 
 pub const PROVIDER_OPTIONS: &str = r#"
     --rpc-url <url>              JSON-RPC URL of Ethereum node (eg. http://localhost:8545)
+    --chain <name|id>            Chain to use: mainnet, goerli, sepolia or a chain id (default: mainnet)
 "#;
 
 pub const ENVIRONMENT_VARIABLES: &str = r#"
-    ETH_RPC_URL  Ethereum JSON-RPC URL (overwrite with '--rpc-url')
-    ETH_HDPATH   Hardware wallet derivation path (overwrite with '--ledger-hdpath')
+    ETH_RPC_URL          Ethereum JSON-RPC URL (overwrite with '--rpc-url')
+    ETH_RPC_URL_<CHAIN>  JSON-RPC URL for a specific chain, eg. 'ETH_RPC_URL_SEPOLIA' (takes precedence over 'ETH_RPC_URL')
+    ETH_HDPATH           Hardware wallet derivation path (overwrite with '--ledger-hdpath')
 "#;
 
 /// Command-line ethereum signer options.
@@ -93,9 +98,21 @@ impl SignerOptions {
 }
 
 /// Command-line ethereum provider options.
-#[derive(Default, Debug)]
+#[derive(Debug)]
 pub struct ProviderOptions {
+    /// JSON-RPC URL of the Ethereum node (default: taken from the environment).
     pub rpc_url: Option<String>,
+    /// Chain the provider must be on (default: mainnet).
+    pub chain: u64,
+}
+
+impl Default for ProviderOptions {
+    fn default() -> Self {
+        Self {
+            rpc_url: None,
+            chain: MAINNET_CHAIN_ID,
+        }
+    }
 }
 
 impl ProviderOptions {
@@ -113,6 +130,14 @@ impl ProviderOptions {
 
                     options.rpc_url = Some(args::parse_value(&flag, value)?);
                 }
+                Long("chain") => {
+                    let value = parser.value()?;
+                    let value = value.to_str().ok_or_else(|| {
+                        anyhow::anyhow!("the value specified for '--chain' is not UTF-8")
+                    })?;
+
+                    options.chain = parse_chain(value)?;
+                }
                 _ => unparsed.push(args::format(arg)),
             }
         }
@@ -121,16 +146,19 @@ impl ProviderOptions {
 }
 
 /// Create a provider from provider options.
+///
+/// The JSON-RPC URL is taken from `--rpc-url`, then from `ETH_RPC_URL_<CHAIN>` for the
+/// selected chain, and finally from `ETH_RPC_URL`.
 pub fn provider(cfg: ProviderOptions) -> anyhow::Result<Provider<Http>> {
     let rpc_url = if let Some(url) = cfg.rpc_url {
         url
     } else {
-        env::var("ETH_RPC_URL")
-            .ok()
-            .and_then(|url| if url.is_empty() { None } else { Some(url) })
-            .ok_or_else(|| {
-                anyhow::anyhow!("'ETH_RPC_URL' must be set to an Ethereum JSON-RPC URL")
-            })?
+        rpc_url_from_env(cfg.chain).ok_or_else(|| {
+            anyhow::anyhow!(
+                "'ETH_RPC_URL' or '{}' must be set to an Ethereum JSON-RPC URL",
+                rpc_url_var(cfg.chain)
+            )
+        })?
     };
 
     let provider =
@@ -139,6 +167,33 @@ pub fn provider(cfg: ProviderOptions) -> anyhow::Result<Provider<Http>> {
     Ok(provider)
 }
 
+/// Get the JSON-RPC URL for the given chain from the environment.
+fn rpc_url_from_env(chain: u64) -> Option<String> {
+    let var = |name: &str| env::var(name).ok().filter(|url| !url.is_empty());
+
+    var(&rpc_url_var(chain)).or_else(|| var("ETH_RPC_URL"))
+}
+
+/// Name of the environment variable holding the JSON-RPC URL of the given chain,
+/// eg. `ETH_RPC_URL_SEPOLIA`.
+fn rpc_url_var(chain: u64) -> String {
+    let suffix = chain_name(chain)
+        .map(|name| name.to_uppercase())
+        .unwrap_or_else(|| chain.to_string());
+
+    format!("ETH_RPC_URL_{}", suffix)
+}
+
+/// Get the name of a chain, if it is one we know.
+pub fn chain_name(id: u64) -> Option<&'static str> {
+    match id {
+        MAINNET_CHAIN_ID => Some("mainnet"),
+        5 => Some("goerli"),
+        11155111 => Some("sepolia"),
+        _ => None,
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum WalletError {
     #[error(transparent)]
@@ -228,11 +283,48 @@ pub fn chain_from_id(id: u64) -> Option<Chain> {
         3 => Some(Chain::Ropsten),
         4 => Some(Chain::Rinkeby),
         5 => Some(Chain::Goerli),
+        11155111 => Some(Chain::Sepolia),
         _ => None,
     }
 }
 
+/// Parse an Ethereum chain name or id, eg. `mainnet`, `goerli`, `sepolia` or `5`.
+pub fn parse_chain(s: &str) -> anyhow::Result<u64> {
+    match s {
+        "mainnet" => Ok(MAINNET_CHAIN_ID),
+        "goerli" => Ok(5),
+        "sepolia" => Ok(11155111),
+        id => id
+            .parse()
+            .map_err(|_| anyhow::anyhow!("invalid chain '{}', expected a chain name or id", id)),
+    }
+}
+
 /// Hex-encode bytes into a `0x`-prefixed string.
 pub fn hex(bytes: impl AsRef<[u8]>) -> String {
     format!("0x{}", hex::encode(bytes))
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rpc_url_var() {
+        assert_eq!(rpc_url_var(MAINNET_CHAIN_ID), "ETH_RPC_URL_MAINNET");
+        assert_eq!(rpc_url_var(11155111), "ETH_RPC_URL_SEPOLIA");
+        assert_eq!(rpc_url_var(31337), "ETH_RPC_URL_31337");
+    }
+
+    #[test]
+    fn test_provider_options_default_chain() {
+        let (options, _) =
+            ProviderOptions::from(lexopt::Parser::from_args(Vec::<OsString>::new())).unwrap();
+        assert_eq!(options.chain, MAINNET_CHAIN_ID);
+
+        let (options, _) =
+            ProviderOptions::from(lexopt::Parser::from_args(["--chain", "sepolia"])).unwrap();
+        assert_eq!(options.chain, 11155111);
+        assert_eq!(chain_from_id(options.chain), Some(Chain::Sepolia));
+    }
+}
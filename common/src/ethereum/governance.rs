@@ -80,7 +80,7 @@ where
         Ok(proposal)
     }
 
-    async fn get_proposal_state(&self, id: U256) -> Result<ProposalState, Error<M>> {
+    pub async fn get_proposal_state(&self, id: U256) -> Result<ProposalState, Error<M>> {
         let state = self
             .contract
             .method("state", id)
@@ -0,0 +1,111 @@
+//! Process exit codes.
+use std::io;
+
+use crate::args;
+use crate::cobs::shared::ResolveError;
+use crate::project::CwdError;
+use crate::sync::SyncError;
+
+/// Exit code of a command, allowing scripts to distinguish classes of errors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExitCode {
+    /// The command succeeded.
+    Success = 0,
+    /// The command failed for an unclassified reason.
+    Failure = 1,
+    /// The command was invoked incorrectly, eg. with an unknown option.
+    Usage = 2,
+    /// An object, eg. a project, patch or reference, could not be found or resolved.
+    NotFound = 3,
+    /// A network or sync operation failed.
+    Network = 4,
+    /// Authentication failed, eg. the signing key is not available.
+    Auth = 5,
+}
+
+impl ExitCode {
+    /// The numeric code, to be passed to [`std::process::exit`].
+    pub fn code(self) -> i32 {
+        self as i32
+    }
+}
+
+impl From<&anyhow::Error> for ExitCode {
+    /// Classify an error by the type of the first cause that can be classified.
+    fn from(err: &anyhow::Error) -> Self {
+        for cause in err.chain() {
+            if let Some(err) = cause.downcast_ref::<args::Error>() {
+                match err {
+                    args::Error::Help => return Self::Success,
+                    args::Error::Usage => return Self::Usage,
+                    args::Error::WithHint { err, .. } => return Self::from(err),
+                }
+            }
+            if cause.is::<lexopt::Error>() {
+                return Self::Usage;
+            }
+            if let Some(CwdError::NotRepository(_) | CwdError::NoRadRemote) = cause.downcast_ref() {
+                return Self::NotFound;
+            }
+            if let Some(ResolveError::NotFound { .. }) = cause.downcast_ref::<ResolveError>() {
+                return Self::NotFound;
+            }
+            if let Some(err) = cause.downcast_ref::<git2::Error>() {
+                if err.code() == git2::ErrorCode::NotFound {
+                    return Self::NotFound;
+                }
+                if err.class() == git2::ErrorClass::Net {
+                    return Self::Network;
+                }
+                if err.code() == git2::ErrorCode::Auth {
+                    return Self::Auth;
+                }
+            }
+            if cause.is::<SyncError>() {
+                return Self::Network;
+            }
+            if cause.is::<ureq::Error>() {
+                return Self::Network;
+            }
+            if cause.is::<lnk_clib::keys::ssh::Error>() {
+                return Self::Auth;
+            }
+            if let Some(err) = cause.downcast_ref::<io::Error>() {
+                match err.kind() {
+                    io::ErrorKind::NotFound => return Self::NotFound,
+                    io::ErrorKind::PermissionDenied => return Self::Auth,
+                    io::ErrorKind::ConnectionRefused
+                    | io::ErrorKind::ConnectionReset
+                    | io::ErrorKind::ConnectionAborted
+                    | io::ErrorKind::NotConnected
+                    | io::ErrorKind::TimedOut => return Self::Network,
+                    _ => {}
+                }
+            }
+        }
+        Self::Failure
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use anyhow::Context as _;
+
+    #[test]
+    fn test_sync_error_exit_code() {
+        let err = anyhow::Error::from(SyncError::NoSeeds);
+        assert_eq!(ExitCode::from(&err), ExitCode::Network);
+
+        let err = anyhow::Error::from(SyncError::AllFailed(2));
+        assert_eq!(ExitCode::from(&err), ExitCode::Network);
+
+        let err = Err::<(), _>(SyncError::AllFailed(1))
+            .context("failed to sync patch")
+            .unwrap_err();
+        assert_eq!(ExitCode::from(&err), ExitCode::Network);
+
+        let err = anyhow::anyhow!("something else");
+        assert_eq!(ExitCode::from(&err), ExitCode::Failure);
+    }
+}
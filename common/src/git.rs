@@ -1,23 +1,25 @@
 //! Git-related functions and types.
 use std::collections::HashSet;
+use std::ffi::OsString;
 use std::fs::{File, OpenOptions};
 use std::io;
-use std::io::Write;
+use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
-use std::process::Command;
+use std::process::{Command, Stdio};
 use std::str::FromStr;
 
 use anyhow::anyhow;
 use anyhow::Context as _;
 
+use librad::crypto::keystore::pinentry::SecUtf8;
 use librad::git::local::url::LocalUrl;
 use librad::profile::Profile;
 use librad::reflike;
 use librad::{crypto::BoxedSigner, PeerId};
 
 pub use git2::{
-    build::CheckoutBuilder, AnnotatedCommit, Commit, Direction, ErrorCode, MergeAnalysis,
-    MergeOptions, Oid, Reference, Repository, Signature,
+    build::CheckoutBuilder, AnnotatedCommit, BranchType, Commit, DiffFormat, Direction, ErrorCode,
+    MergeAnalysis, MergeOptions, Oid, Reference, Repository, Signature,
 };
 pub use librad::git::local::transport;
 pub use librad::git::types::remote::LocalFetchspec;
@@ -27,6 +29,18 @@ pub use lnk_identities::git::set_upstream;
 
 use crate::keys;
 
+/// Credential helper that answers with the credentials set in the environment.
+const CREDENTIAL_HELPER: &str = "credential.helper=!f() { \
+    echo \"username=$RAD_GIT_USERNAME\"; echo \"password=$RAD_GIT_PASSWORD\"; }; f";
+/// Git output signaling that authentication with the remote failed.
+const AUTH_ERRORS: &[&str] = &[
+    "Authentication failed",
+    "could not read Username",
+    "could not read Password",
+    "Permission denied (publickey",
+    "terminal prompts disabled",
+];
+
 pub const CONFIG_COMMIT_GPG_SIGN: &str = "commit.gpgsign";
 pub const CONFIG_SIGNING_KEY: &str = "user.signingkey";
 pub const CONFIG_GPG_FORMAT: &str = "gpg.format";
@@ -41,7 +55,7 @@ pub const VERSION_REQUIRED: Version = Version {
 };
 
 /// A parsed git version.
-#[derive(PartialEq, Eq, Debug, PartialOrd, Ord)]
+#[derive(PartialEq, Eq, Debug, PartialOrd, Ord, Clone, Copy)]
 pub struct Version {
     pub major: u8,
     pub minor: u8,
@@ -294,6 +308,20 @@ pub fn branch_remote(repo: &Repository, branch: &str) -> anyhow::Result<String>
     Ok(remote)
 }
 
+/// Get the name of the local branch tracking the given remote, if any.
+pub fn tracking_branch(repo: &Repository, remote: &str) -> anyhow::Result<Option<String>> {
+    for branch in repo.branches(Some(git2::BranchType::Local))? {
+        let (branch, _) = branch?;
+
+        if let Some(name) = branch.name()? {
+            if matches!(branch_remote(repo, name), Ok(r) if r == remote) {
+                return Ok(Some(name.to_owned()));
+            }
+        }
+    }
+    Ok(None)
+}
+
 /// Call `git pull`, optionally with `--force`.
 pub fn pull(repo: &Path, force: bool) -> anyhow::Result<String> {
     let mut args = vec!["-c", "color.diff=always", "pull", "-v"];
@@ -321,22 +349,175 @@ pub fn fetch_remote(
     Ok(())
 }
 
+/// Credentials used to authenticate with a private git remote.
+#[derive(Debug)]
+pub enum Credentials {
+    /// Username and password or access token, for HTTPS remotes.
+    Password { username: String, password: SecUtf8 },
+    /// SSH private key file, tried after the keys in ssh-agent.
+    SshKey(PathBuf),
+}
+
 /// Clone the given repository via `git clone` into a directory.
-pub fn clone(repo: &str, destination: &Path) -> Result<String, anyhow::Error> {
-    git(
-        Path::new("."),
-        ["clone", repo, &destination.to_string_lossy()],
+/// If a depth is given, a shallow clone with that many commits is performed.
+///
+/// Without credentials, git authenticates with ssh-agent and the default SSH key files,
+/// or any configured credential helper. Git is never allowed to prompt: if authentication
+/// fails, an error for which [`is_auth_error`] holds is returned, and the clone can be
+/// retried with credentials.
+///
+/// Transfer and checkout progress reported by git is passed line by line to `progress`,
+/// eg. `Receiving objects:  45% (450/1000)`.
+pub fn clone(
+    repo: &str,
+    destination: &Path,
+    depth: Option<u32>,
+    credentials: Option<&Credentials>,
+    mut progress: impl FnMut(&str),
+) -> Result<(), anyhow::Error> {
+    let mut cmd = Command::new("git");
+    cmd.env("GIT_TERMINAL_PROMPT", "0");
+
+    match credentials {
+        // The credentials are passed through the environment so that they don't show up in
+        // the process arguments.
+        Some(Credentials::Password { username, password }) => {
+            cmd.args(["-c", "credential.helper="])
+                .args(["-c", CREDENTIAL_HELPER])
+                .env("RAD_GIT_USERNAME", username)
+                .env("RAD_GIT_PASSWORD", password.unsecure());
+        }
+        Some(Credentials::SshKey(path)) => {
+            let mut ssh = OsString::from("ssh -i ");
+            ssh.push(path);
+            cmd.env("GIT_SSH_COMMAND", ssh);
+        }
+        None => {}
+    }
+
+    let mut args = vec![OsString::from("clone"), OsString::from("--progress")];
+
+    if let Some(depth) = depth {
+        args.push(format!("--depth={}", depth).into());
+    }
+    args.push(repo.into());
+    args.push(destination.into());
+
+    let mut child = cmd
+        .args(args)
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    let mut stderr = child
+        .stderr
+        .take()
+        .ok_or_else(|| anyhow!("couldn't capture git output"))?;
+
+    // Progress lines are terminated by a carriage return while they are being updated.
+    let mut output = Vec::new();
+    let mut line = Vec::new();
+    let mut buf = [0; 1024];
+    loop {
+        let n = stderr.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        for byte in &buf[..n] {
+            if *byte == b'\r' || *byte == b'\n' {
+                let text = String::from_utf8_lossy(&line);
+                let text = text.trim();
+
+                if !text.is_empty() {
+                    progress(text);
+                }
+                output.append(&mut line);
+                output.push(b'\n');
+            } else {
+                line.push(*byte);
+            }
+        }
+    }
+    output.append(&mut line);
+
+    if child.wait()?.success() {
+        return Ok(());
+    }
+    let output = String::from_utf8_lossy(&output);
+    let kind = if AUTH_ERRORS.iter().any(|e| output.contains(e)) {
+        std::io::ErrorKind::PermissionDenied
+    } else {
+        std::io::ErrorKind::Other
+    };
+
+    Err(anyhow::Error::new(std::io::Error::new(kind, output)))
+}
+
+/// Check whether an error returned by [`clone`] is an authentication failure.
+pub fn is_auth_error(err: &anyhow::Error) -> bool {
+    matches!(
+        err.downcast_ref::<std::io::Error>(),
+        Some(e) if e.kind() == std::io::ErrorKind::PermissionDenied
     )
 }
 
-/// Check that the system's git version is supported. Returns an error otherwise.
-pub fn check_version() -> Result<Version, anyhow::Error> {
-    let git_version = self::version()?;
+/// The git environment: the git CLI and the libgit2 library we're linked against.
+#[derive(Debug, Clone)]
+pub struct Environment {
+    /// Version of the git CLI.
+    pub git: Version,
+    /// Version of libgit2.
+    pub libgit2: Version,
+    /// Whether libgit2 supports the HTTPS transport, used to query seeds.
+    pub https: bool,
+    /// Whether libgit2 supports the SSH transport. SSH remotes are handled by the git CLI,
+    /// so this isn't required.
+    pub ssh: bool,
+}
+
+/// An unsupported git environment.
+#[derive(thiserror::Error, Debug)]
+pub enum EnvironmentError {
+    #[error("unable to determine git version: {0}")]
+    Git(String),
+    #[error(
+        "git version {0} is unsupported, please upgrade to {required} or later",
+        required = VERSION_REQUIRED
+    )]
+    Unsupported(Version),
+    #[error("libgit2 {0} was built without HTTPS support")]
+    NoHttps(Version),
+}
+
+/// Get the git environment, without checking whether it is supported.
+pub fn environment() -> Result<Environment, anyhow::Error> {
+    let git = self::version()?;
+    let lib = git2::Version::get();
+    let (major, minor, patch) = lib.libgit2_version();
+
+    Ok(Environment {
+        git,
+        libgit2: Version {
+            major: major as u8,
+            minor: minor as u8,
+            patch: patch as u8,
+        },
+        https: lib.https(),
+        ssh: lib.ssh(),
+    })
+}
 
-    if git_version < VERSION_REQUIRED {
-        anyhow::bail!("a minimum git version of {} is required", VERSION_REQUIRED);
+/// Check that the system's git version and the libgit2 build are supported.
+/// Returns the environment found, or what is unsupported about it.
+pub fn check_version() -> Result<Environment, EnvironmentError> {
+    let env = environment().map_err(|e| EnvironmentError::Git(e.to_string()))?;
+
+    if env.git < VERSION_REQUIRED {
+        return Err(EnvironmentError::Unsupported(env.git));
+    }
+    if !env.https {
+        return Err(EnvironmentError::NoHttps(env.libgit2));
     }
-    Ok(git_version)
+    Ok(env)
 }
 
 /// Parse a remote refspec into a peer id and ref.
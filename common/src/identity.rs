@@ -1,8 +1,8 @@
 use std::convert::TryFrom;
 use std::str::FromStr;
 
-use anyhow::anyhow;
-use url::Url;
+use anyhow::{anyhow, Context as _};
+use url::{Host, Url};
 
 use librad::git::Urn;
 use librad::PeerId;
@@ -21,12 +21,42 @@ pub struct Origin {
     pub urn: Urn,
     /// If available, the address of a seed which has this project.
     pub seed: Option<sync::Seed<String>>,
+    /// If available, the `<host>:<port>` address of a seed which has this project, but
+    /// whose peer id wasn't given, eg. with `rad://<host>/<urn>`.
+    pub host: Option<String>,
 }
 
 impl Origin {
     /// Create an origin from a URN.
     pub fn from_urn(urn: Urn) -> Self {
-        Self { urn, seed: None }
+        Self {
+            urn,
+            seed: None,
+            host: None,
+        }
+    }
+
+    /// Get the seed of this origin. If only the seed host is known, the seed's peer id is
+    /// looked up via its HTTP API, ie. whatever peer the host serves is trusted.
+    pub fn resolve_seed(&self) -> anyhow::Result<Option<sync::Seed<String>>> {
+        if let Some(seed) = &self.seed {
+            return Ok(Some(seed.clone()));
+        }
+        if let Some(addrs) = &self.host {
+            let host = addrs
+                .rsplit_once(':')
+                .map_or(addrs.as_str(), |(host, _)| host);
+            let url = Url::parse(&format!("https://{}", host))?;
+            let peer = seed::get_seed_id(url)
+                .with_context(|| format!("failed to get the peer id of seed '{}'", host))?;
+
+            return Ok(Some(sync::Seed {
+                peer,
+                addrs: addrs.clone(),
+                label: None,
+            }));
+        }
+        Ok(None)
     }
 }
 
@@ -35,7 +65,7 @@ impl FromStr for Origin {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if let Ok(urn) = Urn::from_str(s) {
-            Ok(Self { urn, seed: None })
+            Ok(Self::from_urn(urn))
         } else if let Ok(url) = Url::from_str(s) {
             Self::try_from(url)
         } else {
@@ -58,24 +88,40 @@ impl TryFrom<Url> for Origin {
             anyhow::bail!("not a radicle URL '{}': invalid scheme", url.to_string());
         }
 
-        let host = url.host();
         let port = url.port().unwrap_or(seed::DEFAULT_SEED_P2P_PORT);
+        let host = match url.host() {
+            Some(Host::Domain(domain)) if !sync::is_valid_domain(domain) => {
+                anyhow::bail!(
+                    "invalid radicle URL '{}': malformed host '{}'",
+                    url.to_string(),
+                    domain
+                );
+            }
+            Some(host) => Some(format!("{}:{}", host, port)),
+            None => None,
+        };
 
         let peer = url.username();
-        if peer.is_empty() {
-            anyhow::bail!(
-                "invalid radicle URL '{}': peer id required",
-                url.to_string()
-            );
-        }
-        let peer = PeerId::from_str(peer)
-            .map_err(|_| anyhow::anyhow!("invalid radicle URL '{}': invalid peer id", peer))?;
+        let (seed, host) = if peer.is_empty() {
+            if host.is_none() {
+                anyhow::bail!(
+                    "invalid radicle URL '{}': peer id or host required",
+                    url.to_string()
+                );
+            }
+            // Without a peer id, we trust whatever peer the host serves.
+            (None, host)
+        } else {
+            let peer = PeerId::from_str(peer)
+                .map_err(|_| anyhow::anyhow!("invalid radicle URL '{}': invalid peer id", peer))?;
+            let seed = host.map(|addrs| sync::Seed {
+                peer,
+                addrs,
+                label: None,
+            });
 
-        let seed = host.map(|h| sync::Seed {
-            peer,
-            addrs: format!("{}:{}", h, port),
-            label: None,
-        });
+            (seed, None)
+        };
 
         let urn = if let Some(id) = segments.next() {
             if id.is_empty() {
@@ -86,7 +132,7 @@ impl TryFrom<Url> for Origin {
             anyhow::bail!("invalid radicle URL '{}': missing path", url.to_string());
         };
 
-        Ok(Self { urn, seed })
+        Ok(Self { urn, seed, host })
     }
 }
 
@@ -97,11 +143,25 @@ mod test {
 
     #[test]
     fn test_origin_from_url() {
+        let expected_urn = Urn::try_from_id("hnrkbjg7r54q48sqsaho1n4qfxhi4nbmdh51y").unwrap();
         let url = Url::parse("rad://willow.radicle.garden/hnrkbjg7r54q48sqsaho1n4qfxhi4nbmdh51y")
             .unwrap();
+        let origin = Origin::try_from(url).unwrap();
+
+        assert_eq!(origin.seed, None);
+        assert_eq!(
+            origin.host,
+            Some(String::from("willow.radicle.garden:8776"))
+        );
+        assert_eq!(origin.urn, expected_urn);
+
+        let url = Url::parse("rad://willow_radicle!garden/hnrkbjg7r54q48sqsaho1n4qfxhi4nbmdh51y")
+            .unwrap();
+        Origin::try_from(url).unwrap_err();
+
+        let url = Url::parse("rad:///hnrkbjg7r54q48sqsaho1n4qfxhi4nbmdh51y").unwrap();
         Origin::try_from(url).unwrap_err();
 
-        let expected_urn = Urn::try_from_id("hnrkbjg7r54q48sqsaho1n4qfxhi4nbmdh51y").unwrap();
         let url = Url::parse("rad://hyb5to4rshftx4apgmu9s6wnsp4ddmp1mz6ijh4qqey7fb8wrpawxa@pine.radicle.garden:8776/hnrkbjg7r54q48sqsaho1n4qfxhi4nbmdh51y")
             .unwrap();
         let origin = Origin::try_from(url).unwrap();
@@ -1,22 +1,27 @@
 //! SSH and key-related functions.
+use std::fs;
+use std::io::Write as _;
+use std::path::Path;
+
 use anyhow::{Context as _, Error, Result};
 
+use serde::{Deserialize, Serialize};
 use zeroize::Zeroizing;
 
 use librad::crypto::keystore::crypto;
 use librad::crypto::keystore::crypto::Pwhash;
 use librad::crypto::keystore::pinentry::{Pinentry, SecUtf8};
 use librad::crypto::keystore::{FileStorage, Keystore};
-use librad::git::storage::Storage;
-use librad::profile::Profile;
-use librad::{PeerId, PublicKey};
+use librad::git::storage::{ReadOnly, Storage};
+use librad::profile::{LnkHome, Profile};
+use librad::{PeerId, PublicKey, SecretKey};
 
 use lnk_clib::keys;
 use lnk_clib::keys::ssh::SshAuthSock;
 
 pub use lnk_clib::keys::LIBRAD_KEY_FILE as KEY_FILE;
 
-use crate::signer::{ToSigner, ZeroizingSecretKey};
+use crate::signer::{self, ToSigner, ZeroizingSecretKey};
 
 /// Env var used to pass down the passphrase to the git-remote-helper when
 /// ssh-agent isn't present.
@@ -26,8 +31,10 @@ pub const RAD_PASSPHRASE: &str = "RAD_PASSPHRASE";
 pub fn storage(profile: &Profile, signer: impl ToSigner) -> Result<Storage, Error> {
     let signer = match signer.to_signer(profile) {
         Ok(signer) => signer,
-        Err(keys::ssh::Error::NoSuchKey(_)) => {
-            anyhow::bail!("the radicle ssh key for this profile is not in ssh-agent")
+        Err(err @ keys::ssh::Error::NoSuchKey(_)) => {
+            return Err(
+                Error::new(err).context("the radicle ssh key for this profile is not in ssh-agent")
+            );
         }
         Err(err) => anyhow::bail!(err),
     };
@@ -68,6 +75,15 @@ pub fn ssh_auth_sock() -> Result<SshAuthSock, anyhow::Error> {
     Ok(SshAuthSock::Env)
 }
 
+/// Get the agent socket of a profile's signer backend. This is the hardware signer's
+/// socket if selected, otherwise the system ssh-agent's.
+pub fn agent(profile: &Profile) -> Result<SshAuthSock, anyhow::Error> {
+    match signer::backend(profile)? {
+        signer::Backend::SshAgent => ssh_auth_sock(),
+        signer::Backend::Hardware => Ok(SshAuthSock::Uds(signer::hardware_sock(profile)?)),
+    }
+}
+
 /// Check whether the radicle signing key has been added to ssh-agent.
 pub fn is_ready(profile: &Profile, sock: SshAuthSock) -> Result<bool, Error> {
     keys::ssh::is_signer_present(profile, sock)
@@ -94,6 +110,14 @@ pub fn to_ssh_key(peer_id: &PeerId) -> Result<String, std::io::Error> {
     Ok(encoded)
 }
 
+/// Get the SSH public key of a peer id as an OpenSSH `authorized_keys` line.
+/// The peer id is used as the key comment.
+pub fn to_ssh_authorized_key(peer_id: &PeerId) -> Result<String, std::io::Error> {
+    let key = to_ssh_key(peer_id)?;
+
+    Ok(format!("{} {}", key, peer_id))
+}
+
 /// Get the SSH key fingerprint from a peer id.
 /// This is the output of `ssh-add -l`.
 pub fn to_ssh_fingerprint(peer_id: &PeerId) -> Result<String, std::io::Error> {
@@ -128,6 +152,144 @@ pub fn load_secret_key(
     Ok(ZeroizingSecretKey::new(keypair.secret_key))
 }
 
+/// Change the passphrase protecting a profile's secret key. The key is unlocked with
+/// the old passphrase and re-encrypted with the new one; it is never written in plaintext.
+pub fn change_passphrase(profile: &Profile, old: SecUtf8, new: SecUtf8) -> Result<(), Error> {
+    let path = profile.paths().keys_dir().join(KEY_FILE);
+    let tmp = path.with_extension("tmp");
+    let current: FileStorage<_, PublicKey, SecretKey, _> = FileStorage::new(&path, pwhash(old));
+    let keypair = current.get_key().context("invalid passphrase supplied")?;
+
+    // Write the re-encrypted key next to the existing one, and only replace it once
+    // that has succeeded.
+    fs::remove_file(&tmp).ok();
+
+    let mut updated: FileStorage<_, PublicKey, SecretKey, _> = FileStorage::new(&tmp, pwhash(new));
+    updated.put_key(keypair.secret_key)?;
+
+    fs::rename(&tmp, &path).context("could not replace key file")?;
+
+    Ok(())
+}
+
+/// Version of the export bundle format.
+pub const BUNDLE_VERSION: u32 = 1;
+
+/// A profile's key and minimal metadata, for moving an identity to another device.
+///
+/// The key is kept encrypted with the profile passphrase, exactly as it is stored in
+/// the profile's keystore.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Bundle {
+    /// Bundle format version.
+    pub version: u32,
+    /// Peer id of the exported key.
+    pub peer_id: PeerId,
+    /// User name, if set.
+    pub name: Option<String>,
+    /// Personal identity URN, if set. It isn't set as the local identity on import,
+    /// since the identity has to be fetched from a seed first.
+    pub urn: Option<String>,
+    /// The encrypted keystore file, base64-encoded.
+    pub key: String,
+}
+
+/// Export a profile's encrypted key and metadata to a bundle file.
+///
+/// The passphrase is required to check that the bundle can be imported later.
+/// The key is never decrypted to disk.
+pub fn export(profile: &Profile, passphrase: SecUtf8, path: &Path) -> Result<Bundle, Error> {
+    load_secret_key(profile, passphrase).context("invalid passphrase supplied")?;
+
+    let storage = ReadOnly::open(profile.paths())?;
+    let peer_id = *storage.peer_id();
+    let config = storage.config()?;
+    let keystore = fs::read(profile.paths().keys_dir().join(KEY_FILE))?;
+
+    let bundle = Bundle {
+        version: BUNDLE_VERSION,
+        peer_id,
+        name: config.user_name().ok(),
+        urn: config.user()?.map(|urn| urn.to_string()),
+        key: base64::encode(keystore),
+    };
+    let json = serde_json::to_string_pretty(&bundle)?;
+
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt as _;
+        // The bundle holds the encrypted key, keep it private like the keystore.
+        options.mode(0o600);
+    }
+    options
+        .open(path)
+        .and_then(|mut file| file.write_all(json.as_bytes()))
+        .with_context(|| format!("could not write bundle to {}", path.display()))?;
+
+    Ok(bundle)
+}
+
+/// Import a bundle written by [`export`] into a new profile, and make it the active profile.
+/// The user name of the bundle is restored in the new profile's storage config.
+///
+/// Fails without creating a profile if the passphrase doesn't unlock the bundled key.
+pub fn import(
+    home: &LnkHome,
+    path: &Path,
+    passphrase: SecUtf8,
+) -> Result<(Profile, Bundle), Error> {
+    let json = fs::read_to_string(path)
+        .with_context(|| format!("could not read bundle from {}", path.display()))?;
+    let bundle: Bundle = serde_json::from_str(&json).context("invalid bundle")?;
+
+    if bundle.version != BUNDLE_VERSION {
+        anyhow::bail!("unsupported bundle version {}", bundle.version);
+    }
+    let keystore = base64::decode(&bundle.key).context("invalid key in bundle")?;
+    if Profile::list(home)?
+        .iter()
+        .any(|p| ReadOnly::open(p.paths()).map_or(false, |s| *s.peer_id() == bundle.peer_id))
+    {
+        anyhow::bail!("a profile for peer {} already exists", bundle.peer_id);
+    }
+
+    let profile = Profile::new(home)?;
+    let result = (|| -> Result<(), Error> {
+        let keys_dir = profile.paths().keys_dir();
+        let key_file = keys_dir.join(KEY_FILE);
+
+        fs::create_dir_all(keys_dir)?;
+        fs::write(&key_file, keystore)?;
+
+        let store: FileStorage<_, PublicKey, SecretKey, _> =
+            FileStorage::new(&key_file, pwhash(passphrase));
+        let keypair = store.get_key().context("invalid passphrase supplied")?;
+
+        if PeerId::from(keypair.public_key) != bundle.peer_id {
+            anyhow::bail!("bundle key doesn't match peer id {}", bundle.peer_id);
+        }
+        Storage::open(profile.paths(), keypair.secret_key)?;
+
+        if let Some(name) = &bundle.name {
+            git2::Repository::open_bare(profile.paths().git_dir())?
+                .config()?
+                .set_str("user.name", name)?;
+        }
+        Profile::set(home, profile.id().clone())?;
+
+        Ok(())
+    })();
+
+    if let Err(err) = result {
+        crate::profile::remove(&profile).ok();
+        return Err(err);
+    }
+    Ok((profile, bundle))
+}
+
 pub fn read_env_passphrase() -> Result<SecUtf8, anyhow::Error> {
     let env_var = std::env::var(RAD_PASSPHRASE)?;
     let input: Zeroizing<String> = Zeroizing::new(env_var);
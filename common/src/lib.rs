@@ -3,6 +3,7 @@
 pub mod args;
 pub mod cobs;
 pub mod config;
+pub mod exit;
 pub mod git;
 pub mod identity;
 pub mod keys;
@@ -34,13 +35,35 @@ pub use url::Url;
 
 /// String formatting of various types.
 pub mod fmt {
+    use std::time;
+
+    use chrono::TimeZone;
     use librad::{collaborative_objects::ObjectId, PeerId};
 
+    use crate::cobs::Timestamp;
+
+    /// Age after which timestamps are formatted as a date instead of relative to now.
+    pub const RELATIVE_TIME_LIMIT: time::Duration = time::Duration::from_secs(60 * 60 * 24 * 30);
+
     /// Format a peer id to be more compact.
     pub fn peer(peer: &PeerId) -> String {
-        let peer = peer.default_encoding();
-        let start = peer.chars().take(7).collect::<String>();
-        let end = peer.chars().skip(peer.len() - 7).collect::<String>();
+        peer_with(peer, 7)
+    }
+
+    /// Format a peer id to be more compact, keeping `n` characters on either side.
+    pub fn peer_with(peer: &PeerId, n: usize) -> String {
+        compact(&peer.default_encoding(), n)
+    }
+
+    /// Shorten a string to its first and last `n` characters, separated by an ellipsis.
+    /// Strings that aren't longer than the result would be are returned as-is.
+    fn compact(s: &str, n: usize) -> String {
+        let len = s.chars().count();
+        if len <= n * 2 + 1 {
+            return s.to_owned();
+        }
+        let start = s.chars().take(n).collect::<String>();
+        let end = s.chars().skip(len - n).collect::<String>();
 
         format!("{}…{}", start, end)
     }
@@ -54,6 +77,65 @@ pub mod fmt {
     pub fn cob(id: &ObjectId) -> String {
         format!("{:.11}", id.to_string())
     }
+
+    /// Format a timestamp relative to now, eg. "3 hours ago". Timestamps older than
+    /// [`RELATIVE_TIME_LIMIT`] are formatted as a date, eg. "2022-09-06".
+    pub fn relative(timestamp: &Timestamp) -> String {
+        relative_to(timestamp, &Timestamp::now())
+    }
+
+    fn relative_to(timestamp: &Timestamp, now: &Timestamp) -> String {
+        let elapsed = time::Duration::from_secs(now.as_secs().saturating_sub(timestamp.as_secs()));
+
+        if elapsed > RELATIVE_TIME_LIMIT {
+            chrono::Utc
+                .timestamp(timestamp.as_secs() as i64, 0)
+                .format("%Y-%m-%d")
+                .to_string()
+        } else {
+            timeago::Formatter::new().convert(elapsed)
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::*;
+        use std::str::FromStr;
+
+        #[test]
+        fn test_relative() {
+            let now = Timestamp::new(1662422400); // 2022-09-06
+
+            assert_eq!(
+                relative_to(&Timestamp::new(1662422400 - 3 * 3600), &now),
+                "3 hours ago"
+            );
+            assert_eq!(relative_to(&Timestamp::new(1662422400 + 60), &now), "now");
+            assert_eq!(
+                relative_to(&Timestamp::new(1662422400 - 90 * 86400), &now),
+                "2022-06-08"
+            );
+        }
+
+        #[test]
+        fn test_compact() {
+            assert_eq!(compact("", 7), "");
+            assert_eq!(compact("abc", 7), "abc");
+            assert_eq!(compact("abcdefghijklmno", 7), "abcdefghijklmno");
+            assert_eq!(compact("abcdefghijklmnop", 7), "abcdefg…jklmnop");
+            assert_eq!(compact("abcdef", 0), "…");
+            assert_eq!(compact("ééééééé", 2), "éé…éé");
+        }
+
+        #[test]
+        fn test_peer() {
+            let id =
+                PeerId::from_str("hyb5to4rshftx4apgmu9s6wnsp4ddmp1mz6ijh4qqey7fb8wrpawxa").unwrap();
+
+            assert_eq!(peer(&id), "hyb5to4…wrpawxa");
+            assert_eq!(peer_with(&id, 3), "hyb…wxa");
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
@@ -1,22 +1,54 @@
 //! Logging module.
 use std::io;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
 
-pub use log::{Level, Log, Metadata, Record, SetLoggerError};
+pub use log::{Level, LevelFilter, Log, Metadata, Record, SetLoggerError};
+
+/// Environment variable used to configure logging, eg. `RUST_LOG=debug` or
+/// `RUST_LOG=warn,librad=debug`.
+pub const LOG_ENV: &str = "RUST_LOG";
+
+/// Verbosity requested on the command line, ie. the number of `-v` flags.
+static VERBOSITY: AtomicU8 = AtomicU8::new(0);
+
+/// A logging directive: the maximum level for modules under a target, or all modules.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Directive {
+    target: Option<String>,
+    level: LevelFilter,
+}
 
 struct Logger {
-    level: Level,
+    directives: Vec<Directive>,
+    /// Whether all records are written to `stderr`, keeping `stdout` for command output.
+    stderr: bool,
+}
+
+impl Logger {
+    /// Maximum level enabled for a target. The most specific matching directive wins.
+    fn level(&self, target: &str) -> LevelFilter {
+        self.directives
+            .iter()
+            .filter(|d| match &d.target {
+                Some(t) => target == t || target.starts_with(&format!("{}::", t)),
+                None => true,
+            })
+            .max_by_key(|d| d.target.as_ref().map_or(0, |t| t.len()))
+            .map_or(LevelFilter::Off, |d| d.level)
+    }
 }
 
 impl Log for Logger {
     fn enabled(&self, metadata: &Metadata) -> bool {
-        metadata.level() <= self.level
+        metadata.level() <= self.level(metadata.target())
     }
 
     fn log(&self, record: &Record) {
         if self.enabled(record.metadata()) {
             let module = record.module_path().unwrap_or_default();
 
-            if record.level() == Level::Error {
+            if self.stderr || record.level() == Level::Error {
                 write(record, module, io::stderr());
             } else {
                 write(record, module, io::stdout());
@@ -33,10 +65,112 @@ impl Log for Logger {
 
 /// Initialize a new logger.
 pub fn init(level: Level) -> Result<(), SetLoggerError> {
-    let logger = Logger { level };
+    let logger = Logger {
+        directives: vec![Directive {
+            target: None,
+            level: level.to_level_filter(),
+        }],
+        stderr: false,
+    };
 
     log::set_boxed_logger(Box::new(logger))?;
     log::set_max_level(level.to_level_filter());
 
     Ok(())
 }
+
+/// Set the verbosity requested on the command line. See [`verbosity_level`].
+pub fn set_verbosity(verbosity: u8) {
+    VERBOSITY.store(verbosity, Ordering::Relaxed);
+}
+
+/// Map a verbosity to a level: `-v` is `warn`, `-vv` is `info`, `-vvv` is `debug`
+/// and anything more is `trace`. Logging is off without `-v`.
+pub fn verbosity_level(verbosity: u8) -> LevelFilter {
+    match verbosity {
+        0 => LevelFilter::Off,
+        1 => LevelFilter::Warn,
+        2 => LevelFilter::Info,
+        3 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    }
+}
+
+/// Initialize logging for a command, from [`LOG_ENV`] if it is set, otherwise from the
+/// verbosity set with [`set_verbosity`]. Records are written to `stderr`.
+///
+/// Does nothing if logging is off, or a logger was already set.
+pub fn setup() -> Result<(), anyhow::Error> {
+    let directives = match std::env::var(LOG_ENV) {
+        Ok(spec) if !spec.trim().is_empty() => parse_directives(&spec)?,
+        _ => vec![Directive {
+            target: None,
+            level: verbosity_level(VERBOSITY.load(Ordering::Relaxed)),
+        }],
+    };
+    let max = directives
+        .iter()
+        .map(|d| d.level)
+        .max()
+        .unwrap_or(LevelFilter::Off);
+
+    if max == LevelFilter::Off {
+        return Ok(());
+    }
+    let logger = Logger {
+        directives,
+        stderr: true,
+    };
+    if log::set_boxed_logger(Box::new(logger)).is_ok() {
+        log::set_max_level(max);
+    }
+    Ok(())
+}
+
+/// Parse a comma-separated list of directives, eg. `warn,librad::git=debug`.
+fn parse_directives(spec: &str) -> Result<Vec<Directive>, anyhow::Error> {
+    spec.split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|directive| {
+            let invalid = || anyhow::anyhow!("invalid {} directive '{}'", LOG_ENV, directive);
+
+            match directive.split_once('=') {
+                Some((target, level)) => Ok(Directive {
+                    target: Some(target.to_owned()),
+                    level: LevelFilter::from_str(level).map_err(|_| invalid())?,
+                }),
+                // A bare word is either a level, or a target to log at all levels.
+                None => match LevelFilter::from_str(directive) {
+                    Ok(level) => Ok(Directive {
+                        target: None,
+                        level,
+                    }),
+                    Err(_) => Ok(Directive {
+                        target: Some(directive.to_owned()),
+                        level: LevelFilter::Trace,
+                    }),
+                },
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_directives() {
+        let logger = Logger {
+            directives: parse_directives("warn, librad::git=debug,lnk_sync").unwrap(),
+            stderr: true,
+        };
+
+        assert_eq!(logger.level("radicle_common"), LevelFilter::Warn);
+        assert_eq!(logger.level("librad::git::storage"), LevelFilter::Debug);
+        assert_eq!(logger.level("librad::gitx"), LevelFilter::Warn);
+        assert_eq!(logger.level("lnk_sync"), LevelFilter::Trace);
+        assert!(parse_directives("librad=loud").is_err());
+    }
+}
@@ -2,8 +2,6 @@
 use std::convert::TryInto;
 use std::fmt;
 
-use librad::git::identities;
-use librad::git::identities::project::heads::DefaultBranchHead;
 use librad::git::refs::Refs;
 use librad::git::storage::{ReadOnly, ReadOnlyStorage};
 use librad::git::{Storage, Urn};
@@ -246,24 +244,16 @@ where
 
 pub fn patch_merge_target_oid(
     target: cob::MergeTarget,
-    project: identities::VerifiedProject,
+    project: &project::Metadata,
     storage: &Storage,
 ) -> anyhow::Result<git2::Oid> {
-    let urn = project.urn();
-
     match target {
-        cob::MergeTarget::Upstream => {
-            if let DefaultBranchHead::Head { target, .. } =
-                identities::project::heads::default_branch_head(storage, project)?
-            {
-                Ok(target)
-            } else {
-                anyhow::bail!(
-                    "failed to determine default branch head for project {}",
-                    urn,
-                );
-            }
-        }
+        cob::MergeTarget::Upstream => project.default_branch_head(storage)?.ok_or_else(|| {
+            anyhow::anyhow!(
+                "failed to determine default branch head for project {}",
+                project.urn
+            )
+        }),
     }
 }
 
@@ -20,8 +20,13 @@ lazy_static::lazy_static! {
     static ref ENS_NAMESPACE: url::Url = "https://radicle.xyz/ethereum/ens/v1"
         .parse()
         .expect("static URL malformed");
+    static ref AVATAR_NAMESPACE: url::Url = ext_namespace("avatar")
+        .expect("static URL malformed");
 }
 
+/// Prefix of the namespaces of generic person extensions. See [`ext_namespace`].
+pub const EXT_NAMESPACE_PREFIX: &str = "https://radicle.xyz/person";
+
 /// ENS payload.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
 pub struct Ens {
@@ -34,6 +39,33 @@ impl HasNamespace for Ens {
     }
 }
 
+/// Avatar payload: the URL of an image representing the person.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+pub struct Avatar {
+    pub url: String,
+}
+
+impl Avatar {
+    /// Create an avatar payload. Fails if the URL doesn't parse as an HTTP(S) URL.
+    pub fn new(url: &str) -> Result<Self> {
+        let parsed =
+            url::Url::parse(url).with_context(|| format!("invalid avatar URL '{}'", url))?;
+
+        if !matches!(parsed.scheme(), "http" | "https") {
+            anyhow::bail!("invalid avatar URL '{}': must be an HTTP(S) URL", url);
+        }
+        Ok(Self {
+            url: parsed.to_string(),
+        })
+    }
+}
+
+impl HasNamespace for Avatar {
+    fn namespace() -> &'static url::Url {
+        &AVATAR_NAMESPACE
+    }
+}
+
 /// Create a personal identity.
 pub fn create(
     profile: &Profile,
@@ -76,22 +108,61 @@ pub fn local(storage: &Storage) -> Result<LocalIdentity, local::Error> {
     local::default(storage)
 }
 
+/// Get the namespace of a generic person extension, eg. `pronouns` is stored under
+/// `https://radicle.xyz/person/pronouns/v1`.
+pub fn ext_namespace(key: &str) -> Result<url::Url> {
+    if key.is_empty()
+        || !key
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    {
+        anyhow::bail!(
+            "invalid extension key '{}': only lowercase letters, digits and '-' are allowed",
+            key
+        );
+    }
+    Ok(format!("{}/{}/v1", EXT_NAMESPACE_PREFIX, key).parse()?)
+}
+
 /// Set an ENS payload for the local identity.
 /// Returns the updated person.
 pub fn set_ens_payload(ens: Ens, storage: &Storage) -> Result<Person> {
+    set_ext_payload(ens, storage)
+}
+
+/// Set an extension payload for the local identity, replacing any existing payload
+/// of the same type. Returns the updated person.
+pub fn set_ext_payload<T>(payload: T, storage: &Storage) -> Result<Person>
+where
+    T: serde::Serialize + HasNamespace,
+{
+    let val = serde_json::to_value(payload)?;
+
+    set_ext_values(vec![(T::namespace().clone(), val)], storage)
+}
+
+/// Set extension values for the local identity, by namespace, replacing any existing
+/// values under the same namespaces. Returns the updated person.
+pub fn set_ext_values(
+    values: Vec<(url::Url, serde_json::Value)>,
+    storage: &Storage,
+) -> Result<Person> {
     let id = local::default(storage)?;
     let payload = id.payload();
     let mut exts = payload
         .exts()
+        .filter(|(namespace, _)| !values.iter().any(|(ns, _)| ns == *namespace))
         .map(|(namespace, val)| (namespace.clone(), val.clone()))
         .map(|(namespace, val)| payload::Ext { namespace, val })
         .collect::<Vec<_>>();
 
-    let namespace = Ens::namespace().clone();
-    let val = serde_json::to_value(ens)?;
     let delegations = id.delegations().iter().cloned();
 
-    exts.push(payload::Ext { namespace, val });
+    exts.extend(
+        values
+            .into_iter()
+            .map(|(namespace, val)| payload::Ext { namespace, val }),
+    );
 
     let new = person::update(
         storage,
@@ -104,3 +175,25 @@ pub fn set_ens_payload(ens: Ens, storage: &Storage) -> Result<Person> {
 
     Ok(new)
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_avatar_new() {
+        assert_eq!(
+            Avatar::new("https://radicle.xyz/avatar.png").unwrap().url,
+            "https://radicle.xyz/avatar.png"
+        );
+        assert_eq!(
+            Avatar::new("http://radicle.xyz").unwrap().url,
+            "http://radicle.xyz/"
+        );
+
+        assert!(Avatar::new("").is_err());
+        assert!(Avatar::new("avatar.png").is_err());
+        assert!(Avatar::new("file:///home/alice/avatar.png").is_err());
+        assert!(Avatar::new("ftp://radicle.xyz/avatar.png").is_err());
+    }
+}
@@ -12,6 +12,7 @@ use url::Url;
 
 use librad::canonical::Cstring;
 use librad::crypto::BoxedSigner;
+use librad::git::identities::project::heads::DefaultBranchHead;
 use librad::git::identities::{self, project, Project};
 use librad::git::local::transport;
 use librad::git::local::url::LocalUrl;
@@ -21,7 +22,7 @@ use librad::git::types::remote::Remote;
 use librad::git::types::{Namespace, Reference};
 use librad::git::Urn;
 use librad::git_ext::{OneLevel, RefLike};
-use librad::identities::payload::{self, ProjectPayload};
+use librad::identities::payload::{self, HasNamespace, ProjectPayload};
 use librad::identities::SomeIdentity;
 use librad::identities::{Person, VerifiedProject};
 use librad::paths::Paths;
@@ -33,7 +34,7 @@ use lnk_identities::working_copy_dir::WorkingCopyDir;
 
 use crate as common;
 use crate::person::Ens;
-use crate::{git, person};
+use crate::{args, git, person, seed};
 
 /// URL scheme for radicle resources.
 pub const URL_SCHEME: &str = "rad";
@@ -41,6 +42,12 @@ pub const URL_SCHEME: &str = "rad";
 /// Prefix for remote tracking branches from peers.
 pub const PEER_PREFIX: &str = "peers";
 
+lazy_static::lazy_static! {
+    static ref DISCOVERY_NAMESPACE: Url = "https://radicle.xyz/project/discovery/v1"
+        .parse()
+        .expect("static URL malformed");
+}
+
 /// Project indirect contributor identity.
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct PeerIdentity {
@@ -188,6 +195,17 @@ impl Metadata {
         peer_self(storage, self.urn.clone(), peer)
     }
 
+    /// Get the authoritative head of the project's default branch, ie. the commit that the
+    /// project delegates agree on. Returns `None` if the delegates' histories have forked.
+    pub fn default_branch_head(&self, storage: &Storage) -> anyhow::Result<Option<git::Oid>> {
+        let verified = self.verified(storage)?;
+
+        match identities::project::heads::default_branch_head(storage, verified)? {
+            DefaultBranchHead::Head { target, .. } => Ok(Some(target)),
+            DefaultBranchHead::Forked(_) => Ok(None),
+        }
+    }
+
     /// Get a [`VerifiedProject`] from project metadata.
     pub fn verified(&self, storage: &Storage) -> anyhow::Result<VerifiedProject> {
         identities::project::verify(storage, &self.urn)?
@@ -262,19 +280,53 @@ impl TryFrom<librad::identities::Project> for Metadata {
     }
 }
 
-/// Create a project payload.
-pub fn payload(name: String, description: String, default_branch: String) -> payload::Project {
-    payload::Project {
+/// Project discovery metadata, stored as an identity payload extension.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Discovery {
+    /// Project homepage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub homepage: Option<Url>,
+    /// Topic tags.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+}
+
+impl Discovery {
+    /// Whether there is no metadata set.
+    pub fn is_empty(&self) -> bool {
+        self.homepage.is_none() && self.tags.is_empty()
+    }
+}
+
+impl HasNamespace for Discovery {
+    fn namespace() -> &'static Url {
+        &DISCOVERY_NAMESPACE
+    }
+}
+
+/// Create a project payload. Discovery metadata is only included if non-empty.
+pub fn payload(
+    name: String,
+    description: String,
+    default_branch: String,
+    discovery: Discovery,
+) -> anyhow::Result<ProjectPayload> {
+    let mut payload = ProjectPayload::new(payload::Project {
         name: Cstring::from(name),
         description: Some(Cstring::from(description)),
         default_branch: Some(Cstring::from(default_branch)),
+    });
+
+    if !discovery.is_empty() {
+        payload.set_ext(discovery)?;
     }
+    Ok(payload)
 }
 
 /// Create a new project identity.
-pub fn create(payload: payload::Project, storage: &Storage) -> anyhow::Result<Project> {
+pub fn create(payload: ProjectPayload, storage: &Storage) -> anyhow::Result<Project> {
     let whoami = person::local(storage)?;
-    let payload = ProjectPayload::new(payload);
     let delegations = identities::IndirectDelegation::try_from_iter(iter::once(Either::Right(
         whoami.clone().into_inner().into_inner(),
     )))?;
@@ -459,6 +511,18 @@ where
     }
 }
 
+/// Get a reference to `rad/id` for a project's peer.
+pub fn peer_rad_id<S>(storage: &S, project: Urn, peer: &PeerId) -> Reference<RefLike>
+where
+    S: AsRef<ReadOnly>,
+{
+    if storage.as_ref().peer_id() == peer {
+        Reference::rad_id(Namespace::from(project))
+    } else {
+        Reference::rad_id(Namespace::from(project)).with_remote(*peer)
+    }
+}
+
 /// List project seed heads.
 pub fn list_seed_heads(
     repo: &git::Repository,
@@ -482,6 +546,75 @@ pub fn list_seed_heads(
     Ok(remotes)
 }
 
+/// A project peer along with its branches.
+#[derive(Debug, Clone)]
+pub struct Peer {
+    /// Peer id.
+    pub id: PeerId,
+    /// Peer metadata, if known.
+    pub meta: Option<PeerInfo>,
+    /// Peer branches.
+    pub branches: Vec<Branch>,
+}
+
+/// A peer branch.
+#[derive(Debug, Clone)]
+pub struct Branch {
+    /// Branch name, eg. `master`.
+    pub name: String,
+    /// Branch head.
+    pub head: git::Oid,
+    /// Summary of the head commit, or empty if unknown.
+    pub message: String,
+}
+
+/// List project peers on a seed, with their branch heads, head commit summaries and metadata.
+pub fn list_seed_peers(
+    project: &Urn,
+    repo: &git::Repository,
+    seed: &Url,
+) -> anyhow::Result<Vec<Peer>> {
+    let remotes = list_seed_heads(repo, seed, project)?;
+    if remotes.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let metadata: HashMap<_, _> = if let Ok(meta) = seed::get_remotes(seed.clone(), project) {
+        meta.into_iter().map(|r| (r.id, r)).collect()
+    } else {
+        HashMap::new() // Support old seeds that don't have metadata.
+    };
+    let mut commits: HashMap<git::Oid, String> = HashMap::new();
+    let mut peers = Vec::new();
+
+    for (id, heads) in remotes {
+        let mut branches = Vec::new();
+
+        for (name, head) in heads {
+            let message = if let Some(m) = commits.get(&head) {
+                m.to_owned()
+            } else if let Ok(commit) = seed::get_commit(seed.clone(), project, &head) {
+                commits.insert(head, commit.header.summary.clone());
+                commit.header.summary
+            } else {
+                String::new()
+            };
+
+            branches.push(Branch {
+                name,
+                head,
+                message,
+            });
+        }
+        peers.push(Peer {
+            id,
+            meta: metadata.get(&id).cloned(),
+            branches,
+        });
+    }
+    Ok(peers)
+}
+
 pub fn find_remote(
     name: &str,
     storage: &Storage,
@@ -525,10 +658,59 @@ pub fn remote(urn: &Urn, peer: &PeerId, name: &str) -> Result<Remote<LocalUrl>,
     Ok(remote)
 }
 
+/// Error returned by [`cwd`] when the working directory isn't a usable radicle project.
+#[derive(thiserror::Error, Debug)]
+pub enum CwdError {
+    #[error("the current working directory is not a git repository")]
+    NotRepository(#[source] git2::Error),
+    #[error("the current git repository is not a radicle project")]
+    NoRadRemote,
+    #[error("could not read git remote configuration: {0}")]
+    Remote(String),
+    #[error("the current git repository has an invalid HEAD: {0}")]
+    InvalidHead(#[source] git2::Error),
+}
+
+impl CwdError {
+    /// A hint on how to fix the error.
+    pub fn hint(&self) -> &'static str {
+        match self {
+            Self::NotRepository(_) => {
+                "This command must be run from within a radicle project's working copy."
+            }
+            Self::NoRadRemote => {
+                "To initialize this repository as a radicle project, run `rad init`."
+            }
+            Self::Remote(_) => "Check the `rad` remote of this repository with `git remote -v`.",
+            Self::InvalidHead(_) => "Make sure a branch with at least one commit is checked out.",
+        }
+    }
+}
+
+impl From<CwdError> for args::Error {
+    fn from(err: CwdError) -> Self {
+        let hint = err.hint();
+
+        Self::WithHint {
+            err: err.into(),
+            hint,
+        }
+    }
+}
+
 /// Get the project URN and repository of the current working directory.
-pub fn cwd() -> anyhow::Result<(Urn, git::Repository)> {
-    let repo = git::repository()?;
-    let urn = git::rad_remote(&repo)?.url.urn;
+///
+/// A detached `HEAD` is allowed, but `HEAD` must point to a commit.
+pub fn cwd() -> Result<(Urn, git::Repository), CwdError> {
+    let repo = git::Repository::open(".").map_err(CwdError::NotRepository)?;
+    let urn = match Remote::<LocalUrl>::find(&repo, reflike!("rad")) {
+        Ok(Some(remote)) => remote.url.urn,
+        Ok(None) => return Err(CwdError::NoRadRemote),
+        Err(err) => return Err(CwdError::Remote(err.to_string())),
+    };
+    repo.head()
+        .and_then(|head| head.peel_to_commit())
+        .map_err(CwdError::InvalidHead)?;
 
     Ok((urn, repo))
 }
@@ -1,16 +1,17 @@
 //! Seed-related functionality.
-use std::convert::TryFrom;
-use std::net;
+use std::convert::{TryFrom, TryInto};
 use std::path::Path;
 use std::str::FromStr;
+use std::{fs, io, net, thread, time};
 
 use anyhow::{anyhow, Context as _, Result};
 use librad::crypto::peer::PeerId;
 use librad::git::Urn;
+use nonempty::NonEmpty;
 use url::{Host, Url};
 
 use crate::args::Error;
-use crate::sync::Seed;
+use crate::sync::{self, Seed};
 use crate::{git, project};
 
 pub const CONFIG_SEED_KEY: &str = "rad.seed";
@@ -19,6 +20,14 @@ pub const DEFAULT_SEED_GIT_LOCAL_PORT: u16 = 8778;
 pub const DEFAULT_SEED_API_PORT: u16 = 8777;
 pub const DEFAULT_SEED_P2P_PORT: u16 = 8776;
 pub const DEFAULT_SEED_GIT_PORT: u16 = 443;
+/// Host of the radicle web gateway.
+pub const GATEWAY_HOST: &str = "app.radicle.xyz";
+/// Number of times a seed HTTP query is attempted before giving up.
+pub const DEFAULT_HTTP_ATTEMPTS: usize = 3;
+/// Delay before retrying a failed seed HTTP query. Doubles with every attempt.
+pub const HTTP_RETRY_DELAY: time::Duration = time::Duration::from_millis(250);
+/// File listing a project's preferred seeds, relative to the working copy root.
+pub const PROJECT_SEEDS_FILE: &str = ".rad/seeds";
 
 #[derive(serde::Deserialize)]
 pub struct CommitHeader {
@@ -213,19 +222,112 @@ impl FromStr for Address {
     }
 }
 
+/// URLs under which a project is available on a seed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProjectUrls {
+    /// Web gateway URLs. Empty if the seed is not publicly routable.
+    pub web: Vec<Url>,
+    /// Git URL of the project.
+    pub git: Url,
+}
+
+/// Check whether a host is publicly routable, ie. not a loopback or private address.
+pub fn is_routable(host: &Host) -> bool {
+    match host {
+        Host::Domain(domain) if domain == "localhost" => false,
+        Host::Domain(_) => true,
+        Host::Ipv4(ip) => !ip.is_loopback() && !ip.is_unspecified() && !ip.is_private(),
+        Host::Ipv6(ip) => !ip.is_loopback() && !ip.is_unspecified(),
+    }
+}
+
+/// Get the URLs under which a project is available on a seed, given the seed's
+/// `<host>:<port>` address. The project's own web URL is only included if the
+/// given peer is a delegate.
+pub fn project_urls(
+    addrs: &str,
+    urn: &Urn,
+    peer: &PeerId,
+    delegate: bool,
+) -> Result<Option<ProjectUrls>, anyhow::Error> {
+    let mut url = match Url::from_str(&format!("https://{}", addrs)) {
+        Ok(url) => url,
+        Err(_) => return Ok(None),
+    };
+    url.set_port(None).ok();
+
+    let host = match url.host() {
+        Some(host) => host.to_owned(),
+        None => return Ok(None),
+    };
+    let mut web = Vec::new();
+
+    if is_routable(&host) {
+        if delegate {
+            web.push(Url::parse(&format!(
+                "https://{}/seeds/{}/{}",
+                GATEWAY_HOST, host, urn
+            ))?);
+        }
+        web.push(Url::parse(&format!(
+            "https://{}/seeds/{}/{}/remotes/{}",
+            GATEWAY_HOST, host, urn, peer
+        ))?);
+    } else {
+        url.set_scheme("http").ok();
+    }
+    let git = url.join(&format!("{}.git", urn.encode_id()))?;
+
+    Ok(Some(ProjectUrls { web, git }))
+}
+
 /// Parse a seed value from an options parser.
 pub fn parse_value(parser: &mut lexopt::Parser) -> anyhow::Result<Seed<String>> {
     let value = parser.value()?;
     let value = value.to_string_lossy();
     let value = value.as_ref();
-    let seed = Seed::from_str(value).map_err(|_| Error::WithHint {
-        err: anyhow!("invalid seed address specified: '{}'", value),
+    let seed = sync::parse_seed(value).map_err(|e| Error::WithHint {
+        err: anyhow!("invalid seed address specified: '{}': {}", value, e),
         hint: "hint: valid seed addresses have the format <peer-id>@<addr>, eg. hyb5to4rshftx4apgmu9s6wnsp4ddmp1mz6ijh4qqey7fb8wrpawxa@pine.radicle.garden:8776",
     })?;
 
     Ok(seed)
 }
 
+/// Get the preferred seeds of a project, as listed in its [`PROJECT_SEEDS_FILE`].
+///
+/// The file contains one seed address per line, of the form `<peer-id>@<addr>`.
+/// Empty lines and lines starting with `#` are ignored. Returns `None` if the
+/// file doesn't exist or lists no seeds.
+pub fn project_seeds(repo: &git::Repository) -> Result<Option<NonEmpty<Seed<String>>>> {
+    let path = match repo.workdir() {
+        Some(workdir) => workdir.join(PROJECT_SEEDS_FILE),
+        None => return Ok(None),
+    };
+    let contents = match fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+    let seeds = contents
+        .lines()
+        .map(|l| l.trim())
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .map(|l| {
+            sync::parse_seed(l).map_err(|e| {
+                anyhow!(
+                    "invalid seed address '{}' in {}: {}",
+                    l,
+                    Path::new(PROJECT_SEEDS_FILE).display(),
+                    e
+                )
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(seeds.try_into().ok())
+}
+
 /// Set the configured "peer" seed within the local repository.
 pub fn set_peer_seed(seed: &Seed<String>, peer_id: &PeerId) -> Result<(), anyhow::Error> {
     let seed = seed.to_string();
@@ -250,13 +352,74 @@ pub fn get_peer_seed(peer_id: &PeerId) -> Result<Url, anyhow::Error> {
     Ok(url)
 }
 
+/// Get all configured "peer" seeds within the given repository, as set by [`set_peer_seed`].
+pub fn get_peer_seeds(repo: &git::Repository) -> Result<Vec<(PeerId, String)>, anyhow::Error> {
+    let config = repo.config()?;
+    let pattern = format!(r"^{}\..*\.seed$", CONFIG_PEER_KEY.replace('.', r"\."));
+    let entries = config.entries(Some(&pattern))?;
+    let mut seeds = Vec::new();
+
+    for entry in &entries {
+        let entry = entry?;
+        let (name, value) = match (entry.name(), entry.value()) {
+            (Some(name), Some(value)) => (name, value),
+            _ => continue,
+        };
+        let peer = name
+            .strip_prefix(CONFIG_PEER_KEY)
+            .and_then(|s| s.strip_prefix('.'))
+            .and_then(|s| s.strip_suffix(".seed"))
+            .and_then(|s| PeerId::from_str(s).ok());
+
+        if let Some(peer) = peer {
+            seeds.push((peer, value.to_owned()));
+        }
+    }
+    Ok(seeds)
+}
+
+/// Query a seed node's HTTP API, returning the JSON response.
+///
+/// Transient failures, ie. connection errors and `5xx` responses, are retried up to
+/// `attempts` times in total, with an exponentially increasing delay between attempts.
+/// Other failures, eg. `404`, are returned immediately.
+///
+/// Fails without making a request when offline mode is enabled.
+pub fn get_json(url: &Url, attempts: usize) -> Result<serde_json::Value, anyhow::Error> {
+    if crate::sync::is_offline() {
+        anyhow::bail!("cannot query {}: offline mode is enabled", url);
+    }
+    let agent = ureq::Agent::new();
+    let mut delay = HTTP_RETRY_DELAY;
+    let mut attempt = 1;
+
+    loop {
+        match agent.get(url.as_str()).call() {
+            Ok(response) => return Ok(response.into_json()?),
+            Err(err) if attempt < attempts && is_transient(&err) => {
+                thread::sleep(delay);
+                delay *= 2;
+                attempt += 1;
+            }
+            Err(err) => return Err(err.into()),
+        }
+    }
+}
+
+/// Whether an HTTP error is worth retrying.
+fn is_transient(err: &ureq::Error) -> bool {
+    match err {
+        ureq::Error::Status(code, _) => *code >= 500,
+        ureq::Error::Transport(_) => true,
+    }
+}
+
 /// Query a seed node for its [`PeerId`].
 pub fn get_seed_id(mut seed: Url) -> Result<PeerId, anyhow::Error> {
     seed.set_port(Some(DEFAULT_SEED_API_PORT)).unwrap();
     seed = seed.join("/v1/peer")?;
 
-    let agent = ureq::Agent::new();
-    let obj: serde_json::Value = agent.get(seed.as_str()).call()?.into_json()?;
+    let obj = get_json(&seed, DEFAULT_HTTP_ATTEMPTS)?;
 
     let id = obj
         .get("id")
@@ -277,8 +440,7 @@ pub fn get_commit(
     seed.set_port(Some(DEFAULT_SEED_API_PORT)).unwrap();
     seed = seed.join(&format!("/v1/projects/{}/commits/{}", project, commit))?;
 
-    let agent = ureq::Agent::new();
-    let val: serde_json::Value = agent.get(seed.as_str()).call()?.into_json()?;
+    let val = get_json(&seed, DEFAULT_HTTP_ATTEMPTS)?;
     let commit = serde_json::from_value(val)?;
 
     Ok(commit)
@@ -289,8 +451,7 @@ pub fn get_remotes(mut seed: Url, project: &Urn) -> Result<Vec<project::PeerInfo
     seed.set_port(Some(DEFAULT_SEED_API_PORT)).unwrap();
     seed = seed.join(&format!("/v1/projects/{}/remotes", project))?;
 
-    let agent = ureq::Agent::new();
-    let val: serde_json::Value = agent.get(seed.as_str()).call()?.into_json()?;
+    let val = get_json(&seed, DEFAULT_HTTP_ATTEMPTS)?;
     let response = serde_json::from_value(val)?;
 
     Ok(response)
@@ -1,3 +1,9 @@
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
 use zeroize::Zeroizing;
 
 use librad::crypto::keystore::sign::ed25519;
@@ -9,6 +15,110 @@ use librad::SecretKey;
 use lnk_clib::keys;
 use lnk_clib::keys::ssh::SshAuthSock;
 
+use crate::args;
+use crate::config::Config;
+
+/// Environment variable selecting the signer backend, eg. `hardware`.
+pub const SIGNER_ENV: &str = "RAD_SIGNER";
+/// Environment variable pointing to the agent socket of the hardware signer.
+pub const HARDWARE_SOCK_ENV: &str = "RAD_HARDWARE_AGENT_SOCK";
+
+/// Where signing keys are obtained from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Backend {
+    /// The system ssh-agent, falling back to the passphrase-protected keystore.
+    SshAgent,
+    /// A hardware security key holding the radicle Ed25519 key, exposed through its own
+    /// agent socket, eg. `gpg-agent` with an OpenPGP card, or `yubikey-agent`.
+    ///
+    /// Nb. FIDO2 `ed25519-sk` keys can't be used, since their signatures aren't plain
+    /// Ed25519 signatures.
+    Hardware,
+}
+
+impl Default for Backend {
+    fn default() -> Self {
+        Self::SshAgent
+    }
+}
+
+impl fmt::Display for Backend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SshAgent => write!(f, "ssh-agent"),
+            Self::Hardware => write!(f, "hardware"),
+        }
+    }
+}
+
+impl FromStr for Backend {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ssh-agent" => Ok(Self::SshAgent),
+            "hardware" => Ok(Self::Hardware),
+            _ => Err(anyhow!(
+                "invalid signer '{}', expected one of: ssh-agent, hardware",
+                s
+            )),
+        }
+    }
+}
+
+/// Signer configuration, under `[signer]` in the profile configuration.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct SignerConfig {
+    /// Signer backend.
+    #[serde(default)]
+    pub backend: Backend,
+    /// Agent socket of the hardware signer.
+    pub socket: Option<PathBuf>,
+}
+
+/// Select the signer backend for this process and the processes it spawns, eg.
+/// `git-remote-rad`. Takes precedence over the profile configuration.
+pub fn set_backend(backend: Backend) {
+    std::env::set_var(SIGNER_ENV, backend.to_string());
+}
+
+/// Get the signer backend of a profile: the one set via [`SIGNER_ENV`] if any, otherwise
+/// the configured one.
+pub fn backend(profile: &Profile) -> Result<Backend, anyhow::Error> {
+    if let Ok(backend) = std::env::var(SIGNER_ENV) {
+        return backend.parse();
+    }
+    Ok(config(profile).backend)
+}
+
+/// Get the agent socket of the hardware signer.
+pub fn hardware_sock(profile: &Profile) -> Result<PathBuf, anyhow::Error> {
+    let sock = std::env::var_os(HARDWARE_SOCK_ENV)
+        .map(PathBuf::from)
+        .or_else(|| config(profile).socket)
+        .ok_or_else(|| args::Error::WithHint {
+            err: anyhow!("no agent socket configured for the hardware signer"),
+            hint: "Set `socket` under `[signer]` in your profile configuration, \
+                   or the `RAD_HARDWARE_AGENT_SOCK` environment variable.",
+        })?;
+
+    if !sock.exists() {
+        anyhow::bail!(
+            "hardware signer agent socket {} does not exist, is the agent running?",
+            sock.display()
+        );
+    }
+    Ok(sock)
+}
+
+fn config(profile: &Profile) -> SignerConfig {
+    Config::profile(profile)
+        .ok()
+        .and_then(|c| c.signer)
+        .unwrap_or_default()
+}
+
 /// A trait for types that can be converted to signers.
 pub trait ToSigner {
     /// Convert to a signer.
@@ -79,3 +189,49 @@ impl librad::Signer for ZeroizingSecretKey {
             .map_err(BoxedSignError::from_std_error)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_backend_from_str() {
+        for backend in [Backend::SshAgent, Backend::Hardware] {
+            assert_eq!(backend.to_string().parse::<Backend>().unwrap(), backend);
+        }
+        assert!("yubikey".parse::<Backend>().is_err());
+    }
+
+    #[test]
+    fn test_config_round_trip() {
+        let config = Config {
+            seed: vec![],
+            signer: Some(SignerConfig {
+                backend: Backend::Hardware,
+                socket: Some(PathBuf::from(
+                    "/run/user/1000/yubikey-agent/yubikey-agent.sock",
+                )),
+            }),
+        };
+        let content = toml::to_string_pretty(&config).unwrap();
+        assert!(content.contains(r#"backend = "hardware""#));
+
+        let config: Config = toml::from_str(&content).unwrap();
+        let signer = config.signer.unwrap();
+        assert_eq!(signer.backend, Backend::Hardware);
+        assert_eq!(
+            signer.socket,
+            Some(PathBuf::from(
+                "/run/user/1000/yubikey-agent/yubikey-agent.sock"
+            ))
+        );
+
+        // The backend is serialized the same way it is parsed from the command line.
+        let config: Config =
+            toml::from_str("seed = []\n\n[signer]\nbackend = \"ssh-agent\"\n").unwrap();
+        assert_eq!(config.signer.unwrap().backend, "ssh-agent".parse().unwrap());
+
+        let config: Config = toml::from_str("seed = []\n").unwrap();
+        assert!(config.signer.is_none());
+    }
+}
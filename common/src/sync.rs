@@ -1,11 +1,17 @@
 mod push;
 
 use std::convert::TryInto;
-use std::net::SocketAddr;
+use std::env;
+use std::fmt;
+use std::net::{IpAddr, Ipv6Addr, SocketAddr};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time;
 
 use anyhow::anyhow;
+use futures::stream::{FuturesUnordered, StreamExt as _};
 
 use librad::crypto::BoxedSigner;
 use librad::git::Urn;
@@ -18,7 +24,7 @@ use librad::net::{
     replication, Network,
 };
 use librad::profile::Profile;
-use librad::Signer;
+use librad::{PeerId, Signer};
 use link_async::Spawner;
 use lnk_clib::seed::store::FileStore;
 
@@ -28,54 +34,256 @@ pub use lnk_sync::Mode;
 use crate::config;
 use crate::nonempty::NonEmpty;
 
+/// Default time allotted to fetching from, or pushing to, a single seed.
+pub const DEFAULT_TIMEOUT: time::Duration = time::Duration::from_secs(9);
+/// Environment variable that enables offline mode when set to a non-empty value.
+pub const OFFLINE_ENV: &str = "RAD_OFFLINE";
+
+/// Whether offline mode was enabled explicitly.
+static OFFLINE: AtomicBool = AtomicBool::new(false);
+
+/// Disable all network operations, such as syncing with seeds and querying seed APIs.
+pub fn set_offline(offline: bool) {
+    OFFLINE.store(offline, Ordering::Relaxed);
+}
+
+/// Whether network operations are disabled, either via [`set_offline`] or the
+/// [`OFFLINE_ENV`] environment variable.
+pub fn is_offline() -> bool {
+    OFFLINE.load(Ordering::Relaxed)
+        || env::var(OFFLINE_ENV).map_or(false, |v| !v.is_empty() && v != "0")
+}
+
+/// Error parsing a seed address of the form `<peer-id>@<host>:<port>`.
+#[derive(thiserror::Error, Debug)]
+pub enum SeedError {
+    #[error("the peer id portion is missing, expected `<peer-id>@<host>:<port>`")]
+    MissingPeerId,
+    #[error("the peer id portion '{0}' is not a valid Peer ID")]
+    InvalidPeerId(String),
+    #[error("the host portion '{0}' is not a valid host name or IP address")]
+    InvalidHost(String),
+    #[error("the port portion '{0}' is not a valid port number")]
+    InvalidPort(String),
+    #[error("{0}")]
+    Invalid(String),
+}
+
+/// Error syncing with seeds, when not a single seed could be synced with.
+#[derive(thiserror::Error, Debug)]
+pub enum SyncError {
+    #[error("no seeds attempted: all seeds failed to resolve")]
+    NoSeeds,
+    #[error("sync failed with all {0} seed(s)")]
+    AllFailed(usize),
+}
+
+/// Parse a seed address of the form `<peer-id>@<host>:<port>`, where the port is optional.
+///
+/// Unlike [`Seed`]'s [`FromStr`] instance, the error says which component is invalid.
+pub fn parse_seed(s: &str) -> Result<Seed<String>, SeedError> {
+    let (peer, addr) = s.split_once('@').ok_or(SeedError::MissingPeerId)?;
+
+    if PeerId::from_str(peer).is_err() {
+        return Err(SeedError::InvalidPeerId(peer.to_owned()));
+    }
+    let (host, port) = if let Some(rest) = addr.strip_prefix('[') {
+        // An IPv6 address, eg. `[::1]:8776`.
+        let (ip, port) = rest
+            .split_once(']')
+            .ok_or_else(|| SeedError::InvalidHost(addr.to_owned()))?;
+        if ip.parse::<Ipv6Addr>().is_err() {
+            return Err(SeedError::InvalidHost(ip.to_owned()));
+        }
+        match port {
+            "" => (ip, None),
+            port => match port.strip_prefix(':') {
+                Some(port) => (ip, Some(port)),
+                None => return Err(SeedError::InvalidHost(addr.to_owned())),
+            },
+        }
+    } else {
+        match addr.split_once(':') {
+            Some((host, port)) => (host, Some(port)),
+            None => (addr, None),
+        }
+    };
+    if host.parse::<IpAddr>().is_err() && !is_valid_domain(host) {
+        return Err(SeedError::InvalidHost(host.to_owned()));
+    }
+    if let Some(port) = port {
+        if port.parse::<u16>().is_err() {
+            return Err(SeedError::InvalidPort(port.to_owned()));
+        }
+    }
+    Seed::from_str(s).map_err(|e| SeedError::Invalid(e.to_string()))
+}
+
+/// Whether a host name is a valid domain name, eg. `pine.radicle.garden`.
+pub(crate) fn is_valid_domain(domain: &str) -> bool {
+    domain.split('.').all(|label| {
+        !label.is_empty()
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    })
+}
+
 /// Sync result of a seed.
 #[derive(Debug)]
 pub struct SyncResult {
     pub seed: Seed<Vec<SocketAddr>>,
     pub fetch: Option<Result<replication::Success, client::error::Replicate>>,
     pub push: Option<Result<push::Success, push::Error>>,
+    /// Whether the fetch or push with this seed was aborted because it took too long.
+    pub timed_out: bool,
+}
+
+/// Outcome of syncing with a single seed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    /// The sync succeeded. Includes the number of refs fetched and pushed.
+    Synced { fetched: usize, pushed: usize },
+    /// We were unable to connect to the seed.
+    NoConnection,
+    /// The seed did not respond in time.
+    NoResponse,
+    /// The sync did not complete within the allotted time.
+    TimedOut,
+    /// The sync failed for some other reason.
+    Failed(String),
+}
+
+impl Status {
+    /// Number of refs updated by the sync, in either direction.
+    pub fn updated(&self) -> usize {
+        match self {
+            Self::Synced { fetched, pushed } => fetched + pushed,
+            _ => 0,
+        }
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Synced { .. } => match self.updated() {
+                0 => write!(f, "no refs updated"),
+                1 => write!(f, "1 ref updated"),
+                n => write!(f, "{} refs updated", n),
+            },
+            Self::NoConnection => write!(f, "no connection"),
+            Self::NoResponse => write!(f, "no response"),
+            Self::TimedOut => write!(f, "timed out"),
+            Self::Failed(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl SyncResult {
+    /// Whether all attempted operations with this seed succeeded.
+    pub fn is_success(&self) -> bool {
+        matches!(self.status(), Status::Synced { .. })
+    }
+
+    /// Get the status of this sync.
+    pub fn status(&self) -> Status {
+        if self.timed_out {
+            return Status::TimedOut;
+        }
+        let fetched = match &self.fetch {
+            Some(Ok(success)) => success.updated_refs().len(),
+            Some(Err(client::error::Replicate::NoConnection(_))) => return Status::NoConnection,
+            Some(Err(err)) => return Status::Failed(err.to_string()),
+            None => 0,
+        };
+        let pushed = match &self.push {
+            Some(Ok(success)) => success.refs.len(),
+            Some(Err(push::Error::NoResponse)) => return Status::NoResponse,
+            Some(Err(err)) => return Status::Failed(err.to_string()),
+            None => 0,
+        };
+
+        Status::Synced { fetched, pushed }
+    }
 }
 
 /// Sync the given URN with the provided list of seeds.
+///
+/// Seeds are synced with concurrently. A failure to sync with one seed does not
+/// affect the others. Fetching from and pushing to each seed are each given at most
+/// `timeout` to complete, and `progress` is called as each seed completes.
 pub async fn sync<S, E>(
     client: &Client<S, E>,
     urn: Urn,
     seeds: Seeds,
     mode: Mode,
     timeout: time::Duration,
+    mut progress: impl FnMut(&SyncResult),
 ) -> Vec<SyncResult>
 where
     S: Signer + Clone,
     E: ConnectPeer + Clone + Send + Sync + 'static,
 {
-    let mut syncs = Vec::with_capacity(seeds.len());
     let is_push = mode.is_push();
     let is_fetch = mode.is_fetch();
     let Seeds(seeds) = seeds;
 
-    for seed in seeds {
-        let fetch = if is_fetch {
-            match tokio::time::timeout(timeout, client.replicate(seed.clone(), urn.clone(), None))
+    let syncs = seeds.into_iter().enumerate().map(|(ix, seed)| {
+        let urn = urn.clone();
+
+        async move {
+            let mut timed_out = false;
+            let fetch = if is_fetch {
+                match tokio::time::timeout(
+                    timeout,
+                    client.replicate(seed.clone(), urn.clone(), None),
+                )
                 .await
-            {
-                Ok(result) => Some(result),
-                Err(_) => Some(Err(client::error::Replicate::NoConnection(
-                    client::error::NoConnection(seed.peer),
-                ))),
-            }
-        } else {
-            None
-        };
+                {
+                    Ok(result) => Some(result),
+                    Err(_) => {
+                        timed_out = true;
+                        None
+                    }
+                }
+            } else {
+                None
+            };
 
-        let push = if is_push {
-            Some(push::push(client, urn.clone(), seed.clone(), timeout).await)
-        } else {
-            None
-        };
+            // If the seed didn't respond to the fetch in time, don't wait on it again.
+            let push = if is_push && !timed_out {
+                match tokio::time::timeout(timeout, push::push(client, urn, seed.clone())).await {
+                    Ok(result) => Some(result),
+                    Err(_) => {
+                        timed_out = true;
+                        None
+                    }
+                }
+            } else {
+                None
+            };
+
+            let result = SyncResult {
+                seed,
+                fetch,
+                push,
+                timed_out,
+            };
+            (ix, result)
+        }
+    });
+
+    let mut syncs = syncs.collect::<FuturesUnordered<_>>();
+    let mut results = Vec::new();
 
-        syncs.push(SyncResult { seed, fetch, push })
+    while let Some((ix, result)) = syncs.next().await {
+        progress(&result);
+        results.push((ix, result));
     }
-    syncs
+    // Keep results in the order the seeds were given.
+    results.sort_by_key(|(ix, _)| *ix);
+    results.into_iter().map(|(_, r)| r).collect()
 }
 
 /// Create a sync client.
@@ -98,17 +306,52 @@ pub async fn client(
     Ok(client)
 }
 
+/// Where the seeds used for syncing are configured, see [`seeds_with_source`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SeedsSource {
+    /// The working copy configuration, ie. `Radicle.toml`.
+    Local,
+    /// The profile configuration.
+    Profile(PathBuf),
+    /// The profile's seeds file, used when no configuration has seeds.
+    SeedsFile(PathBuf),
+}
+
+impl fmt::Display for SeedsSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Local => write!(f, "{} (working copy)", config::FILE_NAME_LOCAL),
+            Self::Profile(path) => write!(f, "{}", path.display()),
+            Self::SeedsFile(path) => write!(f, "{} (seeds file)", path.display()),
+        }
+    }
+}
+
 /// Get the seeds configured for the profile.
 /// First checks local (working copy) config, then global.
 pub fn seeds(profile: &Profile) -> anyhow::Result<NonEmpty<Seed<String>>> {
-    if let Ok(config) = config::Config::load(profile) {
+    seeds_with_source(profile).map(|(seeds, _)| seeds)
+}
+
+/// Get the seeds configured for the profile, along with where they were found.
+pub fn seeds_with_source(
+    profile: &Profile,
+) -> anyhow::Result<(NonEmpty<Seed<String>>, SeedsSource)> {
+    let config = config::Config::local()
+        .map(|config| (config, SeedsSource::Local))
+        .or_else(|_| {
+            config::Config::profile(profile)
+                .map(|config| (config, SeedsSource::Profile(config::Config::path(profile))))
+        });
+
+    if let Ok((config, source)) = config {
         let seeds = config
             .seeds()
             .cloned()
             .map(|s| s.try_into())
             .collect::<Result<Vec<_>, _>>()?;
         if let Ok(seeds) = seeds.try_into() {
-            return Ok(seeds);
+            return Ok((seeds, source));
         }
     }
 
@@ -116,10 +359,43 @@ pub fn seeds(profile: &Profile) -> anyhow::Result<NonEmpty<Seed<String>>> {
     // Nb. These seeds are in a different format, and eventually we will consolidate the
     // seed configurations under a unified format. For now we support both.
     let seeds_file = profile.paths().seeds_file();
+    let source = SeedsSource::SeedsFile(seeds_file.to_path_buf());
     let store = FileStore::<String>::new(seeds_file)?;
     let seeds: Vec<_> = store.iter()?.collect::<Result<_, _>>()?;
 
     seeds
         .try_into()
+        .map(|seeds| (seeds, source))
         .map_err(|_| anyhow!("No seeds configured for profile {}", profile.id()))
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_seed() {
+        let peer = "hyb5to4rshftx4apgmu9s6wnsp4ddmp1mz6ijh4qqey7fb8wrpawxa";
+
+        let seed = parse_seed(&format!("{}@pine.radicle.garden:8776", peer)).unwrap();
+        assert_eq!(seed.addrs, "pine.radicle.garden:8776");
+        parse_seed(&format!("{}@[::1]:8776", peer)).unwrap();
+
+        assert!(matches!(
+            parse_seed("pine.radicle.garden:8776"),
+            Err(SeedError::MissingPeerId)
+        ));
+        assert!(matches!(
+            parse_seed("hyb5to4@pine.radicle.garden:8776"),
+            Err(SeedError::InvalidPeerId(_))
+        ));
+        assert!(matches!(
+            parse_seed(&format!("{}@pine_radicle!garden:8776", peer)),
+            Err(SeedError::InvalidHost(_))
+        ));
+        assert!(matches!(
+            parse_seed(&format!("{}@pine.radicle.garden:87760", peer)),
+            Err(SeedError::InvalidPort(_))
+        ));
+    }
+}
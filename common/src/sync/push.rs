@@ -1,5 +1,4 @@
 use std::net::SocketAddr;
-use std::time;
 
 use futures_lite::StreamExt;
 use thiserror::Error;
@@ -16,8 +15,6 @@ use librad::{
 use lnk_clib::seed::Seed;
 pub use request_pull::Success;
 
-use crate::tokio;
-
 #[derive(Debug, Error)]
 pub enum Error {
     #[error(transparent)]
@@ -32,7 +29,6 @@ pub(super) async fn push<S, E>(
     client: &Client<S, E>,
     urn: Urn,
     seed: Seed<Vec<SocketAddr>>,
-    timeout: time::Duration,
 ) -> Result<request_pull::Success, Error>
 where
     S: Signer + Clone,
@@ -40,7 +36,7 @@ where
 {
     let mut req = client.request_pull(seed.clone(), urn.clone()).await?;
 
-    while let Ok(Some(res)) = tokio::time::timeout(timeout, req.next()).await {
+    while let Some(res) = req.next().await {
         match res {
             Ok(res) => match res {
                 request_pull::Response::Success(succ) => return Ok(succ),
@@ -55,7 +55,9 @@ pub mod setup {
             "nakamoto".to_owned(),
             "Bitcoin light-client".to_owned(),
             "master".to_owned(),
-        );
+            project::Discovery::default(),
+        )
+        .unwrap();
         let project = project::create(payload, &storage).unwrap();
 
         (storage, profile, whoami, project)
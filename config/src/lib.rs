@@ -0,0 +1,227 @@
+use std::convert::TryFrom;
+use std::ffi::OsString;
+use std::str::FromStr;
+
+use anyhow::anyhow;
+
+use radicle_common::args::{Args, Error, Help};
+use radicle_common::config::{self, Config, SeedConfig};
+use radicle_common::sync;
+use radicle_terminal as term;
+
+pub const HELP: Help = Help {
+    name: "config",
+    description: env!("CARGO_PKG_DESCRIPTION"),
+    version: env!("CARGO_PKG_VERSION"),
+    usage: r#"
+Usage
+
+    rad config list
+    rad config get <key>
+    rad config set <key> <value>...
+
+    Shows or modifies the configuration of the active profile.
+
+    `list` and `get` show the seeds that are synced with, and where they are
+    configured: the working copy's `Radicle.toml` takes precedence over the
+    profile configuration, which takes precedence over the profile's seeds file.
+    `set` always modifies the profile configuration.
+
+Keys
+
+    seed    The default seeds to sync with, as `<peer-id>@<addr>:<port>` values.
+            Setting this key replaces all configured seeds.
+
+Options
+
+    --help    Print help
+"#,
+};
+
+/// Configuration keys supported by this command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+    Seed,
+}
+
+impl FromStr for Key {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "seed" | "seeds" => Ok(Self::Seed),
+            _ => Err(anyhow!("unknown config key '{}'", s)),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum OperationName {
+    List,
+    Get,
+    Set,
+}
+
+#[derive(Debug)]
+pub enum Operation {
+    List,
+    Get { key: Key },
+    Set { key: Key, values: Vec<String> },
+}
+
+#[derive(Debug)]
+pub struct Options {
+    pub op: Operation,
+}
+
+impl Args for Options {
+    fn from_args(args: Vec<OsString>) -> anyhow::Result<(Self, Vec<OsString>)> {
+        use lexopt::prelude::*;
+
+        let mut parser = lexopt::Parser::from_args(args);
+        let mut op: Option<OperationName> = None;
+        let mut key: Option<Key> = None;
+        let mut values: Vec<String> = Vec::new();
+
+        while let Some(arg) = parser.next()? {
+            match arg {
+                Long("help") => {
+                    return Err(Error::Help.into());
+                }
+                Value(val) if op.is_none() => match val.to_string_lossy().as_ref() {
+                    "l" | "list" => op = Some(OperationName::List),
+                    "g" | "get" => op = Some(OperationName::Get),
+                    "s" | "set" => op = Some(OperationName::Set),
+
+                    unknown => anyhow::bail!("unknown operation '{}'", unknown),
+                },
+                Value(val) if op != Some(OperationName::List) && key.is_none() => {
+                    key = Some(val.to_string_lossy().parse()?);
+                }
+                Value(val) if op == Some(OperationName::Set) => {
+                    values.push(val.to_string_lossy().to_string());
+                }
+                _ => return Err(anyhow!(arg.unexpected())),
+            }
+        }
+
+        let op = match op.unwrap_or(OperationName::List) {
+            OperationName::List => Operation::List,
+            OperationName::Get => Operation::Get {
+                key: key.ok_or_else(|| anyhow!("a key must be specified"))?,
+            },
+            OperationName::Set => {
+                let key = key.ok_or_else(|| anyhow!("a key must be specified"))?;
+                if values.is_empty() {
+                    anyhow::bail!("a value must be specified");
+                }
+                Operation::Set { key, values }
+            }
+        };
+
+        Ok((Options { op }, vec![]))
+    }
+}
+
+pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
+    let profile = ctx.profile()?;
+
+    match options.op {
+        Operation::List => {
+            let (seeds, source) = sync::seeds_with_source(&profile)?;
+            term::info!("{}", term::format::dim(source));
+
+            let mut table = term::Table::default();
+            for seed in &seeds {
+                table.push([String::from("seed"), format_seed(seed)]);
+            }
+            table.render();
+        }
+        Operation::Get { key: Key::Seed } => {
+            for seed in &sync::seeds(&profile)? {
+                term::print(format_seed(seed));
+            }
+        }
+        Operation::Set {
+            key: Key::Seed,
+            values,
+        } => {
+            let path = Config::path(&profile);
+            let config = Config {
+                seed: parse_seeds(&values)?,
+                ..Config::profile(&profile).unwrap_or_default()
+            };
+
+            config.write(&path)?;
+            term::success!("Updated {}", term::format::dim(path.display()));
+
+            if Config::local().is_ok() {
+                term::warning(&format!(
+                    "Seeds configured in {} take precedence in this working copy",
+                    config::FILE_NAME_LOCAL
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Parse seed values given to `set`, of the form `<peer-id>@<host>:<port>`.
+fn parse_seeds(values: &[String]) -> anyhow::Result<Vec<SeedConfig>> {
+    values
+        .iter()
+        .map(|v| {
+            let seed = sync::parse_seed(v).map_err(|e| anyhow!("invalid seed '{}': {}", v, e))?;
+            SeedConfig::try_from(seed)
+        })
+        .collect()
+}
+
+/// Format a seed as `<peer-id>@<host>:<port>`, the format accepted by `set`.
+fn format_seed(seed: &sync::Seed<String>) -> String {
+    format!("{}@{}", seed.peer, seed.addrs)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::convert::TryInto;
+
+    const PEER: &str = "hyb5to4rshftx4apgmu9s6wnsp4ddmp1mz6ijh4qqey7fb8wrpawxa";
+
+    #[test]
+    fn test_format_seed() {
+        let value = format!("{}@pine.radicle.garden:8776", PEER);
+        let seed = sync::parse_seed(&value).unwrap();
+
+        assert_eq!(format_seed(&seed), value);
+    }
+
+    #[test]
+    fn test_parse_seeds() {
+        let values = vec![
+            format!("{}@pine.radicle.garden:8777", PEER),
+            format!("{}@willow.radicle.garden", PEER),
+        ];
+        let seeds = parse_seeds(&values)
+            .unwrap()
+            .into_iter()
+            .map(|s| s.try_into())
+            .collect::<anyhow::Result<Vec<sync::Seed<String>>>>()
+            .unwrap();
+        let formatted = seeds.iter().map(format_seed).collect::<Vec<_>>();
+
+        assert_eq!(
+            formatted,
+            vec![
+                format!("{}@pine.radicle.garden:8777", PEER),
+                // The default port is used when none is given.
+                format!("{}@willow.radicle.garden:8776", PEER),
+            ]
+        );
+
+        assert!(parse_seeds(&[String::from("pine.radicle.garden")]).is_err());
+        assert!(parse_seeds(&[format!("{}@", PEER)]).is_err());
+    }
+}
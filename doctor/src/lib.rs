@@ -0,0 +1,271 @@
+use std::ffi::OsString;
+use std::fs;
+use std::process;
+
+use anyhow::anyhow;
+use url::Url;
+
+use radicle_common::args::{Args, Error, Help};
+use radicle_common::profile::Profile;
+use radicle_common::{git, keys, seed, signer, sync};
+use radicle_terminal as term;
+
+pub const HELP: Help = Help {
+    name: "doctor",
+    description: env!("CARGO_PKG_DESCRIPTION"),
+    version: env!("CARGO_PKG_VERSION"),
+    usage: r#"
+Usage
+
+    rad doctor [--help]
+
+    Checks that git, your radicle profile, the signer (ssh-agent or hardware),
+    the monorepo and the configured seeds are set up correctly. Exits with a non-zero status if
+    any critical check fails.
+
+Options
+
+    --help   Print help
+"#,
+};
+
+#[derive(Default, Debug, Eq, PartialEq)]
+pub struct Options {}
+
+impl Args for Options {
+    fn from_args(args: Vec<OsString>) -> anyhow::Result<(Self, Vec<OsString>)> {
+        use lexopt::prelude::*;
+
+        let mut parser = lexopt::Parser::from_args(args);
+
+        if let Some(arg) = parser.next()? {
+            match arg {
+                Long("help") => {
+                    return Err(Error::Help.into());
+                }
+                _ => return Err(anyhow!(arg.unexpected())),
+            }
+        }
+
+        Ok((Options {}, vec![]))
+    }
+}
+
+/// Outcome of a single check.
+#[derive(Debug)]
+enum Status {
+    /// The check passed.
+    Pass,
+    /// The check failed, but radicle can still be used.
+    Warn,
+    /// The check failed, and radicle can't be used until it is fixed.
+    Fail,
+    /// The check wasn't run.
+    Skip,
+}
+
+/// A diagnostic check.
+#[derive(Debug)]
+struct Check {
+    name: &'static str,
+    status: Status,
+    detail: String,
+    hint: Option<&'static str>,
+}
+
+impl Check {
+    fn pass(name: &'static str, detail: impl ToString) -> Self {
+        Self {
+            name,
+            status: Status::Pass,
+            detail: detail.to_string(),
+            hint: None,
+        }
+    }
+
+    fn skip(name: &'static str, detail: impl ToString) -> Self {
+        Self {
+            name,
+            status: Status::Skip,
+            detail: detail.to_string(),
+            hint: None,
+        }
+    }
+
+    fn warn(name: &'static str, detail: impl ToString, hint: &'static str) -> Self {
+        Self {
+            name,
+            status: Status::Warn,
+            detail: detail.to_string(),
+            hint: Some(hint),
+        }
+    }
+
+    fn fail(name: &'static str, detail: impl ToString, hint: &'static str) -> Self {
+        Self {
+            name,
+            status: Status::Fail,
+            detail: detail.to_string(),
+            hint: Some(hint),
+        }
+    }
+}
+
+pub fn run(_options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
+    let mut checks = vec![check_git()];
+
+    match ctx.profile() {
+        Ok(profile) => {
+            checks.push(Check::pass("Profile", profile.id()));
+            checks.push(check_signer(&profile));
+            checks.push(check_monorepo(&profile));
+            checks.extend(check_seeds(&profile));
+        }
+        Err(err) => {
+            checks.push(Check::fail(
+                "Profile",
+                err,
+                "To setup your radicle profile, run `rad auth`.",
+            ));
+        }
+    }
+
+    let mut table = term::Table::default();
+    for check in &checks {
+        let mark = match check.status {
+            Status::Pass => term::format::positive("✓"),
+            Status::Warn => term::format::yellow("!"),
+            Status::Fail => term::format::negative("✗"),
+            Status::Skip => term::format::dim("-"),
+        };
+        table.push([
+            mark,
+            term::format::bold(check.name),
+            term::format::dim(&check.detail),
+        ]);
+    }
+    table.render();
+
+    let mut hints = Vec::new();
+    for hint in checks.iter().filter_map(|c| c.hint) {
+        if !hints.contains(&hint) {
+            hints.push(hint);
+        }
+    }
+    if !hints.is_empty() {
+        term::blank();
+    }
+    for hint in hints {
+        term::tip!("{}", hint);
+    }
+
+    let failed = checks
+        .iter()
+        .filter(|c| matches!(c.status, Status::Fail))
+        .count();
+    if failed > 0 {
+        anyhow::bail!("{} critical check(s) failed", failed);
+    }
+    Ok(())
+}
+
+fn check_git() -> Check {
+    match git::check_version() {
+        Ok(env) => Check::pass(
+            "Git",
+            format!(
+                "git {}, libgit2 {} (https: {}, ssh: {})",
+                env.git,
+                env.libgit2,
+                if env.https { "yes" } else { "no" },
+                if env.ssh { "yes" } else { "no" },
+            ),
+        ),
+        Err(err) => Check::fail(
+            "Git",
+            err,
+            "Install a recent version of git, see https://git-scm.com/downloads.",
+        ),
+    }
+}
+
+fn check_signer(profile: &Profile) -> Check {
+    let (name, agent, hint) = match signer::backend(profile) {
+        Ok(signer::Backend::Hardware) => (
+            "Hardware signer",
+            "the hardware signer",
+            "Load your radicle key onto the device, see `rad auth --help`.",
+        ),
+        Ok(signer::Backend::SshAgent) => (
+            "SSH agent",
+            "ssh-agent",
+            "To add your radicle key to ssh-agent, run `rad auth`.",
+        ),
+        Err(err) => {
+            return Check::fail(
+                "Signer",
+                err,
+                "Check `backend` under `[signer]` in your profile configuration.",
+            )
+        }
+    };
+
+    let sock = match keys::agent(profile) {
+        Ok(sock) => sock,
+        Err(err) => return Check::warn(name, err, hint),
+    };
+    match keys::is_ready(profile, sock) {
+        Ok(true) => Check::pass(name, format!("radicle key is in {}", agent)),
+        Ok(false) => Check::warn(name, format!("radicle key is not in {}", agent), hint),
+        Err(err) => Check::warn(name, err, hint),
+    }
+}
+
+fn check_monorepo(profile: &Profile) -> Check {
+    const NAME: &str = "Monorepo";
+
+    let path = profile.paths().git_dir();
+    let probe = path.join(format!(".rad-doctor-{}", process::id()));
+
+    match fs::write(&probe, b"").and_then(|_| fs::remove_file(&probe)) {
+        Ok(()) => Check::pass(NAME, path.display()),
+        Err(err) => Check::fail(
+            NAME,
+            format!("{} is not writable: {}", path.display(), err),
+            "Check the permissions of your radicle home, see `rad path`.",
+        ),
+    }
+}
+
+fn check_seeds(profile: &Profile) -> Vec<Check> {
+    const NAME: &str = "Seed";
+    const HINT: &str = "Check your seed configuration with `rad config`.";
+
+    if sync::is_offline() {
+        return vec![Check::skip(NAME, "offline mode is enabled")];
+    }
+    let seeds = match sync::seeds(profile) {
+        Ok(seeds) => seeds,
+        Err(err) => return vec![Check::warn(NAME, err, HINT)],
+    };
+
+    seeds
+        .iter()
+        .map(|seed| {
+            let host = seed.addrs.split(':').next().unwrap_or_default();
+            let result = Url::parse(&format!("https://{}", host))
+                .map_err(anyhow::Error::from)
+                .and_then(seed::get_seed_id);
+
+            match result {
+                Ok(id) if id == seed.peer => Check::pass(NAME, format!("{} is reachable", host)),
+                Ok(id) => Check::warn(
+                    NAME,
+                    format!("{} has unexpected peer id {}", host, id),
+                    HINT,
+                ),
+                Err(err) => Check::warn(NAME, format!("{} is unreachable: {}", host, err), HINT),
+            }
+        })
+        .collect()
+}
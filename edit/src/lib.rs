@@ -2,15 +2,17 @@ use std::ffi::OsString;
 use std::str::FromStr;
 
 use radicle_common::args::{Args, Error, Help};
-use radicle_common::keys;
+use radicle_common::{keys, project as common_project};
 use radicle_terminal as term;
 
 use librad::git::identities::{any, person, project, SomeIdentity};
+use librad::git::storage::Storage;
 use librad::git::Urn;
 
-use link_identities::payload::{PersonPayload, ProjectPayload};
+use link_identities::payload::{self, HasNamespace, PersonPayload, ProjectPayload};
 
 use anyhow::anyhow;
+use serde::de::DeserializeOwned;
 
 pub const HELP: Help = Help {
     name: "edit",
@@ -24,15 +26,84 @@ Usage
     Edits the identity document pointed to by the URN. If it isn't specified,
     the current project is edited.
 
+    With `--set`, the document is updated without opening an editor. The field
+    is given as a JSON pointer relative to the document's subject, ie. the project
+    or person. The value is parsed as JSON if possible, and used as a string
+    otherwise, eg. `--set /description="My project"`. This option can be specified
+    multiple times.
+
 Options
 
-    --help              Print help
+    --set <pointer>=<value>    Set the subject's field at the given JSON pointer
+    --help                     Print help
 "#,
 };
 
-#[derive(Default, Debug, Eq, PartialEq)]
+#[derive(Default, Debug, PartialEq)]
 pub struct Options {
     pub urn: Option<Urn>,
+    pub set: Vec<Assignment>,
+}
+
+/// A field assignment, eg. `/description="My project"`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Assignment {
+    /// JSON pointer to the field, relative to the subject, eg. `/description`.
+    pub pointer: String,
+    /// New value of the field.
+    pub value: serde_json::Value,
+}
+
+impl FromStr for Assignment {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (pointer, value) = s
+            .split_once('=')
+            .ok_or_else(|| anyhow!("invalid assignment '{}', expected <pointer>=<value>", s))?;
+
+        if !pointer.starts_with('/') {
+            anyhow::bail!("invalid JSON pointer '{}', must start with '/'", pointer);
+        }
+        let value = serde_json::from_str(value)
+            .unwrap_or_else(|_| serde_json::Value::String(value.to_owned()));
+
+        Ok(Self {
+            pointer: pointer.to_owned(),
+            value,
+        })
+    }
+}
+
+impl Assignment {
+    /// Apply the assignment to a JSON document. The parent of the field must exist.
+    pub fn apply(&self, doc: &mut serde_json::Value) -> anyhow::Result<()> {
+        let (parent, key) = self
+            .pointer
+            .rsplit_once('/')
+            .ok_or_else(|| anyhow!("invalid JSON pointer '{}'", self.pointer))?;
+        let key = key.replace("~1", "/").replace("~0", "~");
+        let target = doc
+            .pointer_mut(parent)
+            .ok_or_else(|| anyhow!("field '{}' not found", parent))?;
+
+        match target {
+            serde_json::Value::Object(map) => {
+                map.insert(key, self.value.clone());
+            }
+            serde_json::Value::Array(array) => {
+                let index: usize = key
+                    .parse()
+                    .map_err(|_| anyhow!("invalid array index '{}' in '{}'", key, self.pointer))?;
+                let elem = array
+                    .get_mut(index)
+                    .ok_or_else(|| anyhow!("field '{}' not found", self.pointer))?;
+                *elem = self.value.clone();
+            }
+            _ => anyhow::bail!("field '{}' is not an object or array", parent),
+        }
+        Ok(())
+    }
 }
 
 impl Args for Options {
@@ -41,12 +112,19 @@ impl Args for Options {
 
         let mut parser = lexopt::Parser::from_args(args);
         let mut urn: Option<Urn> = None;
+        let mut set = Vec::new();
 
         while let Some(arg) = parser.next()? {
             match arg {
                 Long("help") => {
                     return Err(Error::Help.into());
                 }
+                Long("set") => {
+                    let value = parser.value()?;
+                    let value = value.to_string_lossy();
+
+                    set.push(value.parse()?);
+                }
                 Value(val) if urn.is_none() => {
                     let val = val.to_string_lossy();
 
@@ -60,7 +138,7 @@ impl Args for Options {
             }
         }
 
-        Ok((Options { urn }, vec![]))
+        Ok((Options { urn, set }, vec![]))
     }
 }
 
@@ -79,32 +157,41 @@ pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
 
     match identity {
         SomeIdentity::Project(_) => {
-            let payload = serde_json::to_string_pretty(
+            let payload = serde_json::to_value(
                 project::verify(&storage, &urn)?
                     .ok_or_else(|| anyhow!("Couldn't get project's identity doc"))?
                     .payload(),
             )?;
-            match term::Editor::new().edit(&payload)? {
-                Some(updated_payload) => {
-                    let payload: ProjectPayload = serde_json::from_str(&updated_payload)?;
-                    project::update(&storage, &urn, None, payload, None)?;
-                }
-                None => return Err(anyhow!("Operation aborted!")),
-            }
+            let validate = |payload: &ProjectPayload| validate_project(&storage, &urn, payload);
+            let payload = if options.set.is_empty() {
+                edit(&payload, validate)?
+            } else {
+                set(
+                    payload,
+                    payload::Project::namespace().as_str(),
+                    &options.set,
+                    validate,
+                )?
+            };
+            project::update(&storage, &urn, None, payload, None)?;
         }
         SomeIdentity::Person(_) => {
-            let payload = serde_json::to_string_pretty(
+            let payload = serde_json::to_value(
                 person::verify(&storage, &urn)?
                     .ok_or_else(|| anyhow!("Couldn't get person's identity doc"))?
                     .payload(),
             )?;
-            match term::Editor::new().edit(&payload)? {
-                Some(updated_payload) => {
-                    let payload: PersonPayload = serde_json::from_str(&updated_payload)?;
-                    person::update(&storage, &urn, None, payload, None)?;
-                }
-                None => return Err(anyhow!("Operation aborted!")),
-            }
+            let payload = if options.set.is_empty() {
+                edit(&payload, validate_person)?
+            } else {
+                set(
+                    payload,
+                    payload::Person::namespace().as_str(),
+                    &options.set,
+                    validate_person,
+                )?
+            };
+            person::update(&storage, &urn, None, payload, None)?;
         }
         _ => {
             anyhow::bail!("Operation not supported for identity type of {}", urn)
@@ -115,3 +202,168 @@ pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Basic sanity checks on an edited project payload.
+fn validate_project(storage: &Storage, urn: &Urn, payload: &ProjectPayload) -> anyhow::Result<()> {
+    let subject = &payload.subject;
+    if subject.name.to_string().trim().is_empty() {
+        anyhow::bail!("project name must not be empty");
+    }
+    let branch = subject
+        .default_branch
+        .as_ref()
+        .ok_or_else(|| anyhow!("project must have a default branch"))?
+        .to_string();
+    if common_project::get_local_head(storage, urn, &branch)?.is_none() {
+        anyhow::bail!("default branch '{}' was not found", branch);
+    }
+    Ok(())
+}
+
+/// Basic sanity checks on an edited person payload.
+fn validate_person(payload: &PersonPayload) -> anyhow::Result<()> {
+    if payload.subject.name.to_string().trim().is_empty() {
+        anyhow::bail!("person name must not be empty");
+    }
+    Ok(())
+}
+
+/// Apply the given assignments to the subject of a payload, found under the given
+/// namespace, and parse and validate the result.
+fn set<T, F>(
+    mut payload: serde_json::Value,
+    namespace: &str,
+    assignments: &[Assignment],
+    validate: F,
+) -> anyhow::Result<T>
+where
+    T: DeserializeOwned,
+    F: Fn(&T) -> anyhow::Result<()>,
+{
+    let subject = payload
+        .get_mut(namespace)
+        .ok_or_else(|| anyhow!("the document has no subject under '{}'", namespace))?;
+
+    for assignment in assignments {
+        assignment.apply(subject)?;
+    }
+    let payload = serde_json::from_value::<T>(payload)
+        .map_err(|e| anyhow!("the updated document is invalid: {}", e))?;
+    validate(&payload)?;
+
+    Ok(payload)
+}
+
+/// Open the given payload in an editor, and parse and validate the result.
+///
+/// If the edited payload is invalid, the editor is re-opened with the error
+/// prepended as a comment, so that no edits are lost.
+fn edit<T, F>(payload: &serde_json::Value, validate: F) -> anyhow::Result<T>
+where
+    T: DeserializeOwned,
+    F: Fn(&T) -> anyhow::Result<()>,
+{
+    let mut text = serde_json::to_string_pretty(payload)?;
+
+    loop {
+        let edited = term::Editor::new()
+            .edit(&text)?
+            .ok_or_else(|| anyhow!("Operation aborted!"))?;
+        let edited = edited
+            .lines()
+            .filter(|l| !l.starts_with(COMMENT))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let result = serde_json::from_str::<T>(&edited)
+            .map_err(anyhow::Error::from)
+            .and_then(|payload| validate(&payload).map(|_| payload));
+
+        match result {
+            Ok(payload) => return Ok(payload),
+            Err(err) => {
+                text = format!(
+                    "{} Error: {}\n{} {}\n{}",
+                    COMMENT, err, COMMENT, EDIT_HINT, edited
+                );
+            }
+        }
+    }
+}
+
+/// Prefix of comment lines in the editor, which are stripped before parsing.
+const COMMENT: &str = "//";
+/// Hint shown when re-opening the editor after an error.
+const EDIT_HINT: &str = "Please fix the document and save, or quit without saving to abort.";
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_assignment() {
+        let mut doc = serde_json::json!({
+            "subject": { "name": "radicle", "description": "" },
+            "tags": ["a", "b"],
+        });
+
+        "/subject/description=A peer-to-peer code collaboration stack"
+            .parse::<Assignment>()
+            .unwrap()
+            .apply(&mut doc)
+            .unwrap();
+        "/tags/1=\"c\""
+            .parse::<Assignment>()
+            .unwrap()
+            .apply(&mut doc)
+            .unwrap();
+        "/subject/count=3"
+            .parse::<Assignment>()
+            .unwrap()
+            .apply(&mut doc)
+            .unwrap();
+
+        assert_eq!(
+            doc,
+            serde_json::json!({
+                "subject": {
+                    "name": "radicle",
+                    "description": "A peer-to-peer code collaboration stack",
+                    "count": 3,
+                },
+                "tags": ["a", "c"],
+            })
+        );
+        assert!("description=foo".parse::<Assignment>().is_err());
+        assert!("/missing/field=1"
+            .parse::<Assignment>()
+            .unwrap()
+            .apply(&mut doc)
+            .is_err());
+    }
+
+    #[test]
+    fn test_set_relative_to_subject() {
+        let namespace = "https://radicle.xyz/link/identities/project/v1";
+        let doc = serde_json::json!({
+            "https://radicle.xyz/link/identities/project/v1": {
+                "name": "radicle",
+                "description": "",
+            },
+        });
+        let assignments = vec!["/description=My project".parse::<Assignment>().unwrap()];
+        let payload: serde_json::Value = set(doc, namespace, &assignments, |_| Ok(())).unwrap();
+
+        assert_eq!(
+            payload,
+            serde_json::json!({
+                "https://radicle.xyz/link/identities/project/v1": {
+                    "name": "radicle",
+                    "description": "My project",
+                },
+            })
+        );
+
+        let other = "https://radicle.xyz/link/identities/person/v1";
+        assert!(set::<serde_json::Value, _>(payload, other, &assignments, |_| Ok(())).is_err());
+    }
+}
@@ -26,6 +26,7 @@ Usage
     rad ens --setup       [<option>...] [--rpc-url <url>] --ledger-hdpath <hd-path>
     rad ens --setup       [<option>...] [--rpc-url <url>] --keystore <file>
     rad ens --setup       [<option>...] [--rpc-url <url>] --walletconnect
+    rad ens --lookup <name> [<option>...] [--rpc-url <url>]
     rad ens [<operation>] [<option>...]
 
     If no operation is specified, `--show` is implied.
@@ -35,22 +36,28 @@ Operations
     --show                       Show ENS data for your local radicle identity
     --setup [<name>]             Associate your local identity with an ENS name
     --set-local <name>           Set an ENS name for your local radicle identity
+    --lookup <name>              Show the radicle records of any ENS name
 
 Options
 
+    --unset <key>                Remove a text record during setup (eg. 'com.twitter')
     --help                       Print help
 
+    During setup, a record can also be cleared by entering an empty value.
+
 Wallet options
 
     --rpc-url <url>              JSON-RPC URL of Ethereum node (eg. http://localhost:8545)
     --ledger-hdpath <hdpath>     Account derivation path when using a Ledger hardware device
     --keystore <file>            Keystore file containing encrypted private key (default: none)
+    --chain <name|id>            Chain to use: mainnet, goerli, sepolia or a chain id (default: mainnet)
     --walletconnect              Use WalletConnect
 
 Environment variables
 
-    ETH_RPC_URL  Ethereum JSON-RPC URL (overwrite with '--rpc-url')
-    ETH_HDPATH   Hardware wallet derivation path (overwrite with '--ledger-hdpath')
+    ETH_RPC_URL          Ethereum JSON-RPC URL (overwrite with '--rpc-url')
+    ETH_RPC_URL_<CHAIN>  JSON-RPC URL for a specific chain, eg. 'ETH_RPC_URL_SEPOLIA' (takes precedence over 'ETH_RPC_URL')
+    ETH_HDPATH           Hardware wallet derivation path (overwrite with '--ledger-hdpath')
 "#,
 };
 
@@ -59,11 +66,13 @@ pub enum Operation {
     Show,
     Setup(Option<String>),
     SetLocal(String),
+    Lookup(String),
 }
 
 #[derive(Debug)]
 pub struct Options {
     pub operation: Operation,
+    pub unset: Vec<String>,
     pub provider: ethereum::ProviderOptions,
     pub signer: ethereum::SignerOptions,
 }
@@ -76,6 +85,7 @@ impl Args for Options {
         let (provider, parser) = ProviderOptions::from(parser)?;
         let (signer, mut parser) = SignerOptions::from(parser)?;
         let mut operation = None;
+        let mut unset = Vec::new();
 
         while let Some(arg) = parser.next()? {
             match arg {
@@ -102,6 +112,32 @@ impl Args for Options {
                         return Err(anyhow!("an ENS name must be specified"));
                     }
                 }
+                Long("lookup") if operation.is_none() => {
+                    let name = parser
+                        .value()?
+                        .into_string()
+                        .map_err(|_| anyhow!("invalid ENS name specified"))?;
+                    operation = Some(Operation::Lookup(name));
+                }
+                Long("unset") => {
+                    let key = parser
+                        .value()?
+                        .into_string()
+                        .map_err(|_| anyhow!("invalid record key specified"))?;
+
+                    if [
+                        resolver::RADICLE_SEED_ID_KEY,
+                        resolver::RADICLE_SEED_HOST_KEY,
+                        resolver::RADICLE_ID_KEY,
+                    ]
+                    .contains(&key.as_str())
+                    {
+                        return Err(anyhow!("the '{}' record is always set during setup", key));
+                    }
+                    if !unset.contains(&key) {
+                        unset.push(key);
+                    }
+                }
                 Long("show") if operation.is_none() => {
                     operation = Some(Operation::Show);
                 }
@@ -112,9 +148,15 @@ impl Args for Options {
             }
         }
 
+        let operation = operation.unwrap_or(Operation::Show);
+        if !unset.is_empty() && !matches!(operation, Operation::Setup(_)) {
+            return Err(anyhow!("'--unset' can only be used with '--setup'"));
+        }
+
         Ok((
             Options {
-                operation: operation.unwrap_or(Operation::Show),
+                operation,
+                unset,
                 provider,
                 signer,
             },
@@ -124,14 +166,17 @@ impl Args for Options {
 }
 
 pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
-    let profile = ctx.profile()?;
-    let signer = term::signer(&profile)?;
-    let storage = keys::storage(&profile, signer)?;
     let rt = radicle_common::tokio::runtime::Runtime::new()?;
-    let id = person::local(&storage)?;
 
     match options.operation {
+        // Looking up a name doesn't require a local identity or a wallet.
+        Operation::Lookup(name) => {
+            let provider = ethereum::provider(options.provider)?;
+            rt.block_on(lookup(&name, provider))?;
+        }
         Operation::Show => {
+            let (storage, id) = local_identity(&ctx)?;
+
             if let Some(person) = person::verify(&storage, &id.urn())? {
                 term::success!("Your local identity is {}", term::format::dim(id.urn()));
 
@@ -146,23 +191,40 @@ pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
             }
         }
         Operation::Setup(name) => {
+            let (storage, id) = local_identity(&ctx)?;
+
             term::headline(&format!(
                 "Associating local 🌱 identity {} with ENS",
                 term::format::highlight(&id.urn()),
             ));
             let name = term::text_input("ENS name", name)?;
+            let chain = options.provider.chain;
             let provider = ethereum::provider(options.provider)?;
             let signer_opts = options.signer;
             let (wallet, provider) =
-                rt.block_on(term::ethereum::get_wallet(signer_opts, provider))?;
-            rt.block_on(setup(&name, id, provider, wallet, &storage))?;
+                rt.block_on(term::ethereum::get_wallet(signer_opts, provider, chain))?;
+            rt.block_on(setup(&name, &options.unset, id, provider, wallet, &storage))?;
+        }
+        Operation::SetLocal(name) => {
+            let (storage, _) = local_identity(&ctx)?;
+
+            set_ens_payload(&name, &storage)?;
         }
-        Operation::SetLocal(name) => set_ens_payload(&name, &storage)?,
     }
 
     Ok(())
 }
 
+/// Open the storage of the active profile, and get its local identity.
+fn local_identity(ctx: &impl term::Context) -> anyhow::Result<(Storage, LocalIdentity)> {
+    let profile = ctx.profile()?;
+    let signer = term::signer(&profile)?;
+    let storage = keys::storage(&profile, signer)?;
+    let id = person::local(&storage)?;
+
+    Ok((storage, id))
+}
+
 fn set_ens_payload(name: &str, storage: &Storage) -> anyhow::Result<()> {
     term::info!("Setting ENS name for local 🌱 identity");
 
@@ -183,8 +245,43 @@ fn set_ens_payload(name: &str, storage: &Storage) -> anyhow::Result<()> {
     Ok(())
 }
 
+async fn lookup(name: &str, provider: Provider<Http>) -> anyhow::Result<()> {
+    let spinner = term::spinner(&format!("Looking up {}...", term::format::highlight(name)));
+    let resolver = match PublicResolver::get(name, provider).await {
+        Ok(resolver) => resolver,
+        Err(err) => return Err(spinner.error(err.into())),
+    };
+    let address = resolver.address(name).await?;
+
+    let mut records = vec![("Address", address.map(ethereum::hex))];
+    for (label, key) in [
+        ("Radicle ID", resolver::RADICLE_ID_KEY),
+        ("Seed ID", resolver::RADICLE_SEED_ID_KEY),
+        ("Seed host", resolver::RADICLE_SEED_HOST_KEY),
+        ("GitHub", "com.github"),
+        ("Twitter", "com.twitter"),
+    ] {
+        records.push((label, resolver.text(name, key).await?));
+    }
+    spinner.finish();
+
+    let mut table = term::Table::default();
+    for (label, value) in records {
+        table.push([
+            term::format::bold(label),
+            value.map_or_else(|| term::format::dim("-"), term::format::tertiary),
+        ]);
+    }
+    term::blank();
+    table.render();
+    term::blank();
+
+    Ok(())
+}
+
 async fn setup(
     name: &str,
+    unset: &[String],
     id: LocalIdentity,
     provider: Provider<Http>,
     signer: ethereum::Wallet,
@@ -231,61 +328,56 @@ async fn setup(
     let address: Option<Address> =
         term::text_input_optional("Address", address_current.map(ethereum::hex))?;
 
-    let github_current = resolver.text(name, "com.github").await?;
-    let github: Option<String> =
-        term::text_input_optional("GitHub handle", github_current.clone())?;
-
-    let twitter_current = resolver.text(name, "com.twitter").await?;
-    let twitter: Option<String> =
-        term::text_input_optional("Twitter handle", twitter_current.clone())?;
-
-    let mut calls = vec![
-        resolver
-            .set_text(
-                name,
-                resolver::RADICLE_SEED_ID_KEY,
-                &seed_id.default_encoding(),
-            )?
-            .calldata()
-            .unwrap(), // Safe because we have call data.
-        resolver
-            .set_text(name, resolver::RADICLE_SEED_HOST_KEY, &seed_host)?
-            .calldata()
-            .unwrap(),
-        resolver
-            .set_text(name, resolver::RADICLE_ID_KEY, &urn.to_string())?
-            .calldata()
-            .unwrap(),
-    ];
+    let mut texts = Vec::new();
+    for (key, label) in [
+        ("com.github", "GitHub handle"),
+        ("com.twitter", "Twitter handle"),
+    ] {
+        if unset.iter().any(|k| k == key) {
+            continue;
+        }
+        let current = resolver.text(name, key).await?;
+        let value: Option<String> = term::text_input_optional(label, current.clone())?;
+
+        texts.push((key, current, value));
+    }
+
+    let mut calls = Vec::new();
+    for (key, value) in [
+        (resolver::RADICLE_SEED_ID_KEY, seed_id.default_encoding()),
+        (resolver::RADICLE_SEED_HOST_KEY, seed_host),
+        (resolver::RADICLE_ID_KEY, urn.to_string()),
+    ] {
+        if resolver.text(name, key).await?.as_ref() != Some(&value) {
+            // Safe because we have call data.
+            calls.push(resolver.set_text(name, key, &value)?.calldata().unwrap());
+        }
+    }
 
     if let Some(address) = address {
         if address_current.map_or(true, |a| a != address) {
             calls.push(resolver.set_address(name, address)?.calldata().unwrap());
         }
     }
-    if let Some(github) = github {
-        if github_current.map_or(true, |g| g != github) {
-            calls.push(
-                resolver
-                    .set_text(name, "com.github", &github)?
-                    .calldata()
-                    .unwrap(),
-            );
+    for (key, current, value) in texts {
+        // An empty value clears the record, if it was set.
+        let value = value.unwrap_or_default();
+        if current.unwrap_or_default() != value {
+            calls.push(resolver.set_text(name, key, &value)?.calldata().unwrap());
         }
     }
-    if let Some(twitter) = twitter {
-        if twitter_current.map_or(true, |t| t != twitter) {
-            calls.push(
-                resolver
-                    .set_text(name, "com.twitter", &twitter)?
-                    .calldata()
-                    .unwrap(),
-            );
+    for key in unset {
+        if resolver.text(name, key).await?.is_some() {
+            calls.push(resolver.set_text(name, key, "")?.calldata().unwrap());
         }
     }
 
-    let call = resolver.multicall(calls)?;
-    term::ethereum::transaction(call).await?;
+    if calls.is_empty() {
+        term::info!("Nothing to update, skipping transaction");
+    } else {
+        let call = resolver.multicall(calls)?;
+        term::ethereum::transaction(call).await?;
+    }
 
     if chain_id == u64::from(Chain::Mainnet) {
         let spinner = term::spinner("Updating local identity...");
@@ -4,7 +4,7 @@ use std::str::FromStr;
 
 use ethers::abi::token::{LenientTokenizer, Token, Tokenizer};
 use ethers::abi::AbiParser;
-use ethers::prelude::{Middleware, SignerMiddleware};
+use ethers::prelude::{Http, Middleware, Provider, SignerMiddleware};
 use ethers::types::{Address, U256};
 
 use anyhow::anyhow;
@@ -15,7 +15,7 @@ use radicle_common::args::{Args, Error, Help};
 use radicle_common::ethereum::{
     self,
     governance::{self, Governance},
-    ProviderOptions, SignerOptions,
+    ProviderOptions, SignerOptions, Wallet,
 };
 use radicle_terminal as term;
 
@@ -30,6 +30,7 @@ Usage
     rad gov [<options>...] execute <proposal-id>
     rad gov [<options>...] propose <proposal-file>
     rad gov [<options>...] queue <proposal-id>
+    rad gov [<options>...] status <proposal-id>
     rad gov [<options>...] vote <proposal-id> (true | false)
 
 Options
@@ -41,12 +42,14 @@ Wallet options
     --rpc-url <url>              JSON-RPC URL of Ethereum node (eg. http://localhost:8545)
     --ledger-hdpath <hdpath>     Account derivation path when using a Ledger hardware device
     --keystore <file>            Keystore file containing encrypted private key (default: none)
+    --chain <name|id>            Chain to use: mainnet, goerli, sepolia or a chain id (default: mainnet)
 
 Commands
 
     execute (e)  execute a proposal
     propose (p)  make a governance proposal
     queue   (q)  queue a proposal
+    status  (s)  show the state of a proposal
     vote    (v)  vote on a proposal
 "#,
 };
@@ -55,6 +58,7 @@ enum Command {
     Execute { id: U256 },
     Propose { file: OsString },
     Queue { id: U256 },
+    Status { id: U256 },
     Vote { id: U256 },
 }
 
@@ -97,6 +101,13 @@ impl Args for Options {
                             .map(U256::from_dec_str)
                             .ok_or_else(|| anyhow!("Proposal ID is not a valid uint256"))??;
                         command = Some(Command::Queue { id });
+                    } else if val == "status" || val == "s" {
+                        let id = parser
+                            .value()?
+                            .to_str()
+                            .map(U256::from_dec_str)
+                            .ok_or_else(|| anyhow!("Proposal ID is not a valid uint256"))??;
+                        command = Some(Command::Status { id });
                     } else if val == "vote" || val == "v" {
                         let id = parser
                             .value()?
@@ -126,23 +137,29 @@ impl Args for Options {
 
 pub fn run(options: Options, _ctx: impl term::Context) -> anyhow::Result<()> {
     let rt = radicle_common::tokio::runtime::Runtime::new()?;
+    let chain = options.provider.chain;
     let provider = ethereum::provider(options.provider)?;
-    let signer_opts = options.signer;
-    let (wallet, provider) = rt.block_on(term::ethereum::get_wallet(signer_opts, provider))?;
-    let signer = SignerMiddleware::new(provider, wallet);
-    let governance = Governance::new(signer);
+    let signer = options.signer;
 
     match options.command {
+        // Reading a proposal's status doesn't require a wallet.
+        Command::Status { id } => {
+            rt.block_on(run_status(id, Governance::new(provider)))?;
+        }
         Command::Execute { id } => {
+            let governance = rt.block_on(signed_governance(signer, provider, chain))?;
             rt.block_on(run_execute(id, governance))?;
         }
         Command::Propose { file } => {
+            let governance = rt.block_on(signed_governance(signer, provider, chain))?;
             rt.block_on(run_propose(file, governance))?;
         }
         Command::Queue { id } => {
+            let governance = rt.block_on(signed_governance(signer, provider, chain))?;
             rt.block_on(run_queue(id, governance))?;
         }
         Command::Vote { id } => {
+            let governance = rt.block_on(signed_governance(signer, provider, chain))?;
             rt.block_on(run_vote(id, governance))?;
         }
     }
@@ -150,6 +167,17 @@ pub fn run(options: Options, _ctx: impl term::Context) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Access the governance contract through the wallet specified in the signer options.
+async fn signed_governance(
+    signer: SignerOptions,
+    provider: Provider<Http>,
+    chain: u64,
+) -> anyhow::Result<Governance<SignerMiddleware<Provider<Http>, Wallet>>> {
+    let (wallet, provider) = term::ethereum::get_wallet(signer, provider, chain).await?;
+
+    Ok(Governance::new(SignerMiddleware::new(provider, wallet)))
+}
+
 async fn run_execute<M>(id: U256, governance: Governance<M>) -> anyhow::Result<()>
 where
     M: Middleware + 'static,
@@ -176,13 +204,17 @@ where
         .position(|l| l == "## ACTIONS ##")
         .ok_or_else(|| anyhow!("Proposal is missing `### ACTIONS ###` section."))?;
 
+    if let Err(err) = validate_actions(&content, cutoff) {
+        return Err(spinner.error(err));
+    }
+
     let mut targets: Vec<Address> = Vec::new();
     let mut values: Vec<U256> = Vec::new();
     let mut signatures: Vec<String> = Vec::new();
     let mut calldatas: Vec<Vec<u8>> = Vec::new();
 
     for l in content.lines().skip(cutoff + 1) {
-        if l == "```" || l.is_empty() {
+        if is_ignored(l) {
             continue;
         }
         let mut tokens = l.split(' ');
@@ -253,6 +285,37 @@ where
     Ok(())
 }
 
+async fn run_status<M>(id: U256, governance: Governance<M>) -> anyhow::Result<()>
+where
+    M: Middleware + 'static,
+    crate::governance::Error<M>: From<<M as Middleware>::Error>,
+{
+    let state = governance.get_proposal_state(id).await?;
+    let proposal = governance.get_proposal(id).await?;
+    let mut table = term::Table::default();
+    table.push([
+        term::format::bold("state"),
+        term::format::bold("proposer"),
+        term::format::bold("for"),
+        term::format::bold("against"),
+        term::format::bold("start block"),
+        term::format::bold("end block"),
+    ]);
+    table.push([
+        term::format::highlight(state),
+        term::format::secondary(proposal.0),
+        term::format::positive(format!("▲ {}", proposal.4)),
+        term::format::negative(format!("▼ {}", proposal.5)),
+        term::format::secondary(proposal.2),
+        term::format::secondary(proposal.3),
+    ]);
+    term::blank();
+    table.render();
+    term::blank();
+
+    Ok(())
+}
+
 async fn run_vote<M>(id: U256, governance: Governance<M>) -> anyhow::Result<()>
 where
     M: Middleware + 'static,
@@ -285,6 +348,58 @@ where
     Ok(())
 }
 
+/// Whether a line of the actions section should be skipped.
+fn is_ignored(line: &str) -> bool {
+    let line = line.trim();
+
+    line == "```" || line.is_empty() || line.starts_with('#')
+}
+
+/// Check that every line of the actions section following `cutoff` is a valid action,
+/// reporting the offending line number otherwise.
+fn validate_actions(content: &str, cutoff: usize) -> anyhow::Result<()> {
+    for (ix, l) in content.lines().enumerate().skip(cutoff + 1) {
+        if is_ignored(l) {
+            continue;
+        }
+        let line = ix + 1;
+        let mut tokens = l.split(' ');
+
+        let address = tokens
+            .next()
+            .filter(|t| !t.is_empty())
+            .ok_or_else(|| anyhow!("line {}: missing target address", line))?;
+        Address::from_str(address)
+            .map_err(|_| anyhow!("line {}: invalid target address {:?}", line, address))?;
+
+        let value = tokens
+            .next()
+            .ok_or_else(|| anyhow!("line {}: missing value", line))?;
+        U256::from_dec_str(value)
+            .map_err(|_| anyhow!("line {}: invalid value {:?}", line, value))?;
+
+        let sig = tokens
+            .next()
+            .map(quoteless_string)
+            .ok_or_else(|| anyhow!("line {}: missing function signature", line))?;
+        let function = AbiParser::default()
+            .parse_function(&sig)
+            .map_err(|_| anyhow!("line {}: invalid function signature {:?}", line, sig))?;
+
+        let args = tokens.count();
+        if args != function.inputs.len() {
+            anyhow::bail!(
+                "line {}: function {:?} expects {} argument(s), got {}",
+                line,
+                sig,
+                function.inputs.len(),
+                args
+            );
+        }
+    }
+    Ok(())
+}
+
 fn quoteless_string(str: &str) -> String {
     let re = Regex::new(r#""?(.[^"]*)"?"#).unwrap();
     let ql = re.captures_iter(str).next().unwrap()[1].to_string();
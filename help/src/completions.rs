@@ -0,0 +1,392 @@
+//! Shell completion script generation.
+//!
+//! Commands don't declare their options in a structured way, so the options and
+//! sub-commands of each command are derived from its [`Help`] usage string.
+use std::ffi::OsString;
+use std::fmt::Write as _;
+use std::str::FromStr;
+
+use anyhow::anyhow;
+
+use radicle_common::args::{Args, Error, Help};
+use radicle_terminal as term;
+
+pub const HELP: Help = Help {
+    name: "completions",
+    description: "Generate shell completion scripts",
+    version: env!("CARGO_PKG_VERSION"),
+    usage: r#"
+Usage
+
+    rad completions <shell> [--help]
+
+    Prints a completion script for the given shell to stdout.
+    Supported shells are `bash`, `zsh` and `fish`.
+
+Examples
+
+    rad completions bash > ~/.local/share/bash-completion/completions/rad
+    rad completions zsh > ~/.zfunc/_rad
+    rad completions fish > ~/.config/fish/completions/rad.fish
+
+Options
+
+    --help   Print help
+"#,
+};
+
+/// Options accepted by `rad` itself, before the command.
+const GLOBAL_OPTIONS: &[&str] = &[
+    "--help",
+    "--version",
+    "--verbose",
+    "--quiet",
+    "--offline",
+    "--color",
+    "--signer",
+];
+
+/// Global options that take a value, which must be skipped to find the command.
+const GLOBAL_OPTIONS_WITH_VALUE: &[&str] = &["--color", "--signer"];
+
+/// Shell to generate completions for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+impl FromStr for Shell {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "bash" => Ok(Self::Bash),
+            "zsh" => Ok(Self::Zsh),
+            "fish" => Ok(Self::Fish),
+            _ => Err(anyhow!(
+                "unsupported shell '{}', expected one of: bash, zsh, fish",
+                s
+            )),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Options {
+    pub shell: Shell,
+}
+
+impl Args for Options {
+    fn from_args(args: Vec<OsString>) -> anyhow::Result<(Self, Vec<OsString>)> {
+        use lexopt::prelude::*;
+
+        let mut parser = lexopt::Parser::from_args(args);
+        let mut shell = None;
+
+        while let Some(arg) = parser.next()? {
+            match arg {
+                Long("help") => {
+                    return Err(Error::Help.into());
+                }
+                Value(val) if shell.is_none() => {
+                    let val = val
+                        .to_str()
+                        .ok_or_else(|| anyhow!("shell specified is not UTF-8"))?;
+
+                    shell = Some(val.parse()?);
+                }
+                _ => return Err(anyhow!(arg.unexpected())),
+            }
+        }
+
+        Ok((
+            Options {
+                shell: shell.ok_or_else(|| {
+                    anyhow!("a shell must be specified, eg. `rad completions bash`")
+                })?,
+            },
+            vec![],
+        ))
+    }
+}
+
+pub fn run(options: Options, _ctx: impl term::Context) -> anyhow::Result<()> {
+    let specs = crate::commands().map(Spec::from).collect::<Vec<_>>();
+    let script = match options.shell {
+        Shell::Bash => bash(&specs),
+        Shell::Zsh => zsh(&specs),
+        Shell::Fish => fish(&specs),
+    };
+    print!("{}", script);
+
+    Ok(())
+}
+
+/// Completion candidates of a single command.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Spec {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// Sub-commands, eg. `new` in `rad issue new`.
+    pub subcommands: Vec<String>,
+    /// Long and short options, eg. `--verbose` and `-v`.
+    pub options: Vec<String>,
+}
+
+impl From<&Help> for Spec {
+    fn from(help: &Help) -> Self {
+        let mut spec = Spec {
+            name: help.name,
+            description: help.description,
+            ..Spec::default()
+        };
+        let prefix = format!("rad {} ", help.name);
+
+        for line in help.usage.lines() {
+            let line = line.trim();
+
+            // Sub-commands are the first plain word following the command name in usage lines,
+            // eg. `list` in `rad issue [list]`.
+            if let Some(rest) = line.strip_prefix(&prefix) {
+                let sub = rest
+                    .split_whitespace()
+                    .map(|t| t.trim_matches(|c| c == '[' || c == ']'))
+                    .filter(|t| !t.is_empty() && !t.starts_with('-'))
+                    .find(|t| t.chars().all(|c| c.is_ascii_lowercase() || c == '-'));
+
+                if let Some(sub) = sub {
+                    if !spec.subcommands.iter().any(|s| s == sub) {
+                        spec.subcommands.push(sub.to_owned());
+                    }
+                }
+            }
+            for word in line.split(|c: char| !(c.is_ascii_alphanumeric() || c == '-')) {
+                if !spec.options.iter().any(|o| o == word) && is_option(word) {
+                    spec.options.push(word.to_owned());
+                }
+            }
+        }
+        spec
+    }
+}
+
+/// Whether a word looks like a command-line option, eg. `--help` or `-h`.
+fn is_option(word: &str) -> bool {
+    if let Some(long) = word.strip_prefix("--") {
+        long.starts_with(|c: char| c.is_ascii_lowercase())
+    } else if let Some(short) = word.strip_prefix('-') {
+        short.len() == 1 && short.chars().all(|c| c.is_ascii_alphabetic())
+    } else {
+        false
+    }
+}
+
+/// Candidates of a command, sub-commands first.
+fn words(spec: &Spec) -> String {
+    spec.subcommands
+        .iter()
+        .chain(spec.options.iter())
+        .cloned()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn bash(specs: &[Spec]) -> String {
+    let mut out = String::new();
+    let commands = specs.iter().map(|s| s.name).collect::<Vec<_>>().join(" ");
+
+    writeln!(out, "_rad() {{").ok();
+    writeln!(out, "    local cur=\"${{COMP_WORDS[COMP_CWORD]}}\"").ok();
+    writeln!(out, "    local i=1 cmd=\"\"").ok();
+    writeln!(out).ok();
+    // Skip global options to find the command, eg. in `rad --color never sync`.
+    writeln!(out, "    while [ \"$i\" -lt \"$COMP_CWORD\" ]; do").ok();
+    writeln!(out, "        case \"${{COMP_WORDS[i]}}\" in").ok();
+    writeln!(out, "            {})", GLOBAL_OPTIONS_WITH_VALUE.join("|")).ok();
+    // Bash splits `--color=never` into three words.
+    writeln!(
+        out,
+        "                if [ \"${{COMP_WORDS[i+1]}}\" = \"=\" ]; then i=$((i + 3)); else i=$((i + 2)); fi ;;"
+    )
+    .ok();
+    writeln!(out, "            -*) i=$((i + 1)) ;;").ok();
+    writeln!(out, "            *) cmd=\"${{COMP_WORDS[i]}}\"; break ;;").ok();
+    writeln!(out, "        esac").ok();
+    writeln!(out, "    done").ok();
+    writeln!(out).ok();
+    writeln!(out, "    if [ -z \"$cmd\" ]; then").ok();
+    writeln!(
+        out,
+        "        COMPREPLY=($(compgen -W \"{} {}\" -- \"$cur\"))",
+        commands,
+        GLOBAL_OPTIONS.join(" ")
+    )
+    .ok();
+    writeln!(out, "        return").ok();
+    writeln!(out, "    fi").ok();
+    writeln!(out, "    case \"$cmd\" in").ok();
+    for spec in specs {
+        writeln!(
+            out,
+            "        {}) COMPREPLY=($(compgen -W \"{}\" -- \"$cur\")) ;;",
+            spec.name,
+            words(spec)
+        )
+        .ok();
+    }
+    writeln!(out, "    esac").ok();
+    writeln!(out, "}}").ok();
+    writeln!(out).ok();
+    writeln!(out, "complete -o default -F _rad rad").ok();
+
+    out
+}
+
+fn zsh(specs: &[Spec]) -> String {
+    let mut out = String::new();
+
+    writeln!(out, "#compdef rad").ok();
+    writeln!(out).ok();
+    writeln!(out, "_rad() {{").ok();
+    writeln!(out, "    local -a commands").ok();
+    writeln!(out, "    commands=(").ok();
+    for spec in specs {
+        writeln!(
+            out,
+            "        '{}:{}'",
+            spec.name,
+            spec.description.replace('\'', "'\\''").replace(':', "\\:")
+        )
+        .ok();
+    }
+    writeln!(out, "    )").ok();
+    writeln!(out).ok();
+    writeln!(out, "    if (( CURRENT == 2 )); then").ok();
+    writeln!(out, "        _describe 'command' commands").ok();
+    writeln!(out, "        compadd -- {}", GLOBAL_OPTIONS.join(" ")).ok();
+    writeln!(out, "        return").ok();
+    writeln!(out, "    fi").ok();
+    writeln!(out, "    case \"$words[2]\" in").ok();
+    for spec in specs {
+        writeln!(out, "        {}) compadd -- {} ;;", spec.name, words(spec)).ok();
+    }
+    writeln!(out, "    esac").ok();
+    writeln!(out, "}}").ok();
+    writeln!(out).ok();
+    writeln!(out, "compdef _rad rad").ok();
+
+    out
+}
+
+fn fish(specs: &[Spec]) -> String {
+    let mut out = String::new();
+
+    writeln!(out, "complete -c rad -f").ok();
+    for option in GLOBAL_OPTIONS {
+        writeln!(
+            out,
+            "complete -c rad -n '__fish_use_subcommand' -l {}",
+            option.trim_start_matches('-')
+        )
+        .ok();
+    }
+    for spec in specs {
+        writeln!(
+            out,
+            "complete -c rad -n '__fish_use_subcommand' -a {} -d '{}'",
+            spec.name,
+            spec.description.replace('\'', "\\'")
+        )
+        .ok();
+    }
+    for spec in specs {
+        let condition = format!("__fish_seen_subcommand_from {}", spec.name);
+
+        if !spec.subcommands.is_empty() {
+            writeln!(
+                out,
+                "complete -c rad -n '{}' -a '{}'",
+                condition,
+                spec.subcommands.join(" ")
+            )
+            .ok();
+        }
+        for option in &spec.options {
+            let flag = if let Some(long) = option.strip_prefix("--") {
+                format!("-l {}", long)
+            } else {
+                format!("-s {}", option.trim_start_matches('-'))
+            };
+            writeln!(out, "complete -c rad -n '{}' {}", condition, flag).ok();
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_spec_from_help() {
+        let help = Help {
+            name: "issue",
+            description: "Manage issues",
+            version: "0.0.0",
+            usage: r#"
+Usage
+
+    rad issue new [--title <title>] [--description <text>]
+    rad issue state <id> [--closed | --open]
+    rad issue delete <id>
+    rad issue [list] [--assigned <key>]
+
+Options
+
+    --help, -h    Print help
+"#,
+        };
+        let spec = Spec::from(&help);
+
+        assert_eq!(spec.name, "issue");
+        assert_eq!(spec.subcommands, vec!["new", "state", "delete", "list"]);
+        assert_eq!(
+            spec.options,
+            vec![
+                "--title",
+                "--description",
+                "--closed",
+                "--open",
+                "--assigned",
+                "--help",
+                "-h"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_is_option() {
+        assert!(is_option("--help"));
+        assert!(is_option("-v"));
+        assert!(!is_option("--"));
+        assert!(!is_option("-"));
+        assert!(!is_option("--Help"));
+        assert!(!is_option("-vv"));
+        assert!(!is_option("-1"));
+        assert!(!is_option("help"));
+    }
+
+    #[test]
+    fn test_bash_skips_global_options() {
+        let script = bash(&[Spec {
+            name: "sync",
+            ..Spec::default()
+        }]);
+
+        assert!(script.contains("--color|--signer)"));
+        assert!(script.contains("case \"$cmd\" in"));
+        assert!(!script.contains("COMP_WORDS[1]"));
+    }
+}
@@ -3,12 +3,16 @@ use std::ffi::OsString;
 use radicle_common::args::{Args, Error, Help};
 use radicle_terminal as term;
 
+pub mod completions;
+
 #[cfg(feature = "ethereum")]
 pub use rad_account;
 pub use rad_auth;
 pub use rad_checkout;
 pub use rad_clone;
 pub use rad_comment;
+pub use rad_config;
+pub use rad_doctor;
 pub use rad_edit;
 #[cfg(feature = "ethereum")]
 pub use rad_ens;
@@ -17,6 +21,7 @@ pub use rad_gov;
 pub use rad_init;
 pub use rad_inspect;
 pub use rad_issue;
+pub use rad_key;
 pub use rad_ls;
 pub use rad_merge;
 pub use rad_patch;
@@ -25,6 +30,8 @@ pub use rad_pull;
 pub use rad_push;
 pub use rad_remote;
 pub use rad_review;
+#[cfg(feature = "ethereum")]
+pub use rad_reward;
 pub use rad_rm;
 pub use rad_self;
 pub use rad_sync;
@@ -38,29 +45,64 @@ pub const HELP: Help = Help {
     usage: "Usage: rad help [--help]",
 };
 
-const COMMANDS: &[Help] = &[
-    rad_auth::HELP,
-    rad_init::HELP,
-    rad_self::HELP,
-    rad_inspect::HELP,
-    rad_clone::HELP,
-    rad_ls::HELP,
-    rad_remote::HELP,
-    rad_push::HELP,
-    rad_pull::HELP,
-    rad_checkout::HELP,
-    rad_track::HELP,
-    rad_untrack::HELP,
-    rad_sync::HELP,
-    #[cfg(feature = "ethereum")]
-    rad_ens::HELP,
+/// Commands grouped by section, in the order they are listed by `rad help`.
+static SECTIONS: &[(&str, &[Help])] = &[
+    (
+        "Project",
+        &[
+            rad_init::HELP,
+            rad_clone::HELP,
+            rad_checkout::HELP,
+            rad_inspect::HELP,
+            rad_ls::HELP,
+            rad_path::HELP,
+            rad_rm::HELP,
+            rad_push::HELP,
+            rad_pull::HELP,
+            rad_sync::HELP,
+            rad_remote::HELP,
+            rad_track::HELP,
+            rad_untrack::HELP,
+        ],
+    ),
+    (
+        "Collaboration",
+        &[
+            rad_issue::HELP,
+            rad_patch::HELP,
+            rad_review::HELP,
+            rad_merge::HELP,
+            rad_comment::HELP,
+        ],
+    ),
+    (
+        "Identity",
+        &[
+            rad_auth::HELP,
+            rad_self::HELP,
+            rad_key::HELP,
+            rad_edit::HELP,
+            rad_config::HELP,
+        ],
+    ),
     #[cfg(feature = "ethereum")]
-    rad_account::HELP,
-    rad_rm::HELP,
-    rad_edit::HELP,
-    crate::HELP,
+    (
+        "Ethereum",
+        &[
+            rad_account::HELP,
+            rad_ens::HELP,
+            rad_gov::HELP,
+            rad_reward::HELP,
+        ],
+    ),
+    ("Other", &[rad_doctor::HELP, completions::HELP, crate::HELP]),
 ];
 
+/// All commands known to `rad`.
+pub fn commands() -> impl Iterator<Item = &'static Help> {
+    SECTIONS.iter().flat_map(|(_, commands)| commands.iter())
+}
+
 #[derive(Default)]
 pub struct Options {}
 
@@ -83,7 +125,10 @@ impl Args for Options {
 }
 
 pub fn run(_options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
-    println!("Usage: rad <command> [--help]");
+    println!(
+        "Usage: rad [-v...] [--quiet] [--offline] [--color <auto|always|never>] \
+         [--signer <ssh-agent|hardware>] <command> [--help]"
+    );
 
     if ctx.profile().is_err() {
         println!();
@@ -101,14 +146,18 @@ pub fn run(_options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
     println!("Common `rad` commands used in various situations:");
     println!();
 
-    for help in COMMANDS {
-        println!(
-            "\t{} {}",
-            term::format::bold(format!("{:-12}", help.name)),
-            term::format::dim(help.description)
-        );
+    for (section, commands) in SECTIONS {
+        println!("{}", term::format::bold(section));
+
+        for help in commands.iter() {
+            println!(
+                "\t{} {}",
+                term::format::bold(format!("{:-12}", help.name)),
+                term::format::dim(help.description)
+            );
+        }
+        println!();
     }
-    println!();
     println!("See `rad <command> --help` to learn about a specific command.");
     println!();
 
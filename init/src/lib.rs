@@ -8,6 +8,7 @@ use anyhow::{anyhow, bail, Context as _};
 
 use librad::PeerId;
 
+use radicle_common::args;
 use radicle_common::args::{Args, Error, Help};
 use radicle_common::json;
 use radicle_common::Interactive;
@@ -28,6 +29,8 @@ Options
     --name               Name of the project
     --description        Description of the project
     --default-branch     The default branch of the project
+    --homepage <url>     Homepage of the project
+    --tag <name>         Topic tag of the project (may be specified multiple times)
     --set-upstream, -u   Setup the upstream of the default branch
     --no-confirm         Don't ask for confirmation during setup
     --help               Print help
@@ -40,6 +43,7 @@ pub struct Options {
     pub name: Option<String>,
     pub description: Option<String>,
     pub branch: Option<String>,
+    pub discovery: project::Discovery,
     pub interactive: Interactive,
     pub set_upstream: bool,
 }
@@ -54,6 +58,7 @@ impl Args for Options {
         let mut name = None;
         let mut description = None;
         let mut branch = None;
+        let mut discovery = project::Discovery::default();
         let mut interactive = Interactive::Yes;
         let mut set_upstream = false;
 
@@ -91,6 +96,27 @@ impl Args for Options {
 
                     branch = Some(value);
                 }
+                Long("homepage") if discovery.homepage.is_none() => {
+                    let value = parser.value()?;
+                    let value = args::parse_value("homepage", value)?;
+
+                    discovery.homepage = Some(value);
+                }
+                Long("tag") => {
+                    let value = parser
+                        .value()?
+                        .to_str()
+                        .ok_or(anyhow::anyhow!("invalid tag specified with `--tag`"))?
+                        .trim()
+                        .to_owned();
+
+                    if value.is_empty() {
+                        anyhow::bail!("tags specified with `--tag` cannot be empty");
+                    }
+                    if !discovery.tags.contains(&value) {
+                        discovery.tags.push(value);
+                    }
+                }
                 Long("set-upstream") | Short('u') => {
                     set_upstream = true;
                 }
@@ -113,6 +139,7 @@ impl Args for Options {
                 name,
                 description,
                 branch,
+                discovery,
                 interactive,
                 set_upstream,
             },
@@ -124,11 +151,9 @@ impl Args for Options {
 pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
     let profile = ctx.profile()?;
 
-    if git::check_version().is_err() {
-        term::warning(&format!(
-            "Your git version is unsupported, please upgrade to {} or later",
-            git::VERSION_REQUIRED,
-        ));
+    if let Err(err) = git::check_version() {
+        term::warning(&format!("Unsupported git environment: {}", err));
+        term::tip!("Run `rad doctor` to diagnose your environment.");
         term::blank();
     }
     init(options, &profile)
@@ -181,8 +206,22 @@ pub fn init(options: Options, profile: &profile::Profile) -> anyhow::Result<()>
         }
     });
 
+    // Make sure the default branch exists and has commits before creating the project,
+    // so that we don't fail half-way through.
+    if repo
+        .find_branch(&branch, git::BranchType::Local)
+        .ok()
+        .and_then(|b| b.get().peel_to_commit().ok())
+        .is_none()
+    {
+        bail!(
+            "the `{}` branch was either not found, or has no commits",
+            branch
+        );
+    }
+
+    let payload = project::payload(name, description, branch.clone(), options.discovery)?;
     let mut spinner = term::spinner("Initializing...");
-    let payload = project::payload(name, description, branch.clone());
 
     match project::create(payload, &storage).and_then(|proj| {
         project::init(&proj, &repo, &storage, profile.paths(), signer).map(|_| proj)
@@ -2,17 +2,19 @@
 use std::convert::TryFrom;
 use std::ffi::OsString;
 use std::path::{Path, PathBuf};
-use std::process::{Command, Stdio};
 use std::str::FromStr;
+use std::{fs, io};
 
-use radicle_common::args::{Args, Error, Help};
-use radicle_common::{git, profile};
+use radicle_common::args::{self, Args, Error, Help};
+use radicle_common::{git, person, profile, project};
 use radicle_terminal as term;
 
-use librad::git::identities::any;
-use librad::git::storage::ReadOnlyStorage;
+use librad::git::identities::{self, any};
+use librad::git::storage::{ReadOnly, ReadOnlyStorage};
+use librad::git::tracking;
 use librad::git::types::Reference;
 use librad::git::Urn;
+use librad::PeerId;
 
 use anyhow::anyhow;
 
@@ -35,11 +37,15 @@ Usage
 
 Options
 
-    --id        Return the ID without the URN scheme
-    --payload   Inspect the object's payload
-    --refs      Inspect the object's refs on the local device (requires `tree`)
-    --history   Show object's history
-    --help      Print help
+    --id          Return the ID without the URN scheme
+    --payload     Inspect the object's payload
+    --peer        Inspect the payload as seen by the given peer (with `--payload`)
+    --refs        Inspect the object's refs on the local device
+    --history     Show object's history, newest first
+    --limit <n>   Only show the first `n` history entries (with `--history`)
+    --reverse     Show history entries oldest first (with `--history`)
+    --delegates   Show the project's delegates
+    --help        Print help
 "#,
 };
 
@@ -50,7 +56,11 @@ pub struct Options {
     pub refs: bool,
     pub payload: bool,
     pub history: bool,
+    pub limit: Option<usize>,
+    pub reverse: bool,
+    pub delegates: bool,
     pub id: bool,
+    pub peer: Option<PeerId>,
 }
 
 impl Args for Options {
@@ -63,7 +73,11 @@ impl Args for Options {
         let mut refs = false;
         let mut payload = false;
         let mut history = false;
+        let mut limit: Option<usize> = None;
+        let mut reverse = false;
+        let mut delegates = false;
         let mut id = false;
+        let mut peer: Option<PeerId> = None;
 
         while let Some(arg) = parser.next()? {
             match arg {
@@ -79,9 +93,29 @@ impl Args for Options {
                 Long("history") => {
                     history = true;
                 }
+                Long("limit") => {
+                    let value = parser.value()?;
+                    let value = args::parse_value("limit", value)?;
+
+                    limit = Some(value);
+                }
+                Long("reverse") => {
+                    reverse = true;
+                }
+                Long("delegates") => {
+                    delegates = true;
+                }
                 Long("id") => {
                     id = true;
                 }
+                Long("peer") => {
+                    let value = parser.value()?;
+                    let value = value.to_string_lossy();
+                    let value = PeerId::from_str(&value)
+                        .map_err(|_| anyhow!("invalid Peer ID '{}'", value))?;
+
+                    peer = Some(value);
+                }
                 Value(val) if path.is_none() && urn.is_none() => {
                     let val = val.to_string_lossy();
 
@@ -105,8 +139,12 @@ impl Args for Options {
                 path,
                 payload,
                 history,
+                limit,
+                reverse,
+                delegates,
                 refs,
                 urn,
+                peer,
             },
             vec![],
         ))
@@ -140,20 +178,37 @@ pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
     let colorizer = colorizer();
 
     if options.refs {
+        let id = urn.encode_id();
         let path = profile.paths().git_dir().join("refs").join("namespaces");
 
-        Command::new("tree")
-            .current_dir(path)
-            .args([&urn.encode_id(), "--noreport", "--prune"])
-            .stdout(Stdio::inherit())
-            .stderr(Stdio::inherit())
-            .spawn()?
-            .wait()?;
+        term::print(&id);
+        for line in tree(&path.join(&id))? {
+            term::print(line);
+        }
     } else if options.payload {
+        let urn = if let Some(peer) = options.peer {
+            if peer != *storage.peer_id() {
+                let tracked = tracking::tracked(&storage, Some(&urn))?
+                    .filter_map(|t| t.ok())
+                    .any(|t| t.peer_id() == Some(peer));
+
+                if !tracked {
+                    anyhow::bail!("peer {} is not tracked for {}", peer, urn);
+                }
+            }
+            let reference = project::peer_rad_id(&storage, urn.clone(), &peer);
+
+            Urn::try_from(reference).map_err(|e| anyhow!(e))?
+        } else {
+            urn
+        };
         let payload = any::get(&storage, &urn)
             .map(|o| o.map(|p| p.payload()))
             .map_err(|_| anyhow::anyhow!("Couldn't load project or person."))?
-            .ok_or(anyhow::anyhow!("No project or person found for this URN"))?;
+            .ok_or(match options.peer {
+                Some(peer) => anyhow!("No identity document found for {} on peer {}", urn, peer),
+                None => anyhow!("No project or person found for this URN"),
+            })?;
 
         println!(
             "{}",
@@ -164,8 +219,12 @@ pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
         match storage.reference(&branch) {
             Ok(Some(reference)) => {
                 let mut tip = reference.peel_to_commit()?;
+                let mut entries = Vec::new();
 
-                for i in 0.. {
+                loop {
+                    if !options.reverse && Some(entries.len()) == options.limit {
+                        break;
+                    }
                     let tree = tip.tree()?;
                     let entry = tree
                         .get(0)
@@ -191,20 +250,14 @@ pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
                     .with_timezone(&timezone)
                     .to_rfc2822();
 
-                    print!(
-                        "{}",
-                        term::TextBox::new(format!(
-                            "{}\ncommit {}\nblob   {}\ndate   {}\n\n{}",
-                            term::format::yellow(format!("tree   {}", tree.id())),
-                            term::format::dim(tip.id()),
-                            term::format::dim(blob.id()),
-                            term::format::dim(time),
-                            colorizer
-                                .colorize_json_str(&serde_json::to_string_pretty(&content)?)?,
-                        ))
-                        .first(i == 0)
-                        .last(false)
-                    );
+                    entries.push(format!(
+                        "{}\ncommit {}\nblob   {}\ndate   {}\n\n{}",
+                        term::format::yellow(format!("tree   {}", tree.id())),
+                        term::format::dim(tip.id()),
+                        term::format::dim(blob.id()),
+                        term::format::dim(time),
+                        colorizer.colorize_json_str(&serde_json::to_string_pretty(&content)?)?,
+                    ));
 
                     match tip.parent(0) {
                         Ok(p) => tip = p,
@@ -212,12 +265,26 @@ pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
                     }
                 }
 
+                if options.reverse {
+                    entries.reverse();
+
+                    if let Some(limit) = options.limit {
+                        entries.truncate(limit);
+                    }
+                }
+
+                for (i, entry) in entries.into_iter().enumerate() {
+                    print!("{}", term::TextBox::new(entry).first(i == 0).last(false));
+                }
+
                 println!(" └─ {}", term::format::highlight(urn.to_string()));
                 println!();
             }
 
             _ => return Err(anyhow!("Couldn't find reference to {} in storage", urn)),
         }
+    } else if options.delegates {
+        delegates(&storage, &urn)?;
     } else if options.id {
         term::info!("{}", term::format::highlight(urn.encode_id()));
     } else {
@@ -226,3 +293,179 @@ pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Render the directory tree under `root`, in the style of `tree --noreport --prune`.
+/// Directories that don't contain any files are omitted.
+fn tree(root: &Path) -> io::Result<Vec<String>> {
+    let mut lines = Vec::new();
+    walk(&read(root)?, "", &mut lines);
+
+    Ok(lines)
+}
+
+/// An entry of a directory tree.
+#[derive(Debug)]
+enum Entry {
+    File(OsString),
+    Dir(OsString, Vec<Entry>),
+}
+
+impl Entry {
+    fn name(&self) -> &OsString {
+        match self {
+            Self::File(name) | Self::Dir(name, _) => name,
+        }
+    }
+}
+
+/// Read the entries under `dir`, recursively.
+fn read(dir: &Path) -> io::Result<Vec<Entry>> {
+    let mut entries = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+
+        if path.is_dir() {
+            entries.push(Entry::Dir(entry.file_name(), read(&path)?));
+        } else {
+            entries.push(Entry::File(entry.file_name()));
+        }
+    }
+    Ok(entries)
+}
+
+fn walk(entries: &[Entry], prefix: &str, lines: &mut Vec<String>) {
+    let mut entries = entries.iter().filter(|e| has_files(e)).collect::<Vec<_>>();
+    entries.sort_by(|a, b| a.name().cmp(b.name()));
+
+    let count = entries.len();
+    for (i, entry) in entries.into_iter().enumerate() {
+        let last = i + 1 == count;
+        let (branch, indent) = if last {
+            ("└── ", "    ")
+        } else {
+            ("├── ", "│   ")
+        };
+        lines.push(format!(
+            "{}{}{}",
+            prefix,
+            branch,
+            entry.name().to_string_lossy()
+        ));
+
+        if let Entry::Dir(_, children) = entry {
+            walk(children, &format!("{}{}", prefix, indent), lines);
+        }
+    }
+}
+
+fn has_files(entry: &Entry) -> bool {
+    match entry {
+        Entry::File(_) => true,
+        Entry::Dir(_, children) => children.iter().any(has_files),
+    }
+}
+
+/// Print the delegates of a verified project, along with their names where known.
+fn delegates(storage: &ReadOnly, urn: &Urn) -> anyhow::Result<()> {
+    let project = identities::project::verify(storage, urn)?
+        .ok_or_else(|| anyhow!("project {} not found", urn))?;
+    let project = project::Metadata::try_from(project.into_inner())?;
+    let mut rows = Vec::new();
+
+    for delegate in &project.delegates {
+        match delegate {
+            project::Delegate::Direct { id } => {
+                let name = project::person(storage, urn.clone(), id)
+                    .ok()
+                    .flatten()
+                    .map(|p| p.subject().name.to_string());
+                rows.push((*id, name));
+            }
+            project::Delegate::Indirect { urn, ids } => {
+                let name = person::get(storage, urn)
+                    .ok()
+                    .flatten()
+                    .map(|p| p.subject().name.to_string());
+                for id in ids {
+                    rows.push((*id, name.clone()));
+                }
+            }
+        }
+    }
+
+    if atty::is(atty::Stream::Stdout) {
+        let mut table = term::Table::default();
+        for (id, name) in rows {
+            table.push([
+                term::format::highlight(id),
+                term::format::tertiary(name.unwrap_or_default()),
+            ]);
+        }
+        table.render();
+    } else {
+        for (id, name) in rows {
+            match name {
+                Some(name) => term::print(format!("{} {}", id, name)),
+                None => term::print(id),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn file(name: &str) -> Entry {
+        Entry::File(name.into())
+    }
+
+    fn dir(name: &str, entries: Vec<Entry>) -> Entry {
+        Entry::Dir(name.into(), entries)
+    }
+
+    #[test]
+    fn test_walk() {
+        let entries = vec![
+            dir(
+                "refs",
+                vec![
+                    dir("tags", vec![]),
+                    dir(
+                        "heads",
+                        vec![file("master"), dir("empty", vec![dir("empty", vec![])])],
+                    ),
+                    dir("rad", vec![file("id"), file("self")]),
+                ],
+            ),
+            dir("empty", vec![]),
+            file("HEAD"),
+        ];
+        let mut lines = Vec::new();
+        walk(&entries, "", &mut lines);
+
+        assert_eq!(
+            lines,
+            vec![
+                "├── HEAD",
+                "└── refs",
+                "    ├── heads",
+                "    │   └── master",
+                "    └── rad",
+                "        ├── id",
+                "        └── self",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_walk_empty() {
+        let mut lines = Vec::new();
+        walk(&[dir("refs", vec![dir("heads", vec![])])], "", &mut lines);
+
+        assert!(lines.is_empty());
+    }
+}
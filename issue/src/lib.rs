@@ -1,5 +1,7 @@
 #![allow(clippy::or_fun_call)]
 use std::ffi::OsString;
+use std::fs;
+use std::path::PathBuf;
 use std::str::FromStr;
 
 use anyhow::{anyhow, Context};
@@ -16,21 +18,32 @@ pub const HELP: Help = Help {
     usage: r#"
 Usage
 
-    rad issue new [--title <title>] [--description <text>]
+    rad issue new [--title <title>] [--description <text>] [--label <name>]...
+                  [--template <path>]
     rad issue state <id> [--closed | --open | --solved]
     rad issue delete <id>
     rad issue react <id> [--emoji <char>]
+    rad issue label <id> [--add <name>]... [--remove <name>]...
     rad issue list
 
+    When creating an issue in the editor, the buffer is seeded from the
+    given template, or from `.rad/issue_template.md` if it exists. Templates
+    may start with a YAML front-matter block holding the title and labels.
+
 Options
 
     --help      Print help
 "#,
 };
 
-#[derive(serde::Deserialize, serde::Serialize, Debug)]
+/// Issue template used to seed the editor, relative to the repository root.
+pub const ISSUE_TEMPLATE: &str = ".rad/issue_template.md";
+
+#[derive(serde::Deserialize, serde::Serialize, Debug, Default)]
 pub struct Metadata {
+    #[serde(default)]
     title: String,
+    #[serde(default)]
     labels: Vec<cobs::Label>,
 }
 
@@ -39,6 +52,7 @@ pub enum OperationName {
     Create,
     State,
     React,
+    Label,
     Delete,
     List,
 }
@@ -54,6 +68,8 @@ pub enum Operation {
     Create {
         title: Option<String>,
         description: Option<String>,
+        labels: Vec<cobs::Label>,
+        template: Option<PathBuf>,
     },
     State {
         id: cobs::issue::IssueId,
@@ -66,6 +82,11 @@ pub enum Operation {
         id: cobs::issue::IssueId,
         reaction: cobs::Reaction,
     },
+    Label {
+        id: cobs::issue::IssueId,
+        add: Vec<cobs::Label>,
+        remove: Vec<cobs::Label>,
+    },
     List,
 }
 
@@ -86,6 +107,10 @@ impl Args for Options {
         let mut reaction: Option<cobs::Reaction> = None;
         let mut description: Option<String> = None;
         let mut state: Option<cobs::issue::State> = None;
+        let mut add: Vec<cobs::Label> = Vec::new();
+        let mut remove: Vec<cobs::Label> = Vec::new();
+        let mut labels: Vec<cobs::Label> = Vec::new();
+        let mut template: Option<PathBuf> = None;
 
         while let Some(arg) = parser.next()? {
             match arg {
@@ -116,6 +141,33 @@ impl Args for Options {
                         );
                     }
                 }
+                Long("add") if op == Some(OperationName::Label) => {
+                    let name = parser.value()?;
+                    let label = cobs::Label::from_str(&name.to_string_lossy())?;
+
+                    if !add.contains(&label) {
+                        add.push(label);
+                    }
+                }
+                Long("remove") if op == Some(OperationName::Label) => {
+                    let name = parser.value()?;
+                    let label = cobs::Label::from_str(&name.to_string_lossy())?;
+
+                    if !remove.contains(&label) {
+                        remove.push(label);
+                    }
+                }
+                Long("label") if op == Some(OperationName::Create) => {
+                    let name = parser.value()?;
+                    let label = cobs::Label::from_str(&name.to_string_lossy())?;
+
+                    if !labels.contains(&label) {
+                        labels.push(label);
+                    }
+                }
+                Long("template") if op == Some(OperationName::Create) => {
+                    template = Some(PathBuf::from(parser.value()?));
+                }
                 Long("description") if op == Some(OperationName::Create) => {
                     description = Some(parser.value()?.to_string_lossy().into());
                 }
@@ -125,6 +177,7 @@ impl Args for Options {
                     "d" | "delete" => op = Some(OperationName::Delete),
                     "l" | "list" => op = Some(OperationName::List),
                     "r" | "react" => op = Some(OperationName::React),
+                    "label" => op = Some(OperationName::Label),
 
                     unknown => anyhow::bail!("unknown operation '{}'", unknown),
                 },
@@ -145,7 +198,12 @@ impl Args for Options {
         }
 
         let op = match op.unwrap_or_default() {
-            OperationName::Create => Operation::Create { title, description },
+            OperationName::Create => Operation::Create {
+                title,
+                description,
+                labels,
+                template,
+            },
             OperationName::State => Operation::State {
                 id: id.ok_or_else(|| anyhow!("an issue id must be provided"))?,
                 state: state.ok_or_else(|| anyhow!("a state operation must be provided"))?,
@@ -154,6 +212,19 @@ impl Args for Options {
                 id: id.ok_or_else(|| anyhow!("an issue id must be provided"))?,
                 reaction: reaction.ok_or_else(|| anyhow!("a reaction emoji must be provided"))?,
             },
+            OperationName::Label => {
+                if add.is_empty() && remove.is_empty() {
+                    anyhow::bail!("at least one label to `--add` or `--remove` must be provided");
+                }
+                if let Some(label) = add.iter().find(|l| remove.contains(l)) {
+                    anyhow::bail!("label `{}` cannot be both added and removed", label.name());
+                }
+                Operation::Label {
+                    id: id.ok_or_else(|| anyhow!("an issue id must be provided"))?,
+                    add,
+                    remove,
+                }
+            }
             OperationName::Delete => Operation::Delete {
                 id: id.ok_or_else(|| anyhow!("an issue id to remove must be provided"))?,
             },
@@ -168,7 +239,7 @@ pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
     let profile = ctx.profile()?;
     let signer = term::signer(&profile)?;
     let storage = keys::storage(&profile, signer)?;
-    let (project, _) = project::cwd()?;
+    let (project, repo) = project::cwd().map_err(Error::from)?;
     let cobs = cobs::store(&profile, &storage)?;
     let issues = cobs.issues();
 
@@ -176,8 +247,10 @@ pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
         Operation::Create {
             title: Some(title),
             description: Some(description),
+            labels,
+            ..
         } => {
-            issues.create(&project, &title, &description, &[])?;
+            issues.create(&project, &title, &description, &labels)?;
         }
         Operation::State { id, state } => {
             issues.lifecycle(&project, &id, state)?;
@@ -188,48 +261,73 @@ pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
                 issues.react(&project, &id, comment_id, reaction)?;
             }
         }
-        Operation::Create { title, description } => {
-            let meta = Metadata {
-                title: title.unwrap_or("Enter a title".to_owned()),
-                labels: vec![],
+        Operation::Label { id, add, remove } => {
+            if !add.is_empty() {
+                issues.label(&project, &id, &add)?;
+            }
+            if !remove.is_empty() {
+                issues.unlabel(&project, &id, &remove)?;
+            }
+        }
+        Operation::Create {
+            title,
+            description,
+            labels,
+            template,
+        } => {
+            let template = match template {
+                Some(path) => Some(fs::read_to_string(&path).with_context(|| {
+                    format!("failed to read issue template {}", path.display())
+                })?),
+                None => match repo.workdir().map(|dir| dir.join(ISSUE_TEMPLATE)) {
+                    Some(path) if path.exists() => Some(fs::read_to_string(path)?),
+                    _ => None,
+                },
+            };
+            let (mut meta, body) = match template {
+                Some(template) if template.trim_start().starts_with("---") => parse(&template)?,
+                Some(template) => (Metadata::default(), template),
+                None => (Metadata::default(), String::new()),
+            };
+            if let Some(title) = title {
+                meta.title = title;
+            }
+            if meta.title.is_empty() {
+                meta.title = "Enter a title".to_owned();
+            }
+            for label in labels {
+                if !meta.labels.contains(&label) {
+                    meta.labels.push(label);
+                }
+            }
+            let body = match description {
+                Some(description) => description,
+                None if body.trim().is_empty() => "Enter a description...".to_owned(),
+                None => body,
             };
             let yaml = serde_yaml::to_string(&meta)?;
-            let doc = format!(
-                "{}---\n\n{}",
-                yaml,
-                description.unwrap_or("Enter a description...".to_owned())
-            );
+            let doc = format!("{}---\n\n{}", yaml, body.trim_start());
 
             if let Some(text) = term::Editor::new().edit(&doc)? {
-                let mut meta = String::new();
-                let mut frontmatter = false;
-                let mut lines = text.lines();
-
-                while let Some(line) = lines.by_ref().next() {
-                    if line.trim() == "---" {
-                        if frontmatter {
-                            break;
-                        } else {
-                            frontmatter = true;
-                            continue;
-                        }
-                    }
-                    if frontmatter {
-                        meta.push_str(line);
-                        meta.push('\n');
-                    }
-                }
-
-                let description: String = lines.collect::<Vec<&str>>().join("\n");
-                let meta: Metadata =
-                    serde_yaml::from_str(&meta).context("failed to parse yaml front-matter")?;
+                let (meta, description) = parse(&text)?;
 
                 issues.create(&project, &meta.title, description.trim(), &meta.labels)?;
             }
         }
         Operation::List => {
             for (id, issue) in issues.all(&project)? {
-                println!("{} {}", id, issue.title());
+                let mut labels = issue.labels().iter().map(|l| l.name()).collect::<Vec<_>>();
+                let mut line = vec![id.to_string(), issue.title().to_owned()];
+
+                if !labels.is_empty() {
+                    labels.sort_unstable();
+                    line.push(term::format::yellow(labels.join(", ")));
+                }
+                line.push(term::format::dim(radicle_common::fmt::relative(
+                    &issue.timestamp(),
+                )));
+
+                println!("{}", line.join(" "));
             }
         }
         Operation::Delete { id } => {
@@ -239,3 +337,31 @@ pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Parse an issue document into its YAML front-matter and description.
+fn parse(text: &str) -> anyhow::Result<(Metadata, String)> {
+    let mut meta = String::new();
+    let mut frontmatter = false;
+    let mut lines = text.lines();
+
+    while let Some(line) = lines.by_ref().next() {
+        if line.trim() == "---" {
+            if frontmatter {
+                break;
+            } else {
+                frontmatter = true;
+                continue;
+            }
+        }
+        if frontmatter {
+            meta.push_str(line);
+            meta.push('\n');
+        }
+    }
+
+    let description: String = lines.collect::<Vec<&str>>().join("\n");
+    let meta: Metadata =
+        serde_yaml::from_str(&meta).context("failed to parse yaml front-matter")?;
+
+    Ok((meta, description))
+}
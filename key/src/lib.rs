@@ -0,0 +1,122 @@
+use std::ffi::OsString;
+use std::str::FromStr;
+
+use anyhow::anyhow;
+use librad::PeerId;
+
+use radicle_common::args::{Args, Error, Help};
+use radicle_common::{keys, profile};
+use radicle_terminal as term;
+
+pub const HELP: Help = Help {
+    name: "key",
+    description: env!("CARGO_PKG_DESCRIPTION"),
+    version: env!("CARGO_PKG_VERSION"),
+    usage: r#"
+Usage
+
+    rad key [--public | --fingerprint | --authorized-keys] [--peer <peer-id>]
+
+    Prints the SSH key of your radicle peer, or of the given peer, without
+    any decoration, so that it can be piped to other commands.
+
+Examples
+
+    rad key --authorized-keys >> ~/.ssh/authorized_keys
+
+Options
+
+    --public             Print the SSH public key (default)
+    --fingerprint        Print the SSH key fingerprint
+    --authorized-keys    Print the key as an OpenSSH `authorized_keys` line
+    --peer <peer-id>     Print the key of the given peer instead of your own
+    --help               Print help
+"#,
+};
+
+/// Key output format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// SSH public key, as output by `ssh-add -L`.
+    Public,
+    /// SSH key fingerprint, as output by `ssh-add -l`.
+    Fingerprint,
+    /// OpenSSH `authorized_keys` line.
+    AuthorizedKeys,
+}
+
+impl Default for Format {
+    fn default() -> Self {
+        Self::Public
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Options {
+    pub format: Format,
+    pub peer: Option<PeerId>,
+}
+
+impl Args for Options {
+    fn from_args(args: Vec<OsString>) -> anyhow::Result<(Self, Vec<OsString>)> {
+        use lexopt::prelude::*;
+
+        let mut parser = lexopt::Parser::from_args(args);
+        let mut format = None;
+        let mut peer = None;
+
+        while let Some(arg) = parser.next()? {
+            match arg {
+                Long("public") if format.is_none() => {
+                    format = Some(Format::Public);
+                }
+                Long("fingerprint") if format.is_none() => {
+                    format = Some(Format::Fingerprint);
+                }
+                Long("authorized-keys") if format.is_none() => {
+                    format = Some(Format::AuthorizedKeys);
+                }
+                Long("peer") if peer.is_none() => {
+                    let value = parser.value()?;
+                    let value = value.to_string_lossy();
+                    let value = PeerId::from_str(&value)
+                        .map_err(|_| anyhow!("invalid peer id specified: '{}'", value))?;
+
+                    peer = Some(value);
+                }
+                Long("help") => {
+                    return Err(Error::Help.into());
+                }
+                _ => return Err(anyhow!(arg.unexpected())),
+            }
+        }
+
+        Ok((
+            Options {
+                format: format.unwrap_or_default(),
+                peer,
+            },
+            vec![],
+        ))
+    }
+}
+
+pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
+    let peer_id = match options.peer {
+        Some(peer) => peer,
+        None => {
+            let profile = ctx.profile()?;
+            let storage = profile::read_only(&profile)?;
+
+            *storage.peer_id()
+        }
+    };
+    let key = match options.format {
+        Format::Public => keys::to_ssh_key(&peer_id)?,
+        Format::Fingerprint => keys::to_ssh_fingerprint(&peer_id)?,
+        Format::AuthorizedKeys => keys::to_ssh_authorized_key(&peer_id)?,
+    };
+    term::print(key);
+
+    Ok(())
+}
@@ -1,4 +1,8 @@
 use std::ffi::OsString;
+use std::str::FromStr;
+
+use anyhow::anyhow;
+use serde::Serialize;
 
 use radicle_common::args::{Args, Error, Help};
 use radicle_common::{profile, project};
@@ -15,20 +19,65 @@ Usage
 
 Options
 
-    --help    Print help
+    --filter <text>       Only show projects whose name contains the given text
+    --sort <name|urn>     Sort projects by name or URN (default: unsorted)
+    --json                Output the full project list as JSON
+    --help                Print help
 "#,
 };
 
-pub struct Options {}
+/// How to sort the project list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sort {
+    Name,
+    Urn,
+}
+
+impl FromStr for Sort {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "name" => Ok(Self::Name),
+            "urn" => Ok(Self::Urn),
+            _ => Err(anyhow!(
+                "invalid sort key '{}', expected 'name' or 'urn'",
+                s
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Options {
+    pub filter: Option<String>,
+    pub sort: Option<Sort>,
+    pub json: bool,
+}
 
 impl Args for Options {
     fn from_args(args: Vec<OsString>) -> anyhow::Result<(Self, Vec<OsString>)> {
         use lexopt::prelude::*;
 
         let mut parser = lexopt::Parser::from_args(args);
+        let mut filter = None;
+        let mut sort = None;
+        let mut json = false;
 
-        if let Some(arg) = parser.next()? {
+        while let Some(arg) = parser.next()? {
             match arg {
+                Long("filter") => {
+                    filter = Some(parser.value()?.to_string_lossy().to_string());
+                }
+                Long("sort") => {
+                    let value = parser.value()?;
+                    let value = value.to_string_lossy();
+
+                    sort = Some(value.parse()?);
+                }
+                Long("json") => {
+                    json = true;
+                }
                 Long("help") => {
                     return Err(Error::Help.into());
                 }
@@ -36,14 +85,47 @@ impl Args for Options {
             }
         }
 
-        Ok((Options {}, vec![]))
+        Ok((Options { filter, sort, json }, vec![]))
     }
 }
 
-pub fn run(_options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
+/// A project entry, as output by `--json`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Entry<'a> {
+    #[serde(flatten)]
+    meta: &'a project::Metadata,
+    head: Option<String>,
+}
+
+pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
     let profile = ctx.profile()?;
     let storage = profile::read_only(&profile)?;
-    let projs = project::list(&storage)?;
+    let mut projs = project::list(&storage)?;
+
+    if let Some(filter) = &options.filter {
+        let filter = filter.to_lowercase();
+        projs.retain(|(_, meta, _)| meta.name.to_lowercase().contains(&filter));
+    }
+    match options.sort {
+        Some(Sort::Name) => projs.sort_by(|(_, a, _), (_, b, _)| a.name.cmp(&b.name)),
+        Some(Sort::Urn) => projs.sort_by_key(|(urn, _, _)| urn.to_string()),
+        None => {}
+    }
+
+    if options.json {
+        let entries = projs
+            .iter()
+            .map(|(_, meta, head)| Entry {
+                meta,
+                head: head.map(|h| h.to_string()),
+            })
+            .collect::<Vec<_>>();
+        term::print(serde_json::to_string_pretty(&entries)?);
+
+        return Ok(());
+    }
+
     let mut table = term::Table::default();
 
     for (urn, meta, head) in projs {
@@ -29,6 +29,8 @@ Options
 
     -i, --interactive         Ask for confirmations
     -r, --revision <number>   Revision number to merge, defaults to the latest
+        --cleanup             Delete the local patch branch after merging
+        --no-confirm          Don't ask before deleting the patch branch
         --help                Print help
 "#,
 };
@@ -48,6 +50,8 @@ pub struct Options {
     pub id: cobs::Identifier,
     pub interactive: bool,
     pub revision: Option<RevisionIx>,
+    pub cleanup: bool,
+    pub confirm: bool,
 }
 
 impl Args for Options {
@@ -58,6 +62,8 @@ impl Args for Options {
         let mut id: Option<cobs::Identifier> = None;
         let mut revision: Option<RevisionIx> = None;
         let mut interactive = false;
+        let mut cleanup = false;
+        let mut confirm = true;
 
         while let Some(arg) = parser.next()? {
             match arg {
@@ -67,6 +73,12 @@ impl Args for Options {
                 Long("interactive") | Short('i') => {
                     interactive = true;
                 }
+                Long("cleanup") => {
+                    cleanup = true;
+                }
+                Long("no-confirm") => {
+                    confirm = false;
+                }
                 Long("revision") | Short('r') => {
                     let value = parser.value()?;
                     let id =
@@ -94,6 +106,8 @@ impl Args for Options {
                 id: id.ok_or_else(|| anyhow!("a patch id to merge must be provided"))?,
                 interactive,
                 revision,
+                cleanup,
+                confirm,
             },
             vec![],
         ))
@@ -104,8 +118,7 @@ pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
     //
     // Setup
     //
-    let (urn, repo) = project::cwd()
-        .map_err(|_| anyhow!("this command must be run in the context of a project"))?;
+    let (urn, repo) = project::cwd().map_err(Error::from)?;
     let profile = ctx.profile()?;
     let signer = term::signer(&profile)?;
     let storage = keys::storage(&profile, signer)?;
@@ -247,6 +260,39 @@ pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
         term::format::secondary("`rad push`")
     );
 
+    if options.cleanup {
+        cleanup(&repo, &revision.oid, branch, options.confirm)?;
+    }
+
+    Ok(())
+}
+
+/// Delete the local branches pointing at the merged patch revision.
+///
+/// The branch we merged into is never deleted.
+fn cleanup(
+    repo: &git::Repository,
+    patch_oid: &git::Oid,
+    current: &str,
+    confirm: bool,
+) -> anyhow::Result<()> {
+    for branch in repo.branches(Some(git::BranchType::Local))? {
+        let (mut branch, _) = branch?;
+        let name = match branch.name()? {
+            Some(name) if name != current => name.to_owned(),
+            _ => continue,
+        };
+        if branch.get().target() != Some(*patch_oid) {
+            continue;
+        }
+        if confirm && !term::confirm(format!("Delete branch {}?", term::format::highlight(&name))) {
+            continue;
+        }
+        branch.delete()?;
+
+        term::success!("Deleted branch {}", term::format::highlight(&name));
+    }
+
     Ok(())
 }
 
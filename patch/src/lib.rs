@@ -17,7 +17,8 @@ use librad::profile::Profile;
 
 use radicle_common as common;
 use radicle_common::args::{Args, Error, Help};
-use radicle_common::cobs::patch::{MergeTarget, Patch, PatchId, PatchStore};
+use radicle_common::cobs::patch::{MergeTarget, Patch, PatchId, PatchStore, Revision};
+use radicle_common::cobs::Timestamp;
 use radicle_common::tokio;
 use radicle_common::{cobs, git, keys, patch, project, sync};
 use radicle_terminal as term;
@@ -31,6 +32,8 @@ pub const HELP: Help = Help {
 Usage
 
     rad patch [<option>...]
+    rad patch show <id>
+    rad patch --react <id> --emoji <char>
 
 Create options
 
@@ -39,10 +42,20 @@ Create options
         --[no-]push            Push patch head to storage (default: true)
     -m, --message [<string>]   Provide a comment message to the patch or revision (default: prompt)
         --no-message           Leave the patch or revision comment message blank
+        --head <ref>           Propose the given ref instead of `HEAD`
+        --base <ref>           Use the given ref as the patch base instead of the merge base
+        --title <string>       Change the patch title (used with `--update`)
+        --edit                 Edit the patch title and description (used with `--update`)
+    -y, --yes, --no-confirm    Don't ask for confirmation (default: false)
 
 Options
 
     -l, --list                 List all patches (default: false)
+        --mine                 Only list patches you proposed (used with `--list`)
+        --others               Only list patches others proposed (used with `--list`)
+        --react <id>           React to a patch, or to one of its comments
+        --emoji <char>         Emoji to react with (used with `--react`)
+    -v, --verbose              Verbose output, including the patch diff
         --help                 Print help
 "#,
 };
@@ -87,6 +100,16 @@ pub struct Options {
     pub push: bool,
     pub update: Update,
     pub message: Comment,
+    pub head: Option<String>,
+    pub base: Option<String>,
+    pub title: Option<String>,
+    pub edit: bool,
+    pub confirm: bool,
+    pub react: Option<cobs::Identifier>,
+    pub reaction: Option<cobs::Reaction>,
+    pub show: Option<cobs::Identifier>,
+    pub mine: bool,
+    pub others: bool,
 }
 
 impl Args for Options {
@@ -100,12 +123,28 @@ impl Args for Options {
         let mut message = Comment::default();
         let mut push = true;
         let mut update = Update::default();
+        let mut head = None;
+        let mut base = None;
+        let mut title = None;
+        let mut edit = false;
+        let mut confirm = true;
+        let mut react = None;
+        let mut reaction = None;
+        let mut show = None;
+        let mut mine = false;
+        let mut others = false;
 
         while let Some(arg) = parser.next()? {
             match arg {
                 Long("list") | Short('l') => {
                     list = true;
                 }
+                Long("mine") => {
+                    mine = true;
+                }
+                Long("others") => {
+                    others = true;
+                }
                 Long("verbose") | Short('v') => {
                     verbose = true;
                 }
@@ -141,6 +180,55 @@ impl Args for Options {
                 Long("no-push") => {
                     push = false;
                 }
+                Long("head") => {
+                    head = Some(parser.value()?.to_string_lossy().into());
+                }
+                Long("base") => {
+                    base = Some(parser.value()?.to_string_lossy().into());
+                }
+                Long("title") => {
+                    let value: String = parser.value()?.to_string_lossy().into();
+
+                    if value.trim().is_empty() {
+                        anyhow::bail!("the patch title can't be empty");
+                    }
+                    title = Some(value);
+                }
+                Long("edit") => {
+                    edit = true;
+                }
+                Long("yes") | Long("no-confirm") | Short('y') => {
+                    confirm = false;
+                }
+                Long("react") => {
+                    let val = parser.value()?;
+                    let val = val
+                        .to_str()
+                        .ok_or_else(|| anyhow!("patch id specified is not UTF-8"))?;
+                    let id = cobs::Identifier::from_str(val)
+                        .map_err(|_| anyhow!("invalid patch id '{}'", val))?;
+
+                    react = Some(id);
+                }
+                Long("emoji") => {
+                    let val = parser.value()?;
+                    let emoji = val.to_str().unwrap_or_default();
+
+                    reaction = Some(
+                        cobs::Reaction::from_str(emoji)
+                            .map_err(|_| anyhow!("invalid emoji '{}'", val.to_string_lossy()))?,
+                    );
+                }
+                Value(val) if val == "show" && show.is_none() => {
+                    let val = parser.value()?;
+                    let val = val
+                        .to_str()
+                        .ok_or_else(|| anyhow!("patch id specified is not UTF-8"))?;
+                    let id = cobs::Identifier::from_str(val)
+                        .map_err(|_| anyhow!("invalid patch id '{}'", val))?;
+
+                    show = Some(id);
+                }
                 Long("help") => {
                     return Err(Error::Help.into());
                 }
@@ -148,14 +236,31 @@ impl Args for Options {
             }
         }
 
+        if react.is_some() && reaction.is_none() {
+            anyhow::bail!("a reaction emoji must be provided with `--emoji`");
+        }
+        if (title.is_some() || edit) && matches!(update, Update::No) {
+            anyhow::bail!("`--title` and `--edit` can only be used with `--update`");
+        }
+
         Ok((
             Options {
                 list,
-                sync,
+                sync: sync && !sync::is_offline(),
                 message,
                 push,
                 update,
                 verbose,
+                head,
+                base,
+                title,
+                edit,
+                confirm,
+                react,
+                reaction,
+                show,
+                mine,
+                others,
             },
             vec![],
         ))
@@ -163,8 +268,7 @@ impl Args for Options {
 }
 
 pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
-    let (urn, repo) = project::cwd()
-        .map_err(|_| anyhow!("this command must be run in the context of a project"))?;
+    let (urn, repo) = project::cwd().map_err(Error::from)?;
 
     let profile = ctx.profile()?;
     let signer = term::signer(&profile)?;
@@ -174,6 +278,17 @@ pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
 
     if options.list {
         list(&storage, Some(repo), &profile, &project, options)?;
+    } else if let Some(id) = &options.show {
+        show(
+            &storage,
+            &profile,
+            &project,
+            Some(repo),
+            id,
+            options.verbose,
+        )?;
+    } else if let (Some(id), Some(reaction)) = (&options.react, options.reaction) {
+        react(&storage, &profile, &project, id, reaction, &options)?;
     } else {
         create(&storage, &profile, &project, &repo, options)?;
     }
@@ -195,6 +310,7 @@ fn list(
             project.urn.clone(),
             sync::seeds(profile)?,
             sync::Mode::Fetch,
+            sync::DEFAULT_TIMEOUT,
             profile,
             term::signer(profile)?,
             &rt,
@@ -218,30 +334,40 @@ fn list(
             other.push((id, patch));
         }
     }
-    term::blank();
-    term::print(&term::format::badge_positive("YOU PROPOSED"));
+    // Without a filter, list both.
+    let (mine, others) = match (options.mine, options.others) {
+        (false, false) => (true, true),
+        filter => filter,
+    };
 
-    if own.is_empty() {
+    if mine {
         term::blank();
-        term::print(&term::format::italic("Nothing to show."));
-    } else {
-        for (id, patch) in &mut own {
+        term::print(&term::format::badge_positive("YOU PROPOSED"));
+
+        if own.is_empty() {
             term::blank();
+            term::print(&term::format::italic("Nothing to show."));
+        } else {
+            for (id, patch) in &mut own {
+                term::blank();
 
-            print(&cobs.whoami, id, patch, project, &monorepo, &repo, storage)?;
+                print(&cobs.whoami, id, patch, project, &monorepo, &repo, storage)?;
+            }
         }
     }
-    term::blank();
-    term::print(&term::format::badge_secondary("OTHERS PROPOSED"));
-
-    if other.is_empty() {
+    if others {
         term::blank();
-        term::print(&term::format::italic("Nothing to show."));
-    } else {
-        for (id, patch) in &mut other {
+        term::print(&term::format::badge_secondary("OTHERS PROPOSED"));
+
+        if other.is_empty() {
             term::blank();
+            term::print(&term::format::italic("Nothing to show."));
+        } else {
+            for (id, patch) in &mut other {
+                term::blank();
 
-            print(&cobs.whoami, id, patch, project, &monorepo, &repo, storage)?;
+                print(&cobs.whoami, id, patch, project, &monorepo, &repo, storage)?;
+            }
         }
     }
     term::blank();
@@ -261,8 +387,27 @@ fn update(
     profile: &Profile,
 ) -> anyhow::Result<()> {
     let (current, current_revision) = patch.latest();
+    let metadata = if options.edit || options.title.is_some() {
+        Some(edit_metadata(&patch, &options)?).filter(|(t, d)| t.is_some() || d.is_some())
+    } else {
+        None
+    };
 
     if &*current_revision.oid == head {
+        if let Some((title, description)) = metadata {
+            patches.edit(
+                &project.urn,
+                &patch_id,
+                title.as_deref(),
+                description.as_deref(),
+            )?;
+
+            term::blank();
+            term::success!("Patch {} edited 🌱", term::format::highlight(patch_id));
+            term::blank();
+
+            return sync_patch(project, profile, &options);
+        }
         term::info!("Nothing to do, patch is already up to date.");
         return Ok(());
     }
@@ -275,23 +420,82 @@ fn update(
         term::format::dim(format!("R{}", current + 1)),
         term::format::secondary(common::fmt::oid(head)),
     );
-    let message = options.message.get(REVISION_MSG);
+    let message = options.message.clone().get(REVISION_MSG);
 
     // Difference between the two revisions.
     term::patch::print_commits_ahead_behind(repo, *head, *current_revision.oid)?;
     term::blank();
 
-    if !term::confirm("Continue?") {
+    if options.confirm && !term::confirm("Continue?") {
         anyhow::bail!("patch update aborted by user");
     }
 
     let new = patches.update(&project.urn, &patch_id, message, *base, *head)?;
     assert_eq!(new, current + 1);
 
+    // Nb. Metadata edits are separate from the revision and its comment.
+    if let Some((title, description)) = metadata {
+        patches.edit(
+            &project.urn,
+            &patch_id,
+            title.as_deref(),
+            description.as_deref(),
+        )?;
+    }
+
     term::blank();
     term::success!("Patch {} updated 🌱", term::format::highlight(patch_id));
     term::blank();
 
+    sync_patch(project, profile, &options)
+}
+
+/// Get the new title and description of a patch, from `--title` and `--edit`.
+/// Returns `None` for values that are unchanged. An edited description is always returned
+/// unless it matches the one explicitly stored on the patch.
+fn edit_metadata(
+    patch: &Patch,
+    options: &Options,
+) -> anyhow::Result<(Option<String>, Option<String>)> {
+    let (mut title, mut description) = (options.title.clone(), None);
+
+    if options.edit {
+        let current = format!(
+            "{}\n\n{}\n{}",
+            title.as_deref().unwrap_or(&patch.title),
+            patch.description(),
+            PATCH_MSG
+        );
+        let message = term::Editor::new()
+            .require_save(true)
+            .trim_newlines(true)
+            .extension(".markdown")
+            .edit(&current)?
+            .ok_or_else(|| anyhow!("patch edit aborted by user"))?;
+        let message = message.replace(PATCH_MSG.trim(), "");
+        let (t, d) = message.split_once("\n\n").unwrap_or((&message, ""));
+        let (t, d) = (t.trim(), d.trim());
+
+        if t.is_empty() {
+            anyhow::bail!("a title must be given");
+        }
+        title = Some(t.to_owned());
+        description = Some(d.to_owned());
+    }
+    let title = title.filter(|t| t.trim() != patch.title);
+    // Nb. Only compare against an explicitly set description, since the fallback is the
+    // latest revision comment, which changes when a new revision is added.
+    let description = description.filter(|d| patch.description.as_deref() != Some(d.as_str()));
+
+    Ok((title, description))
+}
+
+/// Push the patch to the project seeds, if syncing is enabled.
+fn sync_patch(
+    project: &project::Metadata,
+    profile: &Profile,
+    options: &Options,
+) -> anyhow::Result<()> {
     if options.sync {
         let rt = tokio::runtime::Runtime::new()?;
 
@@ -299,6 +503,7 @@ fn update(
             project.urn.clone(),
             sync::seeds(profile)?,
             sync::Mode::Push,
+            sync::DEFAULT_TIMEOUT,
             profile,
             term::signer(profile)?,
             &rt,
@@ -322,14 +527,30 @@ fn create(
     let cobs = cobs::store(profile, storage)?;
     let patches = cobs.patches();
 
-    // `HEAD`; This is what we are proposing as a patch.
-    let head = repo.head()?;
-    let head_oid = head.target().ok_or(anyhow!("invalid HEAD ref; aborting"))?;
+    // `HEAD`, or the ref given with `--head`; This is what we are proposing as a patch.
+    let (head_oid, head_branch) = if let Some(head) = &options.head {
+        let oid = repo
+            .revparse_single(head)
+            .and_then(|o| o.peel_to_commit())
+            .map_err(|_| anyhow!("head `{}` does not point to a commit", head))?
+            .id();
+        let branch = repo
+            .find_branch(head, git::BranchType::Local)
+            .ok()
+            .map(|_| head.clone());
+
+        (oid, branch.unwrap_or_else(|| common::fmt::oid(&oid)))
+    } else {
+        let head = repo.head()?;
+        let oid = head.target().ok_or(anyhow!("invalid HEAD ref; aborting"))?;
+        let branch = head
+            .shorthand()
+            .ok_or(anyhow!("cannot create patch from detached head; aborting"))?;
+
+        (oid, branch.to_owned())
+    };
     let head_commit = repo.find_commit(head_oid)?;
-    let head_branch = head
-        .shorthand()
-        .ok_or(anyhow!("cannot create patch from detached head; aborting"))?;
-    let head_branch = RefLike::try_from(head_branch)?;
+    let head_branch = RefLike::try_from(head_branch.as_str())?;
 
     // Make sure the `HEAD` commit can be found in the monorepo. Otherwise there
     // is no way for anyone to merge this patch.
@@ -350,7 +571,21 @@ fn create(
         }
         spinner.message("Pushing HEAD to storage...");
 
-        let output = git::git(Path::new("."), ["push", "rad"])?;
+        let output = match &options.head {
+            Some(head) if repo.find_branch(head, git::BranchType::Local).is_ok() => {
+                git::git(Path::new("."), ["push", "rad", head.as_str()])?
+            }
+            Some(head) => {
+                spinner.failed();
+                term::blank();
+
+                anyhow::bail!(
+                    "head `{}` was not found in storage and is not a local branch",
+                    head
+                );
+            }
+            None => git::git(Path::new("."), ["push", "rad"])?,
+        };
         if options.verbose {
             spinner.finish();
             term::blob(output);
@@ -384,7 +619,24 @@ fn create(
     // base.
 
     // The merge base is basically the commit at which the histories diverge.
-    let base_oid = repo.merge_base((*target_oid).into(), head_oid)?;
+    let base_oid = if let Some(base) = &options.base {
+        let oid = repo
+            .revparse_single(base)
+            .and_then(|o| o.peel_to_commit())
+            .map_err(|_| anyhow!("base `{}` does not point to a commit", base))?
+            .id();
+
+        if !repo.graph_descendant_of(head_oid, oid)? {
+            anyhow::bail!(
+                "head {} is not a descendant of base `{}`",
+                common::fmt::oid(&head_oid),
+                base
+            );
+        }
+        oid
+    } else {
+        repo.merge_base((*target_oid).into(), head_oid)?
+    };
     let commits = patch::patch_commits(repo, &base_oid, &head_oid)?;
 
     let patch = match &options.update {
@@ -432,7 +684,7 @@ fn create(
     };
 
     if let Some((id, patch)) = patch {
-        if term::confirm("Update?") {
+        if !options.confirm || term::confirm("Update?") {
             term::blank();
 
             return update(
@@ -466,8 +718,15 @@ fn create(
     term::blank();
     term::patch::list_commits(&commits)?;
     term::blank();
+    term::patch::print_diffstat(repo, base_oid, head_oid)?;
+    term::blank();
+
+    if options.verbose {
+        term::patch::print_diff(repo, base_oid, head_oid)?;
+        term::blank();
+    }
 
-    if !term::confirm("Continue?") {
+    if options.confirm && !term::confirm("Continue?") {
         anyhow::bail!("patch proposal aborted by user");
     }
 
@@ -502,7 +761,7 @@ fn create(
     )));
     term::blank();
 
-    if !term::confirm("Create patch?") {
+    if options.confirm && !term::confirm("Create patch?") {
         anyhow::bail!("patch proposal aborted by user");
     }
 
@@ -526,6 +785,7 @@ fn create(
             project.urn.clone(),
             sync::seeds(profile)?,
             sync::Mode::Push,
+            sync::DEFAULT_TIMEOUT,
             profile,
             term::signer(profile)?,
             &rt,
@@ -535,21 +795,51 @@ fn create(
     Ok(())
 }
 
-/// Create a human friendly message about git's sync status.
-fn pretty_sync_status(
-    repo: &git::Repository,
-    revision_oid: git::Oid,
-    head_oid: git::Oid,
-) -> anyhow::Result<String> {
-    let (a, b) = repo.graph_ahead_behind(revision_oid, head_oid)?;
-    if a == 0 && b == 0 {
-        return Ok(term::format::dim("up to date"));
-    }
+fn react(
+    storage: &Storage,
+    profile: &Profile,
+    project: &project::Metadata,
+    id: &cobs::Identifier,
+    reaction: cobs::Reaction,
+    options: &Options,
+) -> anyhow::Result<()> {
+    let cobs = cobs::store(profile, storage)?;
+    let patches = cobs.patches();
+    let (patch_id, patch) = patches
+        .resolve::<Patch>(&project.urn, id)?
+        .ok_or_else(|| anyhow!("couldn't find patch {} locally", id))?;
+    let (revision_ix, revision) = patch.latest();
+
+    let comment_id = if revision.discussion.is_empty() {
+        cobs::CommentId::root()
+    } else if let Some(comment_id) = term::patch_comment_select(revision) {
+        comment_id
+    } else {
+        anyhow::bail!("patch reaction aborted by user");
+    };
+    patches.react(&project.urn, &patch_id, revision_ix, comment_id, reaction)?;
 
-    let ahead = term::format::positive(a);
-    let behind = term::format::negative(b);
+    term::success!(
+        "Reacted to patch {} with {}",
+        term::format::highlight(common::fmt::cob(&patch_id)),
+        reaction.emoji
+    );
 
-    Ok(format!("ahead {}, behind {}", ahead, behind))
+    if options.sync {
+        let rt = tokio::runtime::Runtime::new()?;
+
+        term::sync::sync(
+            project.urn.clone(),
+            sync::seeds(profile)?,
+            sync::Mode::Push,
+            sync::DEFAULT_TIMEOUT,
+            profile,
+            term::signer(profile)?,
+            &rt,
+        )?;
+    }
+
+    Ok(())
 }
 
 /// Make a human friendly string for commit version information.
@@ -594,6 +884,22 @@ pub fn print(
     monorepo: &git::Repository,
     repo: &Option<git::Repository>,
     storage: &Storage,
+) -> anyhow::Result<()> {
+    print_summary(whoami, patch_id, patch, project, monorepo, repo, storage)?;
+    print_timeline(patch.revisions.last(), project, storage, "└─ ");
+
+    Ok(())
+}
+
+/// Print the title, author and reactions of a patch.
+fn print_summary(
+    whoami: &LocalIdentity,
+    patch_id: &PatchId,
+    patch: &mut Patch,
+    project: &project::Metadata,
+    monorepo: &git::Repository,
+    repo: &Option<git::Repository>,
+    storage: &Storage,
 ) -> anyhow::Result<()> {
     for r in patch.revisions.iter_mut() {
         for (_, r) in &mut r.reviews {
@@ -602,8 +908,7 @@ pub fn print(
     }
     patch.author.resolve(storage).ok();
 
-    let verified = project.verified(storage)?;
-    let target_head = common::patch::patch_merge_target_oid(patch.target, verified, storage)?;
+    let target_head = common::patch::patch_merge_target_oid(patch.target, project, storage)?;
 
     let you = patch.author.urn() == &whoami.urn();
     let prefix = "└─ ";
@@ -616,7 +921,7 @@ pub fn print(
     if you {
         author_info.push(term::format::secondary("(you)"));
     }
-    author_info.push(term::format::dim(patch.timestamp));
+    author_info.push(term::format::dim(common::fmt::relative(&patch.timestamp)));
 
     let revision = patch.revisions.last();
     term::info!(
@@ -625,11 +930,118 @@ pub fn print(
         term::format::highlight(common::fmt::cob(patch_id)),
         term::format::dim(format!("R{}", patch.version())),
         pretty_commit_version(&revision.oid, repo)?,
-        pretty_sync_status(monorepo, *revision.oid, target_head)?,
+        term::patch::pretty_sync_status(monorepo, *revision.oid, target_head)?,
     );
     term::info!("{}", author_info.join(" "));
 
+    let mut reactions = patch.reactions().into_iter().collect::<Vec<_>>();
+    if !reactions.is_empty() {
+        reactions.sort_by(|(a, _), (b, _)| a.emoji.cmp(&b.emoji));
+
+        term::info!(
+            "{}{}",
+            " ".repeat(term::text_width(prefix)),
+            reactions
+                .iter()
+                .map(|(r, n)| format!("{} {}", r.emoji, term::format::dim(n)))
+                .collect::<Vec<_>>()
+                .join("  ")
+        );
+    }
+
+    Ok(())
+}
+
+/// Print the merge and review timeline of a revision, most recent first.
+fn print_timeline(
+    revision: &Revision,
+    project: &project::Metadata,
+    storage: &Storage,
+    prefix: &str,
+) {
+    let mut timeline = timeline(revision, project, storage, prefix);
+    timeline.sort_by_key(|(t, _)| *t);
+
+    for (time, event) in timeline.iter().rev() {
+        term::info!(
+            "{} {}",
+            event,
+            term::format::dim(common::fmt::relative(time))
+        );
+    }
+}
+
+/// Show a single patch in detail, with all its revisions.
+fn show(
+    storage: &Storage,
+    profile: &Profile,
+    project: &project::Metadata,
+    repo: Option<git::Repository>,
+    id: &cobs::Identifier,
+    verbose: bool,
+) -> anyhow::Result<()> {
+    let cobs = cobs::store(profile, storage)?;
+    let patches = cobs.patches();
+    let monorepo = git::Repository::open_bare(profile.paths().git_dir())?;
+    let (patch_id, mut patch) = patches
+        .resolve::<Patch>(&project.urn, id)?
+        .ok_or_else(|| anyhow!("couldn't find patch {} locally", id))?;
+
+    term::blank();
+    // Nb. Revision timelines are printed below, with each revision.
+    print_summary(
+        &cobs.whoami,
+        &patch_id,
+        &mut patch,
+        project,
+        &monorepo,
+        &repo,
+        storage,
+    )?;
+    term::blank();
+
+    let description = patch.description().trim();
+    if description.is_empty() {
+        term::print(term::format::italic("No description provided."));
+    } else {
+        term::markdown(description);
+    }
+    term::blank();
+
+    let target_head = common::patch::patch_merge_target_oid(patch.target, project, storage)?;
+    let prefix = "└─ ";
+
+    for (ix, revision) in patch.revisions.iter().enumerate().rev() {
+        term::info!(
+            "{} {} {} {}",
+            term::format::dim(format!("R{}", ix)),
+            pretty_commit_version(&revision.oid, &repo)?,
+            term::patch::pretty_sync_status(&monorepo, *revision.oid, target_head)?,
+            term::format::dim(common::fmt::relative(&revision.timestamp)),
+        );
+        print_timeline(revision, project, storage, prefix);
+    }
+    term::blank();
+
+    if verbose {
+        let (_, revision) = patch.latest();
+
+        term::patch::print_diff(&monorepo, *revision.base, *revision.oid)?;
+        term::blank();
+    }
+
+    Ok(())
+}
+
+/// Build the merge and review timeline of a revision, as timestamped lines.
+fn timeline(
+    revision: &Revision,
+    project: &project::Metadata,
+    storage: &Storage,
+    prefix: &str,
+) -> Vec<(Timestamp, String)> {
     let mut timeline = Vec::new();
+
     for merge in &revision.merges {
         let peer = project::PeerInfo::get(&merge.peer, project, storage);
         let mut badges = Vec::new();
@@ -679,13 +1091,7 @@ pub fn print(
             ),
         ));
     }
-    timeline.sort_by_key(|(t, _)| *t);
-
-    for (time, event) in timeline.iter().rev() {
-        term::info!("{} {}", event, term::format::dim(time));
-    }
-
-    Ok(())
+    timeline
 }
 
 /// Find patches with a merge base equal to the one provided.
@@ -10,19 +10,65 @@ use serde::{Deserialize, Serialize};
 use std::{
     borrow::Borrow,
     convert::TryFrom,
-    fmt::{Debug, Display},
+    fmt::{self, Debug, Display},
     fs,
     io::Write,
     path::PathBuf,
     process::{Command, Stdio},
-    str,
+    str::{self, FromStr},
 };
 
+use librad::{PeerId, Signer as _};
+use radicle_common::{keys, profile, signer::ToSigner};
+
+/// Namespace of SSH signatures, preventing their reuse in other contexts.
+pub const SSH_NAMESPACE: &str = "radicle-proof";
+
+/// Method used to prove ownership of a key alongside the ETH key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Method {
+    /// A GPG key, signing with the GPG CLI.
+    Gpg,
+    /// The radicle SSH key, signing with `ssh-keygen` and ssh-agent.
+    Ssh,
+}
+
+impl Default for Method {
+    /// Proofs created before other methods were supported use GPG.
+    fn default() -> Self {
+        Self::Gpg
+    }
+}
+
+impl FromStr for Method {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "gpg" => Ok(Self::Gpg),
+            "ssh" => Ok(Self::Ssh),
+            _ => Err(anyhow!("unknown signing method '{}', expected 'gpg' or 'ssh'", s)),
+        }
+    }
+}
+
+impl Display for Method {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Gpg => write!(f, "GPG"),
+            Self::Ssh => write!(f, "SSH"),
+        }
+    }
+}
+
 /// The options allowed to be provided to the CLI
 #[derive(Debug, Clone)]
 pub struct Options {
-    /// GPG key to sign the proof.
-    pub gpg_key: String,
+    /// Method used to sign the proof, alongside the ETH key.
+    pub method: Method,
+    /// GPG key to sign the proof, when using the GPG method.
+    pub gpg_key: Option<String>,
     /// Output path of created proof
     pub output: PathBuf,
     /// RPC url
@@ -33,21 +79,95 @@ pub struct Options {
     pub keystore: Option<PathBuf>,
 }
 
-/// Proof that a GPG key belongs to the same person as an Ethereum key.
+/// Proof that a GPG or SSH key belongs to the same person as an Ethereum key.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Proof {
     /// Message to be signed by signee
     msg: String,
-    /// GPG signature of message
-    gpg_sig: String,
+    /// Method used to create the ownership signature
+    #[serde(default)]
+    method: Method,
+    /// Ownership signature of message, eg. a GPG clear-sign.
+    /// Proofs created before SSH was supported call this `gpg_sig`.
+    #[serde(alias = "gpg_sig")]
+    sig: String,
     /// ETH signature of message
     eth_sig: Signature,
-    /// GPG key fingerprint of the signee
-    gpg_key: String,
+    /// Key of the signee, eg. a GPG key fingerprint or an SSH public key.
+    /// Proofs created before SSH was supported call this `gpg_key`.
+    #[serde(alias = "gpg_key")]
+    key: String,
     /// ETH address of the signee
     eth_key: H160,
 }
 
+/// Signs messages with a key whose ownership is being proven.
+pub trait OwnershipSigner {
+    /// The method recorded in the proof.
+    fn method(&self) -> Method;
+    /// The key signing, eg. a GPG key fingerprint or an SSH public key.
+    fn key(&self) -> &str;
+    /// Sign a message, returning the signature in textual form.
+    fn sign(&self, message: &str) -> anyhow::Result<String>;
+}
+
+/// Signs with a GPG key, using the GPG CLI.
+#[derive(Debug, Clone)]
+pub struct Gpg {
+    key: String,
+}
+
+impl Gpg {
+    pub fn new(key: impl Into<String>) -> Self {
+        Self { key: key.into() }
+    }
+}
+
+impl OwnershipSigner for Gpg {
+    fn method(&self) -> Method {
+        Method::Gpg
+    }
+
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    fn sign(&self, message: &str) -> anyhow::Result<String> {
+        gpg_sign(&self.key, message)
+    }
+}
+
+/// Signs with the radicle key of the active profile, using `ssh-keygen` and ssh-agent.
+#[derive(Debug, Clone)]
+pub struct Ssh {
+    key: String,
+}
+
+impl Ssh {
+    /// Use the radicle key of the active profile, which must be in ssh-agent.
+    pub fn from_profile() -> anyhow::Result<Self> {
+        let profile = profile::default()?;
+        let signer = keys::ssh_auth_sock()?.to_signer(&profile)?;
+        let key = keys::to_ssh_key(&PeerId::from(signer.public_key()))?;
+
+        Ok(Self { key })
+    }
+}
+
+impl OwnershipSigner for Ssh {
+    fn method(&self) -> Method {
+        Method::Ssh
+    }
+
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    fn sign(&self, message: &str) -> anyhow::Result<String> {
+        ssh_sign(&self.key, message)
+    }
+}
+
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
     /// No wallet specified.
@@ -65,6 +185,18 @@ pub enum Error {
     /// GPG signature failed
     #[error("{0}")]
     GPGSigFailed(String),
+    /// ETH signature doesn't match the ETH address of the proof
+    #[error("eth signature was made by {recovered:?}, expected {expected:?}")]
+    ETHSigMismatch { expected: H160, recovered: H160 },
+    /// GPG signature verification failed
+    #[error("gpg signature verification failed: {0}")]
+    GPGVerifyFailed(String),
+    /// SSH signature failed
+    #[error("{0}")]
+    SSHSigFailed(String),
+    /// SSH signature verification failed
+    #[error("ssh signature verification failed: {0}")]
+    SSHVerifyFailed(String),
 }
 
 /// Sign a message with a GPG private key using the GPG CLI
@@ -91,6 +223,149 @@ fn gpg_sign(key: &str, message: &str) -> anyhow::Result<String> {
     }
 }
 
+/// Verify a GPG clear-signed message using the GPG CLI, checking that it was signed by
+/// `key` and that it contains `message`.
+fn gpg_verify(key: &str, message: &str, signature: &str) -> anyhow::Result<()> {
+    let mut gpg = Command::new("gpg")
+        .arg("--status-fd")
+        .arg("1")
+        .arg("--verify")
+        .stdin(Stdio::piped())
+        .stderr(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()?;
+
+    gpg.stdin.as_mut().unwrap().write_all(signature.as_bytes())?;
+
+    let output = gpg.wait_with_output()?;
+    if !output.status.success() {
+        return Err(anyhow!(Error::GPGVerifyFailed(
+            String::from_utf8_lossy(output.stderr.borrow()).to_string()
+        )));
+    }
+    let status = String::from_utf8_lossy(&output.stdout);
+    let key = key.to_uppercase();
+
+    if !signed_by(&status, &key) {
+        return Err(anyhow!(Error::GPGVerifyFailed(format!(
+            "signature was not made by key {}",
+            key
+        ))));
+    }
+    if !signature.contains(message) {
+        return Err(anyhow!(Error::GPGVerifyFailed(
+            "signed message doesn't match proof message".to_owned()
+        )));
+    }
+    Ok(())
+}
+
+/// Check whether the GPG `--status-fd` output reports a valid signature by `key`.
+///
+/// The status output contains a line of the form
+/// `[GNUPG:] VALIDSIG <fingerprint> <date> ... <primary-key-fingerprint>`. The first
+/// fingerprint is that of the key which made the signature, which is usually a signing
+/// subkey, so the primary key fingerprint is matched as well.
+fn signed_by(status: &str, key: &str) -> bool {
+    status
+        .lines()
+        .filter_map(|l| l.strip_prefix("[GNUPG:] VALIDSIG "))
+        .any(|l| {
+            let fields = l.split_whitespace().collect::<Vec<_>>();
+            let signing = fields.first();
+            let primary = if fields.len() >= 10 {
+                fields.last()
+            } else {
+                None
+            };
+
+            signing
+                .into_iter()
+                .chain(primary)
+                .any(|fingerprint| fingerprint.to_uppercase().ends_with(key))
+        })
+}
+
+/// Sign a message with an SSH key held by ssh-agent, using `ssh-keygen`.
+fn ssh_sign(key: &str, message: &str) -> anyhow::Result<String> {
+    let dir = tempfile::tempdir()?;
+    let key_file = dir.path().join("key.pub");
+    fs::write(&key_file, key)?;
+
+    let output = {
+        let mut ssh = Command::new("ssh-keygen")
+            .args(["-Y", "sign", "-n", SSH_NAMESPACE, "-f"])
+            .arg(&key_file)
+            .stdin(Stdio::piped())
+            .stderr(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()?;
+
+        ssh.stdin.as_mut().unwrap().write_all(message.as_bytes())?;
+        ssh.wait_with_output()?
+    };
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    } else {
+        Err(anyhow!(Error::SSHSigFailed(
+            String::from_utf8_lossy(output.stderr.borrow()).to_string()
+        )))
+    }
+}
+
+/// Verify an SSH signature of a message by the given SSH public key, using `ssh-keygen`.
+fn ssh_verify(key: &str, message: &str, signature: &str) -> anyhow::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let signers_file = dir.path().join("allowed_signers");
+    let sig_file = dir.path().join("sig");
+    fs::write(&signers_file, format!("proof {}", key))?;
+    fs::write(&sig_file, signature)?;
+
+    let output = {
+        let mut ssh = Command::new("ssh-keygen")
+            .args(["-Y", "verify", "-I", "proof", "-n", SSH_NAMESPACE, "-f"])
+            .arg(&signers_file)
+            .arg("-s")
+            .arg(&sig_file)
+            .stdin(Stdio::piped())
+            .stderr(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()?;
+
+        ssh.stdin.as_mut().unwrap().write_all(message.as_bytes())?;
+        ssh.wait_with_output()?
+    };
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(anyhow!(Error::SSHVerifyFailed(
+            String::from_utf8_lossy(output.stderr.borrow()).to_string()
+        )))
+    }
+}
+
+/// Verify both signatures of a proof:
+/// - The ETH signature over the message must recover to the proof's ETH address.
+/// - The ownership signature must be a valid signature of the message by the proof's key.
+pub fn verify(proof: &Proof) -> anyhow::Result<()> {
+    let recovered = proof.eth_sig.recover(proof.msg.as_str())?;
+    if recovered != proof.eth_key {
+        return Err(anyhow!(Error::ETHSigMismatch {
+            expected: proof.eth_key,
+            recovered,
+        }));
+    }
+    log::debug!("ETH signature verified for {:?}.", recovered);
+
+    match proof.method {
+        Method::Gpg => gpg_verify(&proof.key, &proof.msg, &proof.sig)?,
+        Method::Ssh => ssh_verify(&proof.key, &proof.msg, &proof.sig)?,
+    }
+    log::debug!("{} signature verified for {}.", proof.method, proof.key);
+
+    Ok(())
+}
+
 /// Sign a message with a ETH private key using either a keystore file or a Ledger HW
 pub async fn eth_sign<S: 'static + Signer>(
     signer: &S,
@@ -100,20 +375,30 @@ pub async fn eth_sign<S: 'static + Signer>(
 }
 
 /// Create the message to be signed
-fn create_message<T: Display, K: Debug>(ownership: &T, evidence: &K, block_hash: &H256) -> String {
+fn create_message<T: Display, K: Debug>(
+    method: Method,
+    ownership: &T,
+    evidence: &K,
+    block_hash: &H256,
+) -> String {
     format!(
-        "As the owner of GPG key {}, my Ethereum address is {:?} as of {:?}",
-        &ownership, &evidence, &block_hash
+        "As the owner of {} key {}, my Ethereum address is {:?} as of {:?}",
+        method, &ownership, &evidence, &block_hash
     )
 }
 
-/// Sign the message with GPG and ETH keypairs
+/// Sign the message with the ownership and ETH keypairs
 async fn create_proof<T: 'static + Signer>(
-    gpg_key: &str,
+    ownership: &dyn OwnershipSigner,
     signer: &T,
     block_hash: &H256,
 ) -> anyhow::Result<Proof> {
-    let msg = create_message(&gpg_key, &signer.address(), block_hash);
+    let msg = create_message(
+        ownership.method(),
+        &ownership.key(),
+        &signer.address(),
+        block_hash,
+    );
 
     log::info!("Signing message with ETH keypair..");
     let eth_sig = eth_sign(signer, &msg)
@@ -121,15 +406,16 @@ async fn create_proof<T: 'static + Signer>(
         .map_err(|_| anyhow!(Error::ETHSigFailed))?;
     log::debug!("ETH Signature: {:?}.", eth_sig);
 
-    log::info!("Signing message with GPG keypair..");
-    let gpg_sig = gpg_sign(gpg_key, &msg)?;
-    log::debug!("GPG Signature: {:?}.", gpg_sig);
+    log::info!("Signing message with {} keypair..", ownership.method());
+    let sig = ownership.sign(&msg)?;
+    log::debug!("{} Signature: {:?}.", ownership.method(), sig);
 
     Ok(Proof {
         msg,
-        gpg_sig,
+        method: ownership.method(),
+        sig,
         eth_sig,
-        gpg_key: gpg_key.to_string(),
+        key: ownership.key().to_owned(),
         eth_key: signer.address(),
     })
 }
@@ -137,7 +423,9 @@ async fn create_proof<T: 'static + Signer>(
 /// The main lib function that runs the functionality of the program
 /// - Obtains a block hash from a block from 1 day ago.
 /// - Gets either a keystore file or in its absence a Ledger HW as signer to sign a message.
+/// - Gets the GPG or SSH key whose ownership is proven.
 /// - Creates a message that will be signed by the defined signer.
+/// - Verifies both signatures.
 /// - Write both proofs to a JSON file.
 pub async fn run(opts: Options) -> anyhow::Result<()> {
     let provider =
@@ -150,6 +438,13 @@ pub async fn run(opts: Options) -> anyhow::Result<()> {
         .await?
         .ok_or_else(|| anyhow!(Error::NoBlock))?;
     let block_hash = block.hash.ok_or_else(|| anyhow!(Error::NoBlockHash))?;
+    let ownership: Box<dyn OwnershipSigner> = match opts.method {
+        Method::Gpg => Box::new(Gpg::new(opts.gpg_key.clone().ok_or_else(|| {
+            anyhow!("a gpg fingerprint must be specified with '--gpg-key'")
+        })?)),
+        Method::Ssh => Box::new(Ssh::from_profile()?),
+    };
+
     if let Some(keypath) = &opts.keystore {
         use colored::*;
 
@@ -160,7 +455,8 @@ pub async fn run(opts: Options) -> anyhow::Result<()> {
             .map_err(|_| anyhow!("keystore decryption failed"))?;
         log::debug!("Keystore decrypted: {:?}.", signer);
 
-        let proof = create_proof(&opts.gpg_key, &signer, &block_hash).await?;
+        let proof = create_proof(ownership.as_ref(), &signer, &block_hash).await?;
+        verify(&proof)?;
         fs::write(&opts.output, serde_json::to_string(&proof)?)?;
 
         Ok(())
@@ -171,7 +467,8 @@ pub async fn run(opts: Options) -> anyhow::Result<()> {
         let signer = Ledger::new(HDPath::Other(hdpath), 1).await?;
         log::info!("Successfully connected to Ledger..");
 
-        let proof = create_proof(&opts.gpg_key, &signer, &block_hash).await?;
+        let proof = create_proof(ownership.as_ref(), &signer, &block_hash).await?;
+        verify(&proof)?;
         fs::write(&opts.output, serde_json::to_string(&proof)?)?;
 
         Ok(())
@@ -179,3 +476,73 @@ pub async fn run(opts: Options) -> anyhow::Result<()> {
         Err(anyhow!(Error::NoWallet))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const STATUS: &str = "\
+[GNUPG:] NEWSIG
+[GNUPG:] KEY_CONSIDERED 8A0E4D0C2E6F2B1D4C6B3F3C1D2A6E5F4B3C2D1E 0
+[GNUPG:] SIG_ID kLmYc2ltTsLq4ZsVw5KQxQ1fBJk 2022-06-01 1654041600
+[GNUPG:] GOODSIG 5E4D3C2B1A0F9E8D Alice <alice@example.com>
+[GNUPG:] VALIDSIG 7B6A59483726150F4E3D2C1B5E4D3C2B1A0F9E8D 2022-06-01 1654041600 0 4 0 1 10 01 8A0E4D0C2E6F2B1D4C6B3F3C1D2A6E5F4B3C2D1E
+[GNUPG:] TRUST_ULTIMATE 0 pgp
+";
+
+    #[test]
+    fn test_signed_by() {
+        // Signing subkey, long and short form.
+        assert!(signed_by(
+            STATUS,
+            "7B6A59483726150F4E3D2C1B5E4D3C2B1A0F9E8D"
+        ));
+        assert!(signed_by(STATUS, "5E4D3C2B1A0F9E8D"));
+        // Primary key.
+        assert!(signed_by(
+            STATUS,
+            "8A0E4D0C2E6F2B1D4C6B3F3C1D2A6E5F4B3C2D1E"
+        ));
+        assert!(signed_by(STATUS, "1D2A6E5F4B3C2D1E"));
+        // Other keys.
+        assert!(!signed_by(STATUS, "0000000000000000"));
+        assert!(!signed_by(STATUS, "2022-06-01"));
+        assert!(!signed_by("[GNUPG:] BADSIG 5E4D3C2B1A0F9E8D Alice", "5E4D3C2B1A0F9E8D"));
+    }
+
+    #[test]
+    fn test_method_from_str() {
+        assert_eq!(Method::from_str("gpg").unwrap(), Method::Gpg);
+        assert_eq!(Method::from_str("ssh").unwrap(), Method::Ssh);
+        assert!(Method::from_str("GPG").is_err());
+        assert!(Method::from_str("x509").is_err());
+    }
+
+    #[test]
+    fn test_proof_deserialize_gpg() {
+        // A proof created before the signing method was recorded.
+        let json = r#"{
+            "msg": "I am the owner of the GPG key 5E4D3C2B1A0F9E8D",
+            "gpg_sig": "-----BEGIN PGP SIGNED MESSAGE-----",
+            "eth_sig": { "r": "0x1", "s": "0x2", "v": 27 },
+            "gpg_key": "5E4D3C2B1A0F9E8D",
+            "eth_key": "0xb535ced5f003e00cff2424892d4885b139019f1d"
+        }"#;
+        let proof: Proof = serde_json::from_str(json).unwrap();
+
+        assert_eq!(proof.method, Method::Gpg);
+        assert_eq!(proof.key, "5E4D3C2B1A0F9E8D");
+        assert_eq!(proof.sig, "-----BEGIN PGP SIGNED MESSAGE-----");
+
+        let value = serde_json::to_value(&proof).unwrap();
+        assert_eq!(value["method"], "gpg");
+        assert_eq!(value["key"], "5E4D3C2B1A0F9E8D");
+        assert_eq!(value["sig"], "-----BEGIN PGP SIGNED MESSAGE-----");
+        assert!(value.get("gpg_key").is_none());
+        assert!(value.get("gpg_sig").is_none());
+
+        let proof: Proof = serde_json::from_value(value).unwrap();
+        assert_eq!(proof.method, Method::Gpg);
+        assert_eq!(proof.key, "5E4D3C2B1A0F9E8D");
+    }
+}
@@ -17,6 +17,10 @@ enum Command {
         options: proof::Options,
         verbose: bool,
     },
+    Verify {
+        file: PathBuf,
+        verbose: bool,
+    },
     Help,
 }
 
@@ -24,15 +28,20 @@ fn parse_options() -> anyhow::Result<Command> {
     use lexopt::prelude::*;
 
     let mut parser = lexopt::Parser::from_env();
+    let mut method = proof::Method::default();
     let mut gpg_key: Option<String> = None;
     let mut output: Option<PathBuf> = None;
     let mut rpc_url: Option<String> = None;
     let mut ledger_hdpath: Option<DerivationPath> = None;
     let mut keystore: Option<PathBuf> = None;
+    let mut verify: Option<PathBuf> = None;
     let mut verbose = false;
 
     while let Some(arg) = parser.next()? {
         match arg {
+            Long("method") => {
+                method = parser.value()?.to_string_lossy().parse()?;
+            }
             Long("gpg-key") => {
                 gpg_key = Some(parser.value()?.parse()?);
             }
@@ -48,6 +57,9 @@ fn parse_options() -> anyhow::Result<Command> {
             Long("rpc-url") => {
                 rpc_url = Some(parser.value()?.parse()?);
             }
+            Long("verify") => {
+                verify = Some(parser.value()?.parse()?);
+            }
             Long("verbose") | Short('v') => {
                 verbose = true;
             }
@@ -60,10 +72,14 @@ fn parse_options() -> anyhow::Result<Command> {
         }
     }
 
+    if let Some(file) = verify {
+        return Ok(Command::Verify { file, verbose });
+    }
+
     Ok(Command::Run {
         options: proof::Options {
-            gpg_key: gpg_key
-                .ok_or_else(|| anyhow!("a gpg fingerprint must be specified with '--gpg-key'"))?,
+            method,
+            gpg_key,
             output: output
                 .ok_or_else(|| anyhow!("an output path must be specified with '--output'"))?,
             rpc_url: rpc_url
@@ -106,6 +122,18 @@ async fn execute() -> anyhow::Result<()> {
             }
             proof::run(options).await?;
         }
+        Command::Verify { file, verbose } => {
+            if verbose {
+                logger::set_level(log::Level::Debug);
+            } else {
+                logger::set_level(log::Level::Info);
+            }
+            let proof: proof::Proof = serde_json::from_str(&std::fs::read_to_string(&file)?)?;
+            proof::verify(&proof)?;
+
+            log::info!("Proof successfully verified");
+            return Ok(());
+        }
     }
     log::info!("Proof successfully created");
     Ok(())
@@ -42,7 +42,7 @@ impl Args for Options {
         let mut parser = lexopt::Parser::from_args(args);
         let mut seeds = Vec::new();
 
-        if let Some(arg) = parser.next()? {
+        while let Some(arg) = parser.next()? {
             match arg {
                 Long("seed") => {
                     let seed = seed::parse_value(&mut parser)?;
@@ -60,8 +60,7 @@ impl Args for Options {
 }
 
 pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
-    let (urn, repo) = project::cwd()
-        .map_err(|_| anyhow!("this command must be run in the context of a project"))?;
+    let (urn, repo) = project::cwd().map_err(Error::from)?;
 
     let _head = repo
         .head()
@@ -69,15 +68,19 @@ pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
         .and_then(|head| head.shorthand().map(|h| h.to_owned()))
         .ok_or(anyhow!("you must be on a branch to pull"))?;
 
-    rad_sync::run(
-        rad_sync::Options {
-            origin: Some(identity::Origin::from_urn(urn)),
-            seeds: options.seeds,
-            mode: sync::Mode::Fetch,
-            ..rad_sync::Options::default()
-        },
-        ctx,
-    )?;
+    if sync::is_offline() {
+        term::warning("Offline mode is enabled, skipping sync with seeds");
+    } else {
+        rad_sync::run(
+            rad_sync::Options {
+                origin: Some(identity::Origin::from_urn(urn)),
+                seeds: options.seeds,
+                mode: sync::Mode::Fetch,
+                ..rad_sync::Options::default()
+            },
+            ctx,
+        )?;
+    }
 
     term::blank();
     term::subcommand("git pull");
@@ -97,7 +97,7 @@ impl Args for Options {
                 force,
                 all,
                 set_upstream,
-                sync,
+                sync: sync && !sync::is_offline(),
                 verbose,
             },
             vec![],
@@ -143,6 +143,7 @@ pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
                 mode: Mode::Push,
                 origin: None,
                 sync_self: false,
+                ..rad_sync::Options::default()
             },
             ctx,
         )?;
@@ -109,7 +109,7 @@ pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
     let profile = ctx.profile()?;
     let signer = term::signer(&profile)?;
     let storage = keys::storage(&profile, signer.clone())?;
-    let (urn, repo) = project::cwd()?;
+    let (urn, repo) = project::cwd().map_err(Error::from)?;
 
     match options.op {
         Operation::Add { name, peer, fetch } => {
@@ -131,10 +131,22 @@ pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
             );
 
             if fetch {
-                let rt = tokio::runtime::Runtime::new()?;
-                let seeds = sync::seeds(&profile)?;
-
-                term::sync::sync(urn, seeds, sync::Mode::Fetch, &profile, signer.clone(), &rt)?;
+                if sync::is_offline() {
+                    term::warning("Offline mode is enabled, skipping sync with seeds");
+                } else {
+                    let rt = tokio::runtime::Runtime::new()?;
+                    let seeds = sync::seeds(&profile)?;
+
+                    term::sync::sync(
+                        urn,
+                        seeds,
+                        sync::Mode::Fetch,
+                        sync::DEFAULT_TIMEOUT,
+                        &profile,
+                        signer.clone(),
+                        &rt,
+                    )?;
+                }
                 git::fetch_remote(&mut remote, &repo, signer, &profile)?;
             }
             term::success!(
@@ -2,11 +2,12 @@ use std::ffi::OsString;
 use std::str::FromStr;
 
 use anyhow::anyhow;
+use librad::git::Storage;
 
 use common::cobs::patch::Verdict;
 use radicle_common as common;
 use radicle_common::args::{Args, Error, Help};
-use radicle_common::cobs::patch::Patch;
+use radicle_common::cobs::patch::{Patch, PatchStore};
 use radicle_common::tokio;
 use radicle_common::{cobs, keys, project, sync};
 use radicle_terminal as term;
@@ -21,14 +22,16 @@ pub const HELP: Help = Help {
     usage: r#"
 Usage
 
-    rad review [<id>] [--accept|--reject] [-m [<string>]] [<option>...]
+    rad review [<id>...] [--accept|--reject] [-m [<string>]] [<option>...]
 
     To specify a patch to review, use the fully qualified patch id
-    or an unambiguous prefix of it.
+    or an unambiguous prefix of it. When more than one patch is given,
+    the same verdict and comment is applied to each of them.
 
 Options
 
     -r, --revision <number>   Revision number to review, defaults to the latest
+                              (only valid when reviewing a single patch)
         --[no-]sync           Sync review to seed (default: sync)
     -m, --message [<string>]  Provide a comment with the review (default: prompt)
         --no-message          Don't provide a comment with the review
@@ -48,7 +51,7 @@ Markdown supported.
 
 #[derive(Debug)]
 pub struct Options {
-    pub id: cobs::Identifier,
+    pub ids: Vec<cobs::Identifier>,
     pub revision: Option<RevisionIx>,
     pub message: Comment,
     pub sync: bool,
@@ -61,7 +64,7 @@ impl Args for Options {
         use lexopt::prelude::*;
 
         let mut parser = lexopt::Parser::from_args(args);
-        let mut id: Option<cobs::Identifier> = None;
+        let mut ids: Vec<cobs::Identifier> = Vec::new();
         let mut revision: Option<RevisionIx> = None;
         let mut message = Comment::default();
         let mut sync = true;
@@ -108,7 +111,7 @@ impl Args for Options {
                         .to_str()
                         .ok_or_else(|| anyhow!("patch id specified is not UTF-8"))?;
 
-                    id = Some(
+                    ids.push(
                         cobs::Identifier::from_str(val)
                             .map_err(|_| anyhow!("invalid patch id '{}'", val))?,
                     );
@@ -117,11 +120,18 @@ impl Args for Options {
             }
         }
 
+        if ids.is_empty() {
+            anyhow::bail!("a patch id to review must be provided");
+        }
+        if revision.is_some() && ids.len() > 1 {
+            anyhow::bail!("`--revision` can only be used when reviewing a single patch");
+        }
+
         Ok((
             Options {
-                id: id.ok_or_else(|| anyhow!("a patch id to review must be provided"))?,
+                ids,
                 message,
-                sync,
+                sync: sync && !sync::is_offline(),
                 revision,
                 verbose,
                 verdict,
@@ -132,26 +142,83 @@ impl Args for Options {
 }
 
 pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
-    let (urn, _) = project::cwd()
-        .map_err(|_| anyhow!("this command must be run in the context of a project"))?;
+    let (urn, _) = project::cwd().map_err(Error::from)?;
     let profile = ctx.profile()?;
     let signer = term::signer(&profile)?;
     let storage = keys::storage(&profile, signer.clone())?;
     let cobs = cobs::store(&profile, &storage)?;
     let patches = cobs.patches();
+    let message = options.message.clone().get(REVIEW_HELP_MSG);
+
+    let mut results = Vec::new();
+    for id in &options.ids {
+        let result = review(id, &message, &urn, &patches, &storage, &options);
+        results.push((id, result));
+    }
+
+    if results.len() > 1 {
+        let mut table = term::Table::default();
+
+        term::blank();
+        for (id, result) in &results {
+            let status = match result {
+                Ok(()) => term::format::positive("ok"),
+                Err(err) => term::format::negative(err),
+            };
+            table.push([term::format::tertiary(id), status]);
+        }
+        table.render();
+        term::blank();
+    }
+
+    let reviewed = results.iter().filter(|(_, r)| r.is_ok()).count();
+    if options.sync && reviewed > 0 {
+        let rt = tokio::runtime::Runtime::new()?;
 
+        term::sync::sync(
+            urn,
+            sync::seeds(&profile)?,
+            sync::Mode::Push,
+            sync::DEFAULT_TIMEOUT,
+            &profile,
+            signer,
+            &rt,
+        )?;
+    }
+
+    // Nb. The first failure is kept as the source of the returned error, so that the
+    // exit code reflects its cause.
+    let total = results.len();
+    let failed = total - reviewed;
+    if let Some(err) = results.into_iter().find_map(|(_, r)| r.err()) {
+        if total == 1 {
+            return Err(err);
+        }
+        return Err(err.context(format!("{} of {} patch review(s) failed", failed, total)));
+    }
+
+    Ok(())
+}
+
+fn review(
+    id: &cobs::Identifier,
+    message: &str,
+    urn: &common::Urn,
+    patches: &PatchStore,
+    storage: &Storage,
+    options: &Options,
+) -> anyhow::Result<()> {
     let (patch_id, mut patch) = patches
-        .resolve::<Patch>(&urn, &options.id)?
-        .ok_or_else(|| anyhow!("couldn't find patch {} locally", options.id))?;
+        .resolve::<Patch>(urn, id)?
+        .ok_or_else(|| anyhow!("couldn't find patch {} locally", id))?;
     let patch_id_pretty = term::format::tertiary(common::fmt::cob(&patch_id));
     let revision_ix = options.revision.unwrap_or_else(|| patch.version());
     let _revision = patch
         .revisions
         .get(revision_ix)
         .ok_or_else(|| anyhow!("revision R{} does not exist", revision_ix))?;
-    let message = options.message.get(REVIEW_HELP_MSG);
 
-    patch.author.resolve(&storage).ok();
+    patch.author.resolve(storage).ok();
 
     let verdict_pretty = match options.verdict {
         Some(Verdict::Accept) => term::format::highlight("Accept"),
@@ -165,11 +232,11 @@ pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
         term::format::dim(format!("R{}", revision_ix)),
         term::format::tertiary(patch.author.name())
     )) {
-        anyhow::bail!("Patch review aborted");
+        anyhow::bail!("patch review aborted");
     }
 
     patches.review(
-        &urn,
+        urn,
         &patch_id,
         revision_ix,
         options.verdict,
@@ -197,18 +264,5 @@ pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
         }
     }
 
-    if options.sync {
-        let rt = tokio::runtime::Runtime::new()?;
-
-        term::sync::sync(
-            urn,
-            sync::seeds(&profile)?,
-            sync::Mode::Push,
-            &profile,
-            signer,
-            &rt,
-        )?;
-    }
-
     Ok(())
 }
@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 use std::ffi::OsString;
-use std::path::Path;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::Arc;
 
@@ -43,6 +45,9 @@ Options
     --amount                     Unlike interactive mode, this should be a U256 —
                                  multiple of token's smallest denomination
     --strategy                   Reward distribution strategy, can be weighted or equally
+    --min-amount                 Minimum reward of a recipient, as a U256. Recipients below it
+                                 are dropped and their share redistributed
+    --out <file>                 Write the reward distribution to the given CSV file
     --help                       Print help
 
 Wallet options
@@ -50,12 +55,14 @@ Wallet options
     --rpc-url <url>              JSON-RPC URL of Ethereum node (eg. http://localhost:8545)
     --ledger-hdpath <hdpath>     Account derivation path when using a Ledger hardware device
     --keystore <file>            Keystore file containing encrypted private key (default: none)
+    --chain <name|id>            Chain to use: mainnet, goerli, sepolia or a chain id (default: mainnet)
     --walletconnect              Use WalletConnect
 
 Environment variables
 
-    ETH_RPC_URL  Ethereum JSON-RPC URL (overwrite with '--rpc-url')
-    ETH_HDPATH   Hardware wallet derivation path (overwrite with '--ledger-hdpath')
+    ETH_RPC_URL          Ethereum JSON-RPC URL (overwrite with '--rpc-url')
+    ETH_RPC_URL_<CHAIN>  JSON-RPC URL for a specific chain, eg. 'ETH_RPC_URL_SEPOLIA' (takes precedence over 'ETH_RPC_URL')
+    ETH_HDPATH           Hardware wallet derivation path (overwrite with '--ledger-hdpath')
 "#,
 };
 
@@ -84,6 +91,8 @@ pub struct Options {
     pub amount: Option<U256>,
     pub token: Option<Token>,
     pub strategy: Option<Strategy>,
+    pub min_amount: Option<U256>,
+    pub out: Option<PathBuf>,
     pub provider: ethereum::ProviderOptions,
     pub signer: ethereum::SignerOptions,
 }
@@ -100,6 +109,8 @@ impl Args for Options {
         let mut amount = None;
         let mut token = None;
         let mut strategy = None;
+        let mut out = None;
+        let mut min_amount = None;
 
         while let Some(arg) = parser.next()? {
             match arg {
@@ -125,6 +136,19 @@ impl Args for Options {
                 Long("strategy") => {
                     strategy = parser.value()?.parse().ok();
                 }
+                Long("min-amount") => {
+                    let amt = parser
+                        .value()?
+                        .into_string()
+                        .map_err(|_| anyhow!("Can't parse --min-amount value"))?;
+                    min_amount = Some(
+                        U256::from_dec_str(&amt)
+                            .map_err(|_| anyhow!("Can't parse --min-amount value"))?,
+                    );
+                }
+                Long("out") => {
+                    out = Some(PathBuf::from(parser.value()?));
+                }
                 _ => return Err(anyhow!(arg.unexpected())),
             }
         }
@@ -136,6 +160,8 @@ impl Args for Options {
                 amount,
                 token,
                 strategy,
+                min_amount,
+                out,
                 provider,
                 signer,
             },
@@ -146,9 +172,11 @@ impl Args for Options {
 
 pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
     let rt = radicle_common::tokio::runtime::Runtime::new()?;
+    let chain = options.provider.chain;
     let provider = ethereum::provider(options.provider)?;
     let signer_opts = options.signer;
-    let (wallet, provider) = rt.block_on(term::ethereum::get_wallet(signer_opts, provider))?;
+    let (wallet, provider) =
+        rt.block_on(term::ethereum::get_wallet(signer_opts, provider, chain))?;
     let signer: Arc<_> = SignerMiddleware::new(provider, wallet).into();
     let profile = ctx.profile()?;
     let storage = profile::read_only(&profile)?;
@@ -311,7 +339,7 @@ pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
         })
         .ok_or_else(|| anyhow!("Couldn't determine distribution strategy"))?;
 
-    let rewards = calculate_rewards(strategy, amount, &all_ens)?;
+    let (rewards, dropped) = calculate_rewards(strategy, amount, &all_ens, options.min_amount)?;
 
     // show summary and aggregate payments
     term::blank();
@@ -324,6 +352,7 @@ pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
 
     let mut receivers = Vec::new();
     let mut amounts = Vec::new();
+    let mut csv = String::from("address,amount,ens\n");
 
     for (ens, reward) in rewards.iter() {
         let resolver: Result<PublicResolver<SignerMiddleware<_, _>>, _> =
@@ -331,6 +360,7 @@ pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
 
         // if resolver doesn't exist, we just skip this one
         if resolver.is_err() {
+            writeln!(csv, ",{},{}", u256_to_amount(*reward, decimals)?, ens)?;
             table.push([
                 term::format::italic(term::format::negative("Missing")),
                 term::format::tertiary(u256_to_amount(*reward, decimals)?),
@@ -347,6 +377,14 @@ pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
         receivers.push(address);
         amounts.push(*reward);
 
+        writeln!(
+            csv,
+            "{:?},{},{}",
+            address,
+            u256_to_amount(*reward, decimals)?,
+            ens
+        )?;
+
         table.push([
             term::format::highlight(address),
             term::format::tertiary(u256_to_amount(*reward, decimals)?),
@@ -354,9 +392,27 @@ pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
         ]);
     }
 
+    for ens in &dropped {
+        writeln!(csv, ",0,{}", ens)?;
+        table.push([
+            term::format::italic(term::format::dim("Dropped")),
+            term::format::tertiary(u256_to_amount(U256::zero(), decimals)?),
+            term::format::secondary(ens),
+        ]);
+    }
+
     table.render();
     term::blank();
 
+    if let Some(out) = &options.out {
+        fs::write(out, csv)?;
+        term::success!(
+            "Reward distribution written to {}",
+            term::format::highlight(out.display())
+        );
+        term::blank();
+    }
+
     // exit now if this was a dry run
     if options.dry {
         return Ok(());
@@ -411,11 +467,17 @@ pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Calculate the reward of each ENS name, given a distribution strategy.
+///
+/// Recipients whose reward would be below the minimum amount are dropped, and their
+/// share is redistributed among the remaining recipients. Returns the rewards and the
+/// dropped recipients.
 fn calculate_rewards(
     strategy: Strategy,
     reward: U256,
     all_ens: &[&Ens],
-) -> anyhow::Result<HashMap<String, U256>> {
+    min_amount: Option<U256>,
+) -> anyhow::Result<(HashMap<String, U256>, Vec<String>)> {
     let mut shares: HashMap<String, usize> = HashMap::new();
     for ens in all_ens {
         if let Some(v) = shares.get_mut(&ens.name) {
@@ -425,8 +487,40 @@ fn calculate_rewards(
         }
     }
 
+    let mut dropped = Vec::new();
+    loop {
+        let rewards = distribute(&strategy, reward, &shares)?;
+        let below = rewards
+            .iter()
+            .filter(|(_, r)| min_amount.map_or(false, |min| **r < min))
+            .map(|(ens, _)| ens.clone())
+            .collect::<Vec<_>>();
+
+        if below.is_empty() {
+            dropped.sort();
+
+            return Ok((rewards, dropped));
+        }
+        for ens in below {
+            shares.remove(&ens);
+            dropped.push(ens);
+        }
+        if shares.is_empty() {
+            return Err(anyhow!(
+                "No contributor would receive at least the minimum reward amount"
+            ));
+        }
+    }
+}
+
+/// Distribute a reward among ENS names, given their shares.
+fn distribute(
+    strategy: &Strategy,
+    reward: U256,
+    shares: &HashMap<String, usize>,
+) -> anyhow::Result<HashMap<String, U256>> {
     let mut rewards: HashMap<String, U256> = HashMap::new();
-    if strategy == Strategy::Equally {
+    if *strategy == Strategy::Equally {
         let count = U256::from(shares.len());
         let each_reward = reward
             .checked_div(count)
@@ -434,14 +528,14 @@ fn calculate_rewards(
         for ens in shares.keys() {
             rewards.insert(ens.to_string(), each_reward);
         }
-    } else if strategy == Strategy::Weighted {
+    } else if *strategy == Strategy::Weighted {
         let total_shares = shares.values().sum::<usize>();
         let reward_per_share = reward
             .checked_div(U256::from(total_shares))
             .ok_or_else(|| anyhow!("Failed to divide total reward by total number of shares"))?;
         for (ens, share) in shares {
             let this_reward = reward_per_share
-                .checked_mul(U256::from(share))
+                .checked_mul(U256::from(*share))
                 .ok_or_else(|| {
                     anyhow!("Failed to multiply reward by number of shares for {}", ens)
                 })?;
@@ -451,3 +545,98 @@ fn calculate_rewards(
 
     Ok(rewards)
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn ens(names: &[&str]) -> Vec<Ens> {
+        names
+            .iter()
+            .map(|name| Ens {
+                name: name.to_string(),
+            })
+            .collect()
+    }
+
+    fn shares_of(shares: &[(&str, usize)]) -> HashMap<String, usize> {
+        shares
+            .iter()
+            .map(|(name, share)| (name.to_string(), *share))
+            .collect()
+    }
+
+    #[test]
+    fn test_distribute_remainder() {
+        let shares = shares_of(&[("a.eth", 1), ("b.eth", 1), ("c.eth", 1)]);
+        let rewards = distribute(&Strategy::Equally, U256::from(10), &shares).unwrap();
+
+        // The remainder of the division is never handed out.
+        assert_eq!(rewards["a.eth"], U256::from(3));
+        assert_eq!(rewards["b.eth"], U256::from(3));
+        assert_eq!(rewards["c.eth"], U256::from(3));
+
+        let shares = shares_of(&[("a.eth", 2), ("b.eth", 1)]);
+        let rewards = distribute(&Strategy::Weighted, U256::from(10), &shares).unwrap();
+
+        assert_eq!(rewards["a.eth"], U256::from(6));
+        assert_eq!(rewards["b.eth"], U256::from(3));
+    }
+
+    #[test]
+    fn test_calculate_rewards_min_amount() {
+        let names = ens(&[
+            "a.eth", "a.eth", "a.eth", "a.eth", "a.eth", "a.eth", "a.eth", "a.eth", "b.eth",
+            "c.eth",
+        ]);
+        let all = names.iter().collect::<Vec<_>>();
+
+        // Without a minimum, everyone gets their share.
+        let (rewards, dropped) =
+            calculate_rewards(Strategy::Weighted, U256::from(100), &all, None).unwrap();
+        assert_eq!(rewards["a.eth"], U256::from(80));
+        assert_eq!(rewards["b.eth"], U256::from(10));
+        assert_eq!(rewards["c.eth"], U256::from(10));
+        assert!(dropped.is_empty());
+
+        // Shares below the minimum are dropped and redistributed.
+        let (rewards, dropped) = calculate_rewards(
+            Strategy::Weighted,
+            U256::from(100),
+            &all,
+            Some(U256::from(15)),
+        )
+        .unwrap();
+        assert_eq!(rewards.len(), 1);
+        assert_eq!(rewards["a.eth"], U256::from(100));
+        assert_eq!(dropped, vec!["b.eth", "c.eth"]);
+
+        // Nobody reaches the minimum.
+        assert!(calculate_rewards(
+            Strategy::Equally,
+            U256::from(10),
+            &all,
+            Some(U256::from(11))
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_calculate_rewards_redistribution_lifts_others() {
+        let names = ens(&["a.eth", "a.eth", "b.eth", "b.eth", "c.eth"]);
+        let all = names.iter().collect::<Vec<_>>();
+
+        // Per share 20: a and b get 40, c gets 20 and is dropped. The remaining 100
+        // is then split among a and b.
+        let (rewards, dropped) = calculate_rewards(
+            Strategy::Weighted,
+            U256::from(100),
+            &all,
+            Some(U256::from(25)),
+        )
+        .unwrap();
+        assert_eq!(rewards["a.eth"], U256::from(50));
+        assert_eq!(rewards["b.eth"], U256::from(50));
+        assert_eq!(dropped, vec!["c.eth"]);
+    }
+}
@@ -9,6 +9,7 @@ use librad::git::Urn;
 use librad::PeerId;
 
 use radicle_common::args::{Args, Error, Help};
+use radicle_common::cobs::{self, issue, patch};
 use radicle_common::{keys, profile, project};
 use radicle_terminal as term;
 
@@ -19,9 +20,11 @@ pub const HELP: Help = Help {
     usage: r#"
 Usage
 
-    rad rm <urn | peer-id> [<option>...]
+    rad rm <urn | peer-id | id> [<option>...]
 
     Removes a project if URN is given or user if Peer ID is given.
+    If an issue or patch id is given, the local copy of that object is
+    removed from the current project.
 
 Options
 
@@ -31,6 +34,8 @@ Options
                         neither read environment variable `RAD_PASSPHRASE`
                         nor standard input stream (default: false)
     --stdin             Read passphrase from stdin (default: false)
+    --force             Allow removing the active user. This always asks for
+                        confirmation and the user's passphrase (default: false)
     --help              Print help
 "#,
 };
@@ -38,6 +43,7 @@ Options
 enum Object {
     Project(Urn),
     User(PeerId),
+    Cob(cobs::Identifier),
     Unknown(String),
 }
 
@@ -47,6 +53,11 @@ impl From<&str> for Object {
             Object::Project(urn)
         } else if let Ok(peer_id) = PeerId::from_str(value) {
             Object::User(peer_id)
+        } else if !value.is_empty() && value.chars().all(|c| c.is_ascii_hexdigit()) {
+            match cobs::Identifier::from_str(value) {
+                Ok(id) => Object::Cob(id),
+                Err(_) => Object::Unknown(value.to_owned()),
+            }
         } else {
             Object::Unknown(value.to_owned())
         }
@@ -58,6 +69,7 @@ pub struct Options {
     confirm: bool,
     passphrase: bool,
     stdin: bool,
+    force: bool,
 }
 
 impl Args for Options {
@@ -69,6 +81,7 @@ impl Args for Options {
         let mut confirm = true;
         let mut passphrase = true;
         let mut stdin = false;
+        let mut force = false;
 
         while let Some(arg) = parser.next()? {
             match arg {
@@ -81,6 +94,9 @@ impl Args for Options {
                 Long("stdin") => {
                     stdin = true;
                 }
+                Long("force") => {
+                    force = true;
+                }
                 Long("help") => {
                     return Err(Error::Help.into());
                 }
@@ -96,11 +112,14 @@ impl Args for Options {
         Ok((
             Options {
                 object: object.ok_or_else(|| {
-                    anyhow!("Urn or peer id to remove must be provided; see `rad rm --help`")
+                    anyhow!(
+                        "Urn, peer id or object id to remove must be provided; see `rad rm --help`"
+                    )
                 })?,
                 confirm,
                 passphrase,
                 stdin,
+                force,
             },
             vec![],
         ))
@@ -170,12 +189,80 @@ pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
                 } else {
                     anyhow::bail!("No user found with Peer ID: {}", peer_id);
                 }
+            } else if options.force {
+                let username = storage.config()?.user_name()?;
+
+                term::warning("You are about to remove the active user and all of its data!");
+                if !term::confirm(format!(
+                    "Are you sure you would like to remove the active user {} ({})?",
+                    term::format::dim(peer_id),
+                    term::format::dim(username)
+                )) {
+                    return Ok(());
+                }
+                let passphrase = term::read_passphrase(options.stdin, false)?;
+                if keys::load_secret_key(&profile, passphrase).is_err() {
+                    anyhow::bail!(format!("Invalid passphrase supplied."));
+                }
+                profile::remove(&profile)?;
+                term::success!("Successfully removed active user {}", peer_id);
+
+                // Don't leave the active profile pointing to the removed profile.
+                let remaining = profile::list()?
+                    .into_iter()
+                    .filter(|p| p.id() != profile.id())
+                    .collect::<Vec<_>>();
+
+                if let Some(other) = remaining.first() {
+                    profile::set(other.id())?;
+                    term::warning(&format!(
+                        "Active profile switched to {} ({})",
+                        term::format::dim(other.id()),
+                        term::format::dim(profile::name(Some(other))?)
+                    ));
+                } else {
+                    term::warning("No profiles remain; run `rad auth --init` to create one.");
+                }
             } else {
-                anyhow::bail!("Cannot remove active user; see `rad rm --help`");
+                anyhow::bail!(
+                    "Cannot remove active user; use `--force` if you are sure, see `rad rm --help`"
+                );
+            }
+        }
+        Object::Cob(id) => {
+            let signer = term::signer(&profile)?;
+            let storage = keys::storage(&profile, signer)?;
+            let (project, _) = project::cwd().map_err(Error::from)?;
+            let cobs = cobs::store(&profile, &storage)?;
+
+            let (kind, id) = if let Some((id, _)) = cobs.resolve::<issue::Issue>(&project, id)? {
+                ("issue", id)
+            } else if let Some((id, _)) = cobs.resolve::<patch::Patch>(&project, id)? {
+                ("patch", id)
+            } else {
+                anyhow::bail!("Couldn't find issue or patch {}", id);
+            };
+
+            if options.confirm
+                && !term::confirm(format!(
+                    "Are you sure you would like to delete {} {}?",
+                    kind,
+                    term::format::dim(id)
+                ))
+            {
+                return Ok(());
+            }
+            match kind {
+                "issue" => cobs.issues().remove(&project, &id)?,
+                _ => cobs.patches().remove(&project, &id)?,
             }
+            term::success!("Successfully removed {} {}", kind, id);
         }
         Object::Unknown(arg) => {
-            anyhow::bail!(format!("Object must be an URN or a Peer ID: {}", arg));
+            anyhow::bail!(format!(
+                "Object must be an URN, a Peer ID or an object id: {}",
+                arg
+            ));
         }
     }
 
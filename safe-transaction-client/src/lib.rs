@@ -1,3 +1,12 @@
+//! Client for the Safe transaction service, used to propose and confirm multisig
+//! transactions.
+//!
+//! Two kinds of owner signatures are supported:
+//!
+//! * Externally-owned accounts, eg. keystores and hardware wallets, which sign the
+//!   transaction hash with `eth_sign`. See [`SafeTx::sign`] and [`sign_tx_hash`].
+//! * Owners that validate the transaction hash themselves, such as contracts
+//!   (EIP-1271), using pre-validated signatures. See [`SafeTx::sign_prevalidated`].
 use core::{result, str::FromStr};
 use std::io;
 
@@ -70,6 +79,22 @@ pub struct SafeTx {
     pub data: Bytes,
     pub operation: Operation,
     pub nonce: U256,
+    /// Free-form description of who proposed the transaction, shown in the Safe UI.
+    pub origin: Option<String>,
+}
+
+/// Summary of a multisig transaction known to the Safe transaction service.
+#[derive(Debug, Clone)]
+pub struct SafeTxSummary {
+    pub safe_tx_hash: TxHash,
+    pub to: Address,
+    pub value: U256,
+    pub data: Option<Bytes>,
+    pub nonce: U256,
+    /// Owners who have confirmed the transaction.
+    pub confirmations: Vec<Address>,
+    /// Number of confirmations needed before the transaction can be executed.
+    pub confirmations_required: u64,
 }
 
 #[derive(Debug)]
@@ -88,6 +113,15 @@ impl Client<'_> {
         }
     }
 
+    /// URL of the given safe's resource on the transaction service.
+    pub fn safe_url(&self, safe_address: Address) -> String {
+        format!(
+            "{}/v1/safes/{}/",
+            self.transactions_api,
+            to_checksum(&safe_address, None),
+        )
+    }
+
     pub fn get_safe(&self, safe_address: Address) -> Result<Safe, Error> {
         #[derive(serde::Deserialize)]
         struct SafeResponse {
@@ -102,11 +136,7 @@ impl Client<'_> {
             owners,
         } = self
             .agent
-            .get(&format!(
-                "{}/v1/safes/{}/",
-                self.transactions_api,
-                to_checksum(&safe_address, None),
-            ))
+            .get(&self.safe_url(safe_address))
             .call()?
             .into_json()?;
 
@@ -128,6 +158,11 @@ impl Client<'_> {
 }
 
 impl Safe<'_> {
+    /// Address of the Safe contract.
+    pub fn address(&self) -> Address {
+        self.safe_address
+    }
+
     pub fn create_transaction(
         &self,
         to: Address,
@@ -142,6 +177,7 @@ impl Safe<'_> {
             data,
             nonce: self.nonce,
             operation,
+            origin: None,
         }
     }
 
@@ -152,6 +188,7 @@ impl Safe<'_> {
             data,
             nonce,
             operation,
+            origin,
             ..
         } = signed_safe_tx.inner;
 
@@ -176,7 +213,7 @@ impl Safe<'_> {
                 "contractTransactionHash": signed_safe_tx.safe_tx_hash,
                 "sender": to_checksum(&signed_safe_tx.sender, None),
                 "signature": format!("0x{}", signed_safe_tx.signature),
-                "origin": "An unauthenticated origin string?",
+                "origin": origin,
             }))
             // 200 OK, is not okay. We expect 201 Created.
             .and_then(|r| match r.status() {
@@ -186,6 +223,60 @@ impl Safe<'_> {
             .map_err(From::from)
     }
 
+    /// List the transactions of this safe which haven't been executed yet, following
+    /// the pagination of the transaction service.
+    pub fn pending_transactions(&self) -> Result<Vec<SafeTxSummary>> {
+        #[derive(serde::Deserialize)]
+        struct Page {
+            next: Option<String>,
+            results: Vec<TxResponse>,
+        }
+
+        #[derive(serde::Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct TxResponse {
+            safe_tx_hash: TxHash,
+            to: Address,
+            value: String,
+            data: Option<Bytes>,
+            nonce: u64,
+            confirmations_required: Option<u64>,
+            #[serde(default)]
+            confirmations: Vec<ConfirmationResponse>,
+        }
+
+        #[derive(serde::Deserialize)]
+        struct ConfirmationResponse {
+            owner: Address,
+        }
+
+        let mut pending = Vec::new();
+        let mut url = Some(format!(
+            "{}/v1/safes/{}/multisig-transactions/?executed=false&nonce__gte={}",
+            self.client.transactions_api,
+            to_checksum(&self.safe_address, None),
+            self.nonce,
+        ));
+
+        while let Some(next) = url {
+            let page: Page = self.client.agent.get(&next).call()?.into_json()?;
+
+            for tx in page.results {
+                pending.push(SafeTxSummary {
+                    safe_tx_hash: tx.safe_tx_hash,
+                    to: tx.to,
+                    value: U256::from_dec_str(&tx.value).map_err(|_| Error::InvalidData)?,
+                    data: tx.data,
+                    nonce: U256::from(tx.nonce),
+                    confirmations: tx.confirmations.into_iter().map(|c| c.owner).collect(),
+                    confirmations_required: tx.confirmations_required.unwrap_or(self.threshold),
+                });
+            }
+            url = page.next;
+        }
+        Ok(pending)
+    }
+
     pub fn confirm(&self, signed_safe_tx: SignedSafeTxHash) -> Result {
         self.client
             .agent
@@ -205,12 +296,22 @@ impl Safe<'_> {
     }
 }
 
+impl SignedSafeTx {
+    /// The Safe transaction hash, used to identify the transaction on the service.
+    pub fn safe_tx_hash(&self) -> TxHash {
+        self.safe_tx_hash
+    }
+}
+
 impl SafeTx {
-    // We consume self. If signing fails, we have bigger issues than recreating the transaction.
-    pub async fn sign<S>(self, signer: &S) -> Result<SignedSafeTx, S::Error>
-    where
-        S: Signer,
-    {
+    /// Set the origin of the transaction, eg. the name of the proposing application.
+    pub fn with_origin(mut self, origin: impl Into<String>) -> Self {
+        self.origin = Some(origin.into());
+        self
+    }
+
+    /// Compute the EIP-712 hash of the transaction, which is what owners sign.
+    pub fn hash(&self, chain_id: u64) -> TxHash {
         use ethers::abi::Tokenizable;
         use ethers::utils::keccak256;
         use tiny_keccak::{Hasher, Keccak};
@@ -220,7 +321,7 @@ impl SafeTx {
             }
         }
 
-        let safe_tx_hash = TxHash::from({
+        TxHash::from({
             let mut hasher = Keccak::v256();
             let mut output = [0u8; 32];
             hasher.update(&[0x19, 0x01]);
@@ -231,7 +332,7 @@ impl SafeTx {
                     "0x47e79534a245952e8b16893a336b85a3d9ea9fa8c573f3d803afb92a79469218"
                 )
                 .unwrap(),
-                U256::from(signer.chain_id()),
+                U256::from(chain_id),
                 self.safe_address,
             ])));
             // safeTxHash
@@ -254,8 +355,17 @@ impl SafeTx {
             ])));
             hasher.finalize(&mut output);
             output
-        });
+        })
+    }
 
+    /// Sign the transaction with an externally-owned account, producing an `eth_sign`
+    /// signature.
+    // We consume self. If signing fails, we have bigger issues than recreating the transaction.
+    pub async fn sign<S>(self, signer: &S) -> Result<SignedSafeTx, S::Error>
+    where
+        S: Signer,
+    {
+        let safe_tx_hash = self.hash(signer.chain_id());
         let SignedSafeTxHash { signature, .. } = sign_tx_hash(signer, safe_tx_hash).await?;
 
         Ok(SignedSafeTx {
@@ -265,6 +375,33 @@ impl SafeTx {
             signature,
         })
     }
+
+    /// Sign the transaction on behalf of an owner that validates the transaction
+    /// hash itself, eg. a contract owner (EIP-1271) such as another Safe.
+    ///
+    /// This produces a "pre-validated" signature, which the Safe only accepts once
+    /// `owner` has approved the transaction hash on-chain with `approveHash`, or if
+    /// `owner` is the account executing the transaction.
+    pub fn sign_prevalidated(self, owner: Address, chain_id: u64) -> SignedSafeTx {
+        let safe_tx_hash = self.hash(chain_id);
+
+        SignedSafeTx {
+            inner: self,
+            sender: owner,
+            safe_tx_hash,
+            signature: prevalidated_signature(owner),
+        }
+    }
+}
+
+/// Pre-validated signature of an owner, as expected by the Safe contract: `r` holds
+/// the owner address, `s` is unused and `v` is `1`.
+pub fn prevalidated_signature(owner: Address) -> Signature {
+    Signature {
+        r: U256::from_big_endian(owner.as_bytes()),
+        s: U256::zero(),
+        v: 1,
+    }
 }
 
 pub struct SignedSafeTxHash {
@@ -300,6 +437,42 @@ where
     })
 }
 
+#[test]
+fn test_safe_tx_hash() {
+    let safe_tx = SafeTx {
+        safe_address: Address::from_str("0xb535CEd5f003e00CfF2424892D4885b139019F1d").unwrap(),
+        to: Address::from_str("0xaFb752f961CEF7FdfB9d2925120D23Aa9B4ed7Ae").unwrap(),
+        value: U256::zero(),
+        data: Bytes::from(hex::decode("68f1fbf80000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000000").unwrap()),
+        operation: Operation::Call,
+        nonce: U256::from(7u64),
+        origin: None,
+    };
+
+    // Computed independently from the EIP-712 `SafeTx` and `EIP712Domain` type definitions.
+    assert_eq!(
+        safe_tx.hash(1),
+        TxHash::from_str("0xb55a30bb3898f241fe8fef4ffd241320fb53d7ca7cfc8f0cc7c958dc6b613593")
+            .unwrap()
+    );
+    assert_ne!(safe_tx.hash(1), safe_tx.hash(5));
+}
+
+#[test]
+fn test_prevalidated_signature() {
+    let owner = Address::from_str("0xb535CEd5f003e00CfF2424892D4885b139019F1d").unwrap();
+    let signature = prevalidated_signature(owner);
+
+    assert_eq!(
+        hex::encode(signature.to_vec()),
+        format!(
+            "000000000000000000000000{}{}01",
+            hex::encode(owner.as_bytes()),
+            "0".repeat(64)
+        )
+    );
+}
+
 #[test]
 #[ignore]
 fn check() {
@@ -1,6 +1,7 @@
 use std::ffi::OsString;
 
 use anyhow::anyhow;
+use serde::Serialize;
 
 use radicle_common::args::{Args, Error, Help};
 use radicle_common::{keys, person, profile};
@@ -14,6 +15,13 @@ pub const HELP: Help = Help {
 Usage
 
     rad self [<option>...]
+    rad self --ssh-key [--format <hash|full|authorized_keys>]
+    rad self --set <key>=<value>...
+    rad self --avatar <url>
+
+    With `--set`, the given extension is added to your identity document,
+    replacing any existing value for the key. Values that are valid JSON are
+    stored as such, other values are stored as strings.
 
 Options
 
@@ -21,6 +29,11 @@ Options
     --urn        Show URN
     --peer       Show Peer ID
     --profile    Show Profile ID
+    --json       Output all information as JSON
+    --ssh-key    Show SSH public key
+    --format     SSH key format: `hash`, `full` or `authorized_keys` (default: full)
+    --set        Set an identity extension, eg. `--set pronouns=they/them`
+    --avatar     Set the URL of your avatar image
     --help       Show help
 "#,
 };
@@ -32,11 +45,37 @@ enum Show {
     Peer,
     Profile,
     All,
+    Json,
+    SshKey(KeyFormat),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum KeyFormat {
+    Hash,
+    Full,
+    AuthorizedKeys,
+}
+
+impl std::str::FromStr for KeyFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "hash" => Ok(Self::Hash),
+            "full" => Ok(Self::Full),
+            "authorized_keys" => Ok(Self::AuthorizedKeys),
+            _ => Err(anyhow!(
+                "invalid key format '{}', expected one of: hash, full, authorized_keys",
+                s
+            )),
+        }
+    }
 }
 
 #[derive(Debug)]
 pub struct Options {
     show: Show,
+    set: Vec<(String, serde_json::Value)>,
 }
 
 impl Args for Options {
@@ -45,6 +84,8 @@ impl Args for Options {
 
         let mut parser = lexopt::Parser::from_args(args);
         let mut show: Option<Show> = None;
+        let mut format: Option<KeyFormat> = None;
+        let mut set = Vec::new();
 
         while let Some(arg) = parser.next()? {
             match arg {
@@ -60,6 +101,46 @@ impl Args for Options {
                 Long("profile") if show.is_none() => {
                     show = Some(Show::Profile);
                 }
+                Long("json") if show.is_none() => {
+                    show = Some(Show::Json);
+                }
+                Long("ssh-key") | Long("key") if show.is_none() => {
+                    show = Some(Show::SshKey(KeyFormat::Full));
+                }
+                Long("format") => {
+                    let value = parser.value()?;
+                    let value = value.to_string_lossy();
+
+                    format = Some(value.parse()?);
+                }
+                Long("set") => {
+                    let value = parser.value()?;
+                    let value = value
+                        .to_str()
+                        .ok_or_else(|| anyhow!("value specified with `--set` is not UTF-8"))?;
+                    let (key, value) = value
+                        .split_once('=')
+                        .ok_or_else(|| anyhow!("expected `<key>=<value>` with `--set`"))?;
+                    let value = if key == "avatar" {
+                        // Avatars are validated the same way as with `--avatar`.
+                        let url = serde_json::from_str::<person::Avatar>(value)
+                            .map(|a| a.url)
+                            .unwrap_or_else(|_| value.to_owned());
+
+                        serde_json::to_value(person::Avatar::new(&url)?)?
+                    } else {
+                        serde_json::from_str(value)
+                            .unwrap_or_else(|_| serde_json::Value::String(value.to_owned()))
+                    };
+
+                    set.push((key.to_owned(), value));
+                }
+                Long("avatar") => {
+                    let value = parser.value()?;
+                    let avatar = person::Avatar::new(&value.to_string_lossy())?;
+
+                    set.push((String::from("avatar"), serde_json::to_value(avatar)?));
+                }
                 Long("help") => {
                     return Err(Error::Help.into());
                 }
@@ -67,17 +148,25 @@ impl Args for Options {
             }
         }
 
-        Ok((
-            Options {
-                show: show.unwrap_or(Show::All),
-            },
-            vec![],
-        ))
+        let show = match (show, format) {
+            (Some(Show::SshKey(_)), Some(format)) => Show::SshKey(format),
+            (_, Some(_)) => anyhow::bail!("`--format` can only be used with `--ssh-key`"),
+            (show, None) => show.unwrap_or(Show::All),
+        };
+        if !set.is_empty() && !matches!(show, Show::All) {
+            anyhow::bail!("`--set` and `--avatar` cannot be combined with other options");
+        }
+
+        Ok((Options { show, set }, vec![]))
     }
 }
 
 pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
     let profile = ctx.profile()?;
+
+    if !options.set.is_empty() {
+        return set(&profile, options.set);
+    }
     let storage = profile::read_only(&profile)?;
 
     match options.show {
@@ -103,11 +192,82 @@ pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
             );
         }
         Show::All => all(&profile)?,
+        Show::Json => json(&profile)?,
+        Show::SshKey(format) => {
+            let peer_id = storage.peer_id();
+            let key = match format {
+                KeyFormat::Hash => keys::to_ssh_fingerprint(peer_id)?,
+                KeyFormat::Full => keys::to_ssh_key(peer_id)?,
+                KeyFormat::AuthorizedKeys => keys::to_ssh_authorized_key(peer_id)?,
+            };
+            term::print(key);
+        }
     }
 
     Ok(())
 }
 
+fn set(profile: &profile::Profile, values: Vec<(String, serde_json::Value)>) -> anyhow::Result<()> {
+    let values = values
+        .into_iter()
+        .map(|(key, val)| Ok((person::ext_namespace(&key)?, val)))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    let signer = term::signer(profile)?;
+    let storage = keys::storage(profile, signer)?;
+
+    let spinner = term::spinner("Updating identity...");
+    let person = person::set_ext_values(values, &storage)?;
+    spinner.finish();
+
+    term::success!("Identity {} updated", term::format::highlight(person.urn()));
+    term::blob(serde_json::to_string(&person.payload())?);
+
+    Ok(())
+}
+
+/// Identity information, as output by `--json`.
+#[derive(Debug, Serialize)]
+struct Info {
+    profile: String,
+    name: Option<String>,
+    urn: Option<String>,
+    avatar: Option<String>,
+    peer_id: String,
+    ssh_fingerprint: String,
+    ssh_key: String,
+    git_storage: String,
+    keys_storage: String,
+}
+
+fn json(profile: &profile::Profile) -> anyhow::Result<()> {
+    let storage = profile::read_only(profile)?;
+    let urn = storage.config()?.user()?;
+    let person = match &urn {
+        Some(urn) => person::get(&storage, urn)?,
+        None => None,
+    };
+    let name = person.as_ref().map(|p| p.subject().name.to_string());
+    let avatar = person
+        .as_ref()
+        .and_then(|p| p.payload().get_ext::<person::Avatar>().ok().flatten())
+        .map(|a| a.url);
+    let peer_id = storage.peer_id();
+    let info = Info {
+        profile: profile.id().to_string(),
+        name,
+        urn: urn.map(|u| u.to_string()),
+        avatar,
+        peer_id: peer_id.to_string(),
+        ssh_fingerprint: keys::to_ssh_fingerprint(peer_id)?,
+        ssh_key: keys::to_ssh_key(peer_id)?,
+        git_storage: profile.paths().git_dir().display().to_string(),
+        keys_storage: profile.paths().keys_dir().display().to_string(),
+    };
+    term::print(serde_json::to_string_pretty(&info)?);
+
+    Ok(())
+}
+
 fn all(profile: &profile::Profile) -> anyhow::Result<()> {
     term::info!("Profile {}", term::format::secondary(profile.id()));
 
@@ -115,13 +275,21 @@ fn all(profile: &profile::Profile) -> anyhow::Result<()> {
     let storage = profile::read_only(profile)?;
 
     if let Some(urn) = storage.config()?.user()? {
-        if let Some(person) = person::get(&storage, &urn)? {
+        let person = person::get(&storage, &urn)?;
+
+        if let Some(person) = &person {
             table.push([
                 String::from("Name"),
                 term::format::tertiary(&person.subject().name),
             ]);
         }
         table.push([String::from("URN"), term::format::tertiary(&urn)]);
+
+        if let Some(person) = &person {
+            if let Ok(Some(avatar)) = person.payload().get_ext::<person::Avatar>() {
+                table.push([String::from("Avatar"), term::format::tertiary(avatar.url)]);
+            }
+        }
     }
 
     let peer_id = storage.peer_id();
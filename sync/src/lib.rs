@@ -3,6 +3,7 @@ use std::convert::TryInto;
 use std::ffi::OsString;
 use std::iter;
 use std::str::FromStr;
+use std::time;
 
 use librad::git::Storage;
 use librad::git::Urn;
@@ -12,13 +13,9 @@ use radicle_common::args;
 use radicle_common::args::{Args, Error, Help};
 use radicle_common::nonempty::NonEmpty;
 use radicle_common::sync::Mode;
-use radicle_common::{identity, keys, person, project, sync, tokio};
+use radicle_common::{identity, keys, person, project, seed, sync, tokio};
 use radicle_terminal as term;
 
-use anyhow::anyhow;
-use url::Url;
-
-pub const GATEWAY_HOST: &str = "app.radicle.xyz";
 pub const HELP: Help = Help {
     name: "sync",
     description: env!("CARGO_PKG_DESCRIPTION"),
@@ -30,14 +27,24 @@ Usage
     rad sync --self [--seed <address>]...
 
     If a <urn> is specified, seeds may be given via the `--seed` option.
-    If a <url> is specified, the seed is implied.
+    If a <url> is specified, the seed is implied. If the <url> has no peer id,
+    eg. `rad://<host>/<urn>`, the peer id is looked up from the seed itself.
     If neither is specified, the URN and seed of the current project is used.
-    If the project has no configured seed, the active profile's default seed list is used.
+
+    Seeds are chosen in the following order of precedence:
+
+        1. Seeds given with `--seed`, or implied by a <url>
+        2. Seeds listed in the project's `.rad/seeds` file, one per line
+        3. The active profile's default seeds (see `rad config`)
 
 Options
 
     --seed <address>    Sync to the given seed (may be specified multiple times)
     --self              Sync your local identity only
+    --timeout <secs>    Seconds allotted to each fetch or push (default: 30)
+    --fetch             Only fetch from seeds
+    --push              Only push to seeds
+    --verbose, -v       Show the outcome of each fetch and push, including errors
     --help              Print help
 
 Seed addresses
@@ -50,15 +57,33 @@ Seed addresses
 "#,
 };
 
-#[derive(Default, Debug)]
+/// Default time allotted to fetching from, or pushing to, a single seed. Longer than
+/// [`sync::DEFAULT_TIMEOUT`], since syncing is the purpose of this command.
+pub const DEFAULT_TIMEOUT: time::Duration = time::Duration::from_secs(30);
+
+#[derive(Debug)]
 pub struct Options {
     pub origin: Option<identity::Origin>,
     pub seeds: Vec<sync::Seed<String>>,
     pub mode: Mode,
+    pub timeout: time::Duration,
     pub verbose: bool,
     pub sync_self: bool,
 }
 
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            origin: None,
+            seeds: Vec::new(),
+            mode: Mode::default(),
+            timeout: DEFAULT_TIMEOUT,
+            verbose: false,
+            sync_self: false,
+        }
+    }
+}
+
 impl Args for Options {
     fn from_args(args: Vec<OsString>) -> anyhow::Result<(Self, Vec<OsString>)> {
         use lexopt::prelude::*;
@@ -69,6 +94,8 @@ impl Args for Options {
         let mut sync_self = false;
         let mut unparsed = Vec::new();
         let mut seeds = Vec::new();
+        let mut timeout = DEFAULT_TIMEOUT;
+        let mut mode = None;
 
         while let Some(arg) = parser.next()? {
             match arg {
@@ -81,14 +108,28 @@ impl Args for Options {
                 Long("self") => {
                     sync_self = true;
                 }
-                Long("seed") => {
+                Long("fetch") if mode.is_none() => {
+                    mode = Some(Mode::Fetch);
+                }
+                Long("push") if mode.is_none() => {
+                    mode = Some(Mode::Push);
+                }
+                Long("fetch") | Long("push") => {
+                    anyhow::bail!(
+                        "`--fetch` and `--push` can't be used together; omit both to fetch and push"
+                    );
+                }
+                Long("timeout") => {
                     let value = parser.value()?;
-                    let value = value.to_string_lossy();
-                    let value = value.as_ref();
-                    let addr = sync::Seed::from_str(value).map_err(|_| Error::WithHint {
-                        err: anyhow!("invalid seed address specified: '{}'", value),
-                        hint: "hint: valid seed addresses have the format <peer-id>@<host>:<port>, see `rad sync --help` for more information",
-                    })?;
+                    let secs = args::parse_value("timeout", value)?;
+
+                    if secs == 0 {
+                        anyhow::bail!("invalid timeout specified: must be at least 1 second");
+                    }
+                    timeout = time::Duration::from_secs(secs);
+                }
+                Long("seed") => {
+                    let addr = seed::parse_value(&mut parser)?;
 
                     seeds.push(addr);
                 }
@@ -108,24 +149,26 @@ impl Args for Options {
             }
         }
 
-        if let (
-            &[_, ..],
-            Some(identity::Origin {
-                seed: Some(addr), ..
-            }),
-        ) = (seeds.as_slice(), &origin)
-        {
-            anyhow::bail!(
-                "unexpected argument `--seed`, seed already set to '{}'",
-                addr
-            );
+        if let (&[_, ..], Some(origin)) = (seeds.as_slice(), &origin) {
+            let addr = match (&origin.seed, &origin.host) {
+                (Some(seed), _) => Some(seed.to_string()),
+                (None, Some(host)) => Some(host.clone()),
+                (None, None) => None,
+            };
+            if let Some(addr) = addr {
+                anyhow::bail!(
+                    "unexpected argument `--seed`, seed already set to '{}'",
+                    addr
+                );
+            }
         }
 
         Ok((
             Options {
                 origin,
                 seeds,
-                mode: Mode::default(),
+                mode: mode.unwrap_or_default(),
+                timeout,
                 sync_self,
                 verbose,
             },
@@ -135,6 +178,9 @@ impl Args for Options {
 }
 
 pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
+    if sync::is_offline() {
+        anyhow::bail!("cannot sync: offline mode is enabled");
+    }
     let profile = ctx.profile()?;
     let signer = term::signer(&profile)?;
     let storage = keys::storage(&profile, signer)?;
@@ -142,13 +188,22 @@ pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
     let urn = if let Some(origin) = &options.origin {
         origin.urn.clone()
     } else {
-        project::cwd().map(|(urn, _)| urn)?
+        project::cwd().map_err(Error::from).map(|(urn, _)| urn)?
     };
 
-    let seeds = if let Some(seed) = options.origin.as_ref().and_then(|o| o.seed.clone()) {
+    let origin_seed = options
+        .origin
+        .as_ref()
+        .map(|o| o.resolve_seed())
+        .transpose()?
+        .flatten();
+
+    let seeds = if let Some(seed) = origin_seed {
         NonEmpty::new(seed)
     } else if let Ok(seeds) = options.seeds.clone().try_into() {
         seeds
+    } else if let Some(seeds) = project_seeds(&urn, &options)? {
+        seeds
     } else {
         sync::seeds(&profile)?
     };
@@ -160,6 +215,33 @@ pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
     }
 }
 
+/// Get the seeds of the current project, if it's the project being synced and no seed
+/// was implied by the origin.
+fn project_seeds(
+    urn: &Urn,
+    options: &Options,
+) -> anyhow::Result<Option<NonEmpty<sync::Seed<String>>>> {
+    if !use_project_seeds(options) {
+        return Ok(None);
+    }
+    match project::cwd() {
+        Ok((cwd, repo)) if &cwd == urn => seed::project_seeds(&repo),
+        _ => Ok(None),
+    }
+}
+
+/// Whether the project's `.rad/seeds` file should be consulted. This is the case unless
+/// we're syncing our own identity, or the origin already names a seed.
+fn use_project_seeds(options: &Options) -> bool {
+    if options.sync_self {
+        return false;
+    }
+    match &options.origin {
+        Some(origin) => origin.seed.is_none() && origin.host.is_none(),
+        None => true,
+    }
+}
+
 pub fn sync_self(
     profile: &Profile,
     seeds: NonEmpty<sync::Seed<String>>,
@@ -176,13 +258,24 @@ pub fn sync_self(
     ));
 
     let signer = term::signer(profile)?;
-    let _result = term::sync::sync(urn, seeds, options.mode, profile, signer, &rt)?;
+    let results = term::sync::sync(
+        urn,
+        seeds.clone(),
+        options.mode,
+        options.timeout,
+        profile,
+        signer,
+        &rt,
+    )?;
+    term::blank();
+    term::sync::print_results(&results);
 
     if options.verbose {
-        // TODO: When sync result is usable, output should go here.
+        term::blank();
+        term::sync::print_status(&seeds, &results);
     }
 
-    Ok(())
+    ensure_synced(&results)
 }
 
 pub fn sync(
@@ -201,81 +294,86 @@ pub fn sync(
 
     let storage = storage.read_only();
     let signer = term::signer(profile)?;
-    let _result = term::sync::sync(
+    let results = term::sync::sync(
         urn.clone(),
         seeds.clone(),
         options.mode,
+        options.timeout,
         profile,
         signer,
         &rt,
     )?;
     term::blank();
+    term::sync::print_results(&results);
+    term::blank();
 
     if options.verbose {
-        // TODO: When sync result is usable, output should go here.
-        // TODO: Depending on the result, we can show `~` as in partial success, `ok` as in total
-        //       success and `!!` as in no success.
         // TODO: NoConnection can be due to invalid PeerId!
         // TODO: Success with no refs updated can mean the server is not tracking us.
+        term::sync::print_status(&seeds, &results);
+        term::blank();
     }
+    ensure_synced(&results)?;
 
     if let Some(proj) = project::get(&storage, &urn)? {
         let peer_id = storage.peer_id();
 
-        for seed in &seeds {
-            let host = &seed.addrs;
-            if let Ok(mut url) = Url::from_str(&format!("https://{}", host)) {
-                url.set_port(None).ok();
-
-                if let Some(host) = url.host() {
-                    let is_routable = match host {
-                        url::Host::Domain("localhost") => false,
-                        url::Host::Domain(_) => true,
-                        url::Host::Ipv4(ip) => {
-                            !ip.is_loopback() && !ip.is_unspecified() && !ip.is_private()
-                        }
-                        url::Host::Ipv6(ip) => !ip.is_loopback() && !ip.is_unspecified(),
-                    };
-
-                    term::info!("🍃 Your project is available at:");
-                    term::blank();
-
-                    if is_routable {
-                        if proj.remotes.contains(peer_id) {
-                            term::indented(&format!(
-                                "{} {}",
-                                term::format::dim("(web)"),
-                                term::format::highlight(format!(
-                                    "https://{}/seeds/{}/{}",
-                                    GATEWAY_HOST, host, urn
-                                ))
-                            ));
-                        }
-                        term::indented(&format!(
-                            "{} {}",
-                            term::format::dim("(web)"),
-                            term::format::highlight(format!(
-                                "https://{}/seeds/{}/{}/remotes/{}",
-                                GATEWAY_HOST, host, urn, peer_id
-                            ))
-                        ));
-                    } else {
-                        url.set_scheme("http").ok();
-                    }
+        term::sync::print_project_urls(&urn, peer_id, proj.remotes.contains(peer_id), &seeds)?;
+    }
 
-                    let id = urn.encode_id();
-                    let git_url = url.join(&id)?;
+    Ok(())
+}
 
-                    term::indented(&format!(
-                        "{} {}",
-                        term::format::dim("(git)"),
-                        term::format::highlight(format!("{}.git", git_url)),
-                    ));
-                    term::blank();
-                }
-            }
-        }
+/// Fail if not a single seed could be synced with, so that `rad sync` exits with the
+/// network error code.
+fn ensure_synced(results: &NonEmpty<sync::SyncResult>) -> anyhow::Result<()> {
+    if results.iter().any(|r| r.is_success()) {
+        Ok(())
+    } else {
+        Err(sync::SyncError::AllFailed(results.len()).into())
     }
+}
 
-    Ok(())
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_use_project_seeds() {
+        let urn = Urn::from_str("rad:git:hnrkmg77m8tfzj4gi4pa4mbhgysfgzwntjpao").unwrap();
+
+        // `rad sync` from within a project.
+        assert!(use_project_seeds(&Options::default()));
+
+        // `rad pull` always passes the URN of the current project as origin.
+        assert!(use_project_seeds(&Options {
+            origin: Some(identity::Origin::from_urn(urn.clone())),
+            mode: Mode::Fetch,
+            ..Options::default()
+        }));
+
+        // An origin with a seed, eg. `rad sync rad://<peer>@<host>/<urn>`.
+        let origin = identity::Origin::from_str(
+            "rad://hyb5to4rshftx4apgmu9s6wnsp4ddmp1mz6ijh4qqey7fb8wrpawxa@willow.radicle.garden:8776/hnrkmg77m8tfzj4gi4pa4mbhgysfgzwntjpao",
+        )
+        .unwrap();
+        assert!(!use_project_seeds(&Options {
+            origin: Some(origin),
+            ..Options::default()
+        }));
+
+        // An origin with only a seed host, eg. `rad sync rad://<host>/<urn>`.
+        assert!(!use_project_seeds(&Options {
+            origin: Some(identity::Origin {
+                host: Some(String::from("willow.radicle.garden:8776")),
+                ..identity::Origin::from_urn(urn)
+            }),
+            ..Options::default()
+        }));
+
+        assert!(!use_project_seeds(&Options {
+            sync_self: true,
+            ..Options::default()
+        }));
+    }
 }
@@ -0,0 +1,72 @@
+//! Terminal color mode.
+use std::fmt;
+use std::str::FromStr;
+
+use console::Term;
+
+/// When to use colors in terminal output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Use colors only when writing to a terminal, and `NO_COLOR` isn't set.
+    Auto,
+    /// Always use colors.
+    Always,
+    /// Never use colors.
+    Never,
+}
+
+impl Default for Mode {
+    fn default() -> Self {
+        Self::Auto
+    }
+}
+
+impl fmt::Display for Mode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Auto => write!(f, "auto"),
+            Self::Always => write!(f, "always"),
+            Self::Never => write!(f, "never"),
+        }
+    }
+}
+
+impl FromStr for Mode {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Self::Auto),
+            "always" => Ok(Self::Always),
+            "never" => Ok(Self::Never),
+            _ => Err(anyhow::anyhow!(
+                "invalid color mode '{}', expected one of 'auto', 'always' or 'never'",
+                s
+            )),
+        }
+    }
+}
+
+impl Mode {
+    /// Whether colors should be used when writing to the given terminal.
+    fn enabled(&self, term: &Term) -> bool {
+        match self {
+            Self::Always => true,
+            Self::Never => false,
+            Self::Auto => {
+                let no_color = std::env::var_os("NO_COLOR").map_or(false, |v| !v.is_empty());
+
+                !no_color && term.features().colors_supported()
+            }
+        }
+    }
+}
+
+/// Set the color mode of all terminal output.
+///
+/// Styling is done via `console`, so this applies to the `format` helpers as
+/// well as tables, spinners and prompts.
+pub fn set_mode(mode: Mode) {
+    console::set_colors_enabled(mode.enabled(&Term::stdout()));
+    console::set_colors_enabled_stderr(mode.enabled(&Term::stderr()));
+}
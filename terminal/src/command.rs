@@ -1,4 +1,4 @@
-use std::io::Write;
+use std::io::{self, Write};
 use std::process::{Command, Stdio};
 
 pub fn bat<S: AsRef<std::ffi::OsStr>>(
@@ -11,7 +11,34 @@ pub fn bat<S: AsRef<std::ffi::OsStr>>(
         .spawn()?;
 
     let writer = child.stdin.as_mut().unwrap();
-    writer.write_all(stdin.as_bytes())?;
+    match writer.write_all(stdin.as_bytes()) {
+        // The pager was quit before reading all of its input.
+        Err(err) if err.kind() == io::ErrorKind::BrokenPipe => {}
+        result => result?,
+    }
+
+    child.wait()?;
+
+    Ok(())
+}
+
+/// Show the given text in the user's pager, defaulting to `less`.
+pub fn pager(stdin: &str) -> anyhow::Result<()> {
+    let pager = std::env::var("PAGER").unwrap_or_else(|_| String::from("less -R"));
+    let mut args = pager.split_whitespace();
+    let program = args.next().unwrap_or("less");
+
+    let mut child = Command::new(program)
+        .stdin(Stdio::piped())
+        .args(args)
+        .spawn()?;
+
+    let writer = child.stdin.as_mut().unwrap();
+    match writer.write_all(stdin.as_bytes()) {
+        // The pager was quit before reading all of its input.
+        Err(err) if err.kind() == io::ErrorKind::BrokenPipe => {}
+        result => result?,
+    }
 
     child.wait()?;
 
@@ -11,13 +11,29 @@ use radicle_common::ethereum::{Wallet, WalletError};
 
 use crate as term;
 
-/// Open a wallet from the given options and provider.
-pub async fn open_wallet<P>(options: SignerOptions, provider: Provider<P>) -> anyhow::Result<Wallet>
+/// Open a wallet from the given options and provider, which must be on the given chain.
+pub async fn open_wallet<P>(
+    options: SignerOptions,
+    provider: Provider<P>,
+    chain: u64,
+) -> anyhow::Result<Wallet>
 where
     P: JsonRpcClient + Clone + 'static,
 {
     let chain_id = provider.get_chainid().await?.as_u64();
 
+    if chain_id != chain {
+        return Err(args::Error::WithHint {
+            err: anyhow!(
+                "the JSON-RPC provider is on chain {}, but chain {} was selected",
+                chain_id,
+                chain
+            ),
+            hint: "Use `--chain` to select the provider's chain, or `--rpc-url` to use a different provider.",
+        }
+        .into());
+    }
+
     if let Some(keypath) = &options.keystore {
         let password = term::secret_input_with_prompt("Keystore password");
         let spinner = term::spinner("Decrypting keystore...");
@@ -48,13 +64,14 @@ where
     }
 }
 
-/// Access the wallet specified in SignerOptions
+/// Access the wallet specified in SignerOptions, on the given chain.
 pub async fn get_wallet(
     signer_opts: SignerOptions,
     provider: Provider<Http>,
+    chain: u64,
 ) -> anyhow::Result<(Wallet, Provider<Http>)> {
     term::tip!("Accessing your wallet...");
-    let signer = match open_wallet(signer_opts, provider.clone()).await {
+    let signer = match open_wallet(signer_opts, provider.clone(), chain).await {
         Ok(signer) => signer,
         Err(err) => {
             if let Some(WalletError::NoWallet) = err.downcast_ref::<WalletError>() {
@@ -1,6 +1,8 @@
 use std::fmt;
 use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
 
+use anyhow::Context as _;
 use zeroize::Zeroizing;
 
 use librad::crypto::keystore::pinentry::SecUtf8;
@@ -10,8 +12,9 @@ use librad::profile::Profile;
 use dialoguer::{console::style, console::Style, theme::ColorfulTheme, Input, Password};
 
 use radicle_common::cobs::issue::Issue;
+use radicle_common::cobs::patch::Revision;
 use radicle_common::cobs::shared::CommentId;
-use radicle_common::signer::ToSigner;
+use radicle_common::signer::{self, ToSigner};
 
 use super::command;
 use super::display;
@@ -25,6 +28,20 @@ pub use super::ethereum;
 
 pub const TAB: &str = "   ";
 
+/// Whether informational output is suppressed.
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+/// Suppress informational output, such as headlines, tips and spinners.
+/// Errors and success messages are still shown.
+pub fn set_quiet(quiet: bool) {
+    QUIET.store(quiet, Ordering::Relaxed);
+}
+
+/// Whether informational output is suppressed.
+pub fn is_quiet() -> bool {
+    QUIET.load(Ordering::Relaxed)
+}
+
 #[macro_export]
 macro_rules! info {
     ($($arg:tt)*) => ({
@@ -51,6 +68,9 @@ pub fn success_args(args: fmt::Arguments) {
 }
 
 pub fn tip_args(args: fmt::Arguments) {
+    if is_quiet() {
+        return;
+    }
     println!(
         "{} {}",
         style("=>").blue(),
@@ -68,12 +88,18 @@ pub fn width() -> usize {
 }
 
 pub fn headline(headline: &str) {
+    if is_quiet() {
+        return;
+    }
     println!();
     println!("{}", style(headline).bold());
     println!();
 }
 
 pub fn blob(text: impl fmt::Display) {
+    if is_quiet() {
+        return;
+    }
     println!("{}", style(text.to_string().trim()).dim());
 }
 
@@ -113,6 +139,9 @@ pub fn indented(msg: &str) {
 }
 
 pub fn subcommand(msg: impl fmt::Display) {
+    if is_quiet() {
+        return;
+    }
     println!("{} {}", style("$").dim(), style(msg).dim());
 }
 
@@ -181,7 +210,17 @@ pub fn abort<D: fmt::Display>(prompt: D) -> bool {
 }
 
 /// Get the signer. First we try getting it from ssh-agent, otherwise we prompt the user.
+/// If a hardware signer is selected, only its agent is tried.
 pub fn signer(profile: &Profile) -> anyhow::Result<BoxedSigner> {
+    if signer::backend(profile)? == signer::Backend::Hardware {
+        let sock = keys::agent(profile)?;
+        let signer = sock
+            .to_signer(profile)
+            .context("the radicle key for this profile is not on the hardware signer")?;
+
+        return Ok(signer);
+    }
+
     let signer = if let Ok(sock) = keys::ssh_auth_sock() {
         sock.to_signer(profile)?
     } else {
@@ -294,10 +333,17 @@ pub fn secret_input_with_prompt(prompt: &str) -> SecUtf8 {
 }
 
 pub fn secret_input_with_confirmation() -> SecUtf8 {
+    secret_input_with_confirmation_prompt("Passphrase")
+}
+
+pub fn secret_input_with_confirmation_prompt(prompt: &str) -> SecUtf8 {
     SecUtf8::from(
         Password::with_theme(&theme())
-            .with_prompt("Passphrase")
-            .with_confirmation("Repeat passphrase", "Error: the passphrases don't match.")
+            .with_prompt(prompt)
+            .with_confirmation(
+                format!("Repeat {}", prompt.to_lowercase()),
+                "Error: the passphrases don't match.",
+            )
             .interact()
             .unwrap(),
     )
@@ -400,16 +446,27 @@ pub fn profile_select<'a>(profiles: &'a [Profile], active: &Profile) -> Option<&
 }
 
 pub fn comment_select(issue: &Issue) -> Option<CommentId> {
+    select_comment(
+        issue.description(),
+        issue.comments().iter().map(|c| c.body.as_str()),
+    )
+}
+
+pub fn patch_comment_select(revision: &Revision) -> Option<CommentId> {
+    select_comment(
+        revision.description(),
+        revision.discussion.iter().map(|c| c.body.as_str()),
+    )
+}
+
+fn select_comment<'a>(
+    description: &str,
+    comments: impl Iterator<Item = &'a str>,
+) -> Option<CommentId> {
     let selection = dialoguer::Select::with_theme(&theme())
         .with_prompt("Which comment do you want to react to?")
-        .item(&issue.description().to_string())
-        .items(
-            &issue
-                .comments()
-                .iter()
-                .map(|p| p.body.clone())
-                .collect::<Vec<_>>(),
-        )
+        .item(description)
+        .items(&comments.collect::<Vec<_>>())
         .default(CommentId::root().into())
         .interact_opt()
         .unwrap();
@@ -1,5 +1,6 @@
 #![allow(clippy::collapsible_if)]
 
+pub mod color;
 pub mod command;
 pub mod display;
 #[cfg(feature = "ethereum")]
@@ -19,6 +20,7 @@ use std::process;
 
 use dialoguer::console::style;
 use radicle_common::args::{Args, Error, Help};
+use radicle_common::exit::ExitCode;
 use radicle_common::profile;
 use radicle_common::profile::Profile;
 
@@ -82,11 +84,26 @@ where
 {
     use crate::io as term;
 
+    // `--version` is handled here rather than by each command's argument parser.
+    if args
+        .iter()
+        .take_while(|arg| *arg != "--")
+        .any(|arg| arg == "--version")
+    {
+        println!("rad-{} {}", help.name, help.version);
+        process::exit(0);
+    }
+
+    // Logging is configured here, before the command runs, from `RUST_LOG` or `rad -v`.
+    if let Err(err) = radicle_common::logger::setup() {
+        term::warning(&format!("Logging disabled: {}", err));
+    }
+
     let options = match A::from_args(args) {
         Ok((opts, unparsed)) => {
             if let Err(err) = radicle_common::args::finish(unparsed) {
                 term::error(err);
-                process::exit(1);
+                process::exit(ExitCode::Usage.code());
             }
             opts
         }
@@ -94,11 +111,11 @@ where
             match err.downcast_ref::<Error>() {
                 Some(Error::Help) => {
                     term::help(help.name, help.version, help.description, help.usage);
-                    process::exit(0);
+                    process::exit(ExitCode::Success.code());
                 }
                 Some(Error::Usage) => {
                     term::usage(help.name, help.usage);
-                    process::exit(1);
+                    process::exit(ExitCode::Usage.code());
                 }
                 _ => {}
             };
@@ -114,15 +131,21 @@ where
                 eprintln!("{}", style(hint).yellow());
             }
 
-            process::exit(1);
+            // Errors during argument parsing are usage errors, unless they can be
+            // classified more precisely.
+            let code = match ExitCode::from(&err) {
+                ExitCode::Failure => ExitCode::Usage,
+                code => code,
+            };
+            process::exit(code.code());
         }
     };
 
     match cmd.run(options, profile::default) {
-        Ok(()) => process::exit(0),
+        Ok(()) => process::exit(ExitCode::Success.code()),
         Err(err) => {
             term::fail(&format!("{} failed", action), &err);
-            process::exit(1);
+            process::exit(ExitCode::from(&err).code());
         }
     }
 }
@@ -1,3 +1,5 @@
+use std::io;
+
 use radicle_common as common;
 use radicle_common::git;
 
@@ -40,6 +42,15 @@ impl Comment {
             *self = Comment::Text(arg.into());
         };
     }
+
+    /// Read text from the given reader and append it to the comment.
+    pub fn append_from(&mut self, mut reader: impl io::Read) -> io::Result<()> {
+        let mut text = String::new();
+        reader.read_to_string(&mut text)?;
+        self.append(text.trim());
+
+        Ok(())
+    }
 }
 
 impl Default for Comment {
@@ -66,6 +77,23 @@ pub fn list_commits(commits: &[git::Commit]) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Create a human friendly message about git's sync status.
+pub fn pretty_sync_status(
+    repo: &git::Repository,
+    revision_oid: git::Oid,
+    head_oid: git::Oid,
+) -> anyhow::Result<String> {
+    let (a, b) = repo.graph_ahead_behind(revision_oid, head_oid)?;
+    if a == 0 && b == 0 {
+        return Ok(term::format::dim("up to date"));
+    }
+
+    let ahead = term::format::positive(a);
+    let behind = term::format::negative(b);
+
+    Ok(format!("ahead {}, behind {}", ahead, behind))
+}
+
 /// Print commits ahead and behind.
 pub fn print_commits_ahead_behind(
     repo: &git::Repository,
@@ -85,3 +113,101 @@ pub fn print_commits_ahead_behind(
     );
     Ok(())
 }
+
+/// Print a summary of the files changed between two commits.
+///
+/// For example `5 files changed, 120 insertions(+), 8 deletions(-)`.
+pub fn print_diffstat(
+    repo: &git::Repository,
+    base: git::Oid,
+    head: git::Oid,
+) -> anyhow::Result<()> {
+    let base = repo.find_commit(base)?.tree()?;
+    let head = repo.find_commit(head)?.tree()?;
+    let stats = repo
+        .diff_tree_to_tree(Some(&base), Some(&head), None)?
+        .stats()?;
+
+    // Like `git diff --stat`, counts of zero insertions or deletions are left out.
+    let mut summary = vec![format!(
+        "{} {} changed",
+        term::format::bold(stats.files_changed()),
+        plural(stats.files_changed(), "file", "files")
+    )];
+    if stats.insertions() > 0 {
+        summary.push(format!(
+            "{} {}(+)",
+            term::format::positive(stats.insertions()),
+            plural(stats.insertions(), "insertion", "insertions")
+        ));
+    }
+    if stats.deletions() > 0 {
+        summary.push(format!(
+            "{} {}(-)",
+            term::format::negative(stats.deletions()),
+            plural(stats.deletions(), "deletion", "deletions")
+        ));
+    }
+    term::info!("{}", summary.join(", "));
+
+    Ok(())
+}
+
+fn plural<'a>(n: usize, singular: &'a str, plural: &'a str) -> &'a str {
+    if n == 1 {
+        singular
+    } else {
+        plural
+    }
+}
+
+/// Print a colored unified diff between two commits.
+///
+/// Lines are cut to the terminal width, and the diff is shown in a pager
+/// if it doesn't fit on the screen.
+pub fn print_diff(repo: &git::Repository, base: git::Oid, head: git::Oid) -> anyhow::Result<()> {
+    let base = repo.find_commit(base)?.tree()?;
+    let head = repo.find_commit(head)?.tree()?;
+    let diff = repo.diff_tree_to_tree(Some(&base), Some(&head), None)?;
+    let width = term::width();
+    let mut lines = Vec::new();
+
+    diff.print(git::DiffFormat::Patch, |_, _, line| {
+        let content = String::from_utf8_lossy(line.content());
+        let content = content.trim_end_matches('\n');
+        let origin = line.origin();
+        let text = match origin {
+            '+' | '-' | ' ' => format!("{}{}", origin, content),
+            _ => content.to_owned(),
+        };
+        let text = if text.chars().count() > width {
+            text.chars()
+                .take(width.saturating_sub(1))
+                .chain(std::iter::once('…'))
+                .collect()
+        } else {
+            text
+        };
+
+        lines.push(match origin {
+            '+' => term::format::positive(text),
+            '-' => term::format::negative(text),
+            'H' => term::format::tertiary(text),
+            'F' => term::format::bold(text),
+            _ => text,
+        });
+        true
+    })?;
+
+    let stdout = console::Term::stdout();
+    let (rows, _) = stdout.size();
+
+    if stdout.is_term() && lines.len() > rows as usize {
+        term::command::pager(&lines.join("\n"))?;
+    } else {
+        for line in lines {
+            println!("{}", line);
+        }
+    }
+    Ok(())
+}
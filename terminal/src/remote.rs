@@ -3,6 +3,7 @@ use std::collections::HashSet;
 use librad::git::storage::Storage;
 use librad::git::Urn;
 
+use radicle_common as common;
 use radicle_common::{git, project};
 
 use crate as term;
@@ -13,7 +14,7 @@ pub fn list(storage: &Storage, repo: &git::Repository, urn: &Urn) -> anyhow::Res
         .ok_or_else(|| anyhow::anyhow!("project {} not found on local device", urn))?;
     let mut peers = HashSet::new();
 
-    for (_, peer) in git::remotes(repo)? {
+    for (name, peer) in git::remotes(repo)? {
         if !peers.insert(peer) {
             // Don't show duplicate peers.
             continue;
@@ -24,15 +25,31 @@ pub fn list(storage: &Storage, repo: &git::Repository, urn: &Urn) -> anyhow::Res
         } else {
             String::new()
         };
+        let branch = git::tracking_branch(repo, &name)?
+            .map(term::format::secondary)
+            .unwrap_or_else(|| term::format::dim("-"));
+        let head = project::get_remote_head(&storage, urn, &peer, &proj.default_branch)
+            .ok()
+            .flatten()
+            .map(|oid| term::format::secondary(common::fmt::oid(&oid)))
+            .unwrap_or_else(|| term::format::dim("-"));
 
         if let Some(person) = project::person(&storage, urn.clone(), &peer)? {
             table.push([
                 term::format::bold(person.subject().name.to_string()),
                 term::format::tertiary(peer),
+                branch,
+                head,
                 delegate,
             ]);
         } else {
-            table.push([String::new(), term::format::tertiary(peer), delegate]);
+            table.push([
+                String::new(),
+                term::format::tertiary(peer),
+                branch,
+                head,
+                delegate,
+            ]);
         }
     }
     table.render();
@@ -20,12 +20,18 @@ impl Drop for Spinner {
 impl Spinner {
     pub fn finish(&self) {
         self.progress.finish_and_clear();
-        term::success!("{}", &self.message);
+
+        if !term::is_quiet() {
+            term::success!("{}", &self.message);
+        }
     }
 
     pub fn done(self) {
         self.progress.finish_and_clear();
-        term::info!("{}", &self.message);
+
+        if !term::is_quiet() {
+            term::info!("{}", &self.message);
+        }
     }
 
     pub fn failed(mut self) {
@@ -58,6 +64,23 @@ impl Spinner {
 
 pub fn spinner(message: impl ToString) -> Spinner {
     let message = message.to_string();
+    if term::is_quiet() {
+        return Spinner {
+            message,
+            progress: ProgressBar::hidden(),
+        };
+    }
+    // When not writing to a terminal, eg. in CI logs or pipes, don't animate: print
+    // a start line, and let `finish` and `failed` print the final status line.
+    if !console::Term::stdout().is_term() {
+        term::info!("{}", style(&message).dim());
+
+        return Spinner {
+            message,
+            progress: ProgressBar::hidden(),
+        };
+    }
+
     let style = ProgressStyle::default_spinner()
         .tick_strings(&[
             &style("\\ ").yellow().to_string(),
@@ -2,10 +2,12 @@ use std::convert::TryInto;
 use std::time;
 
 use librad::git::Urn;
+use librad::PeerId;
 
 use radicle_common as common;
 use radicle_common::nonempty::NonEmpty;
 use radicle_common::profile::Profile;
+use radicle_common::seed;
 use radicle_common::signer::ToSigner;
 use radicle_common::sync;
 use radicle_common::sync::SyncResult;
@@ -16,33 +18,157 @@ pub fn sync(
     urn: Urn,
     seeds: NonEmpty<sync::Seed<String>>,
     mode: sync::Mode,
+    timeout: time::Duration,
     profile: &Profile,
     signer: impl ToSigner,
     rt: &common::tokio::runtime::Runtime,
 ) -> anyhow::Result<NonEmpty<SyncResult>> {
     let signer = signer.to_signer(profile)?;
-    let timeout = time::Duration::from_secs(9);
-    let spinner = term::spinner("Syncing...");
+    let mut spinner = term::spinner("Syncing...");
     let result = rt.block_on(async {
-        let (seeds, _errors) = sync::Seeds::resolve(seeds.iter()).await;
+        let (seeds, errors) = sync::Seeds::resolve(seeds.iter()).await;
+        for err in errors {
+            term::warning(&format!("Failed to resolve seed: {}", err));
+        }
+        let total = seeds.0.len();
+        let mut done = 0;
         let client = sync::client(signer, profile).await?;
-        let result = sync::sync(&client, urn, seeds, mode, timeout).await;
+        let result = sync::sync(&client, urn, seeds, mode, timeout, |_| {
+            done += 1;
+            spinner.message(format!("Syncing... ({}/{} seeds)", done, total));
+        })
+        .await;
 
         Ok::<Vec<SyncResult>, anyhow::Error>(result)
     })?;
 
-    let results = if let Ok(results) = result.try_into() {
+    let results: NonEmpty<SyncResult> = if let Ok(results) = result.try_into() {
         results
     } else {
-        return Err(anyhow::anyhow!(
-            "No seeds attempted: all seeds failed to resolve"
-        ));
+        spinner.failed();
+        return Err(sync::SyncError::NoSeeds.into());
     };
 
-    match mode {
-        sync::Mode::Push | sync::Mode::All => spinner.finish(),
-        sync::Mode::Fetch => spinner.clear(),
+    if results.iter().all(|r| r.is_success()) {
+        match mode {
+            sync::Mode::Push | sync::Mode::All => spinner.finish(),
+            sync::Mode::Fetch => spinner.clear(),
+        }
+    } else {
+        spinner.failed();
     }
 
     Ok(results)
 }
+
+/// Print a summary of sync results, one line per seed.
+pub fn print_results(results: &NonEmpty<SyncResult>) {
+    let mut table = term::Table::default();
+
+    for result in results {
+        let addrs = result
+            .seed
+            .addrs
+            .iter()
+            .map(|a| a.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let status = result.status();
+        let status = if result.is_success() {
+            term::format::positive(status)
+        } else {
+            term::format::negative(status)
+        };
+
+        table.push([
+            term::format::tertiary(common::fmt::peer(&result.seed.peer)),
+            term::format::dim(addrs),
+            status,
+        ]);
+    }
+    table.render();
+}
+
+/// Print the URLs under which a project is available on the given seeds.
+pub fn print_project_urls(
+    urn: &Urn,
+    peer: &PeerId,
+    delegate: bool,
+    seeds: &NonEmpty<sync::Seed<String>>,
+) -> anyhow::Result<()> {
+    for seed in seeds {
+        if let Some(urls) = seed::project_urls(&seed.addrs, urn, peer, delegate)? {
+            term::info!("🍃 Your project is available at:");
+            term::blank();
+
+            for url in &urls.web {
+                term::indented(&format!(
+                    "{} {}",
+                    term::format::dim("(web)"),
+                    term::format::highlight(url)
+                ));
+            }
+            term::indented(&format!(
+                "{} {}",
+                term::format::dim("(git)"),
+                term::format::highlight(&urls.git),
+            ));
+            term::blank();
+        }
+    }
+    Ok(())
+}
+
+/// Print the outcome of each operation with each seed synced with, using the seed host names
+/// as labels. Unlike [`print_results`], fetch and push are reported separately, along with the
+/// full chain of any error.
+pub fn print_status(seeds: &NonEmpty<sync::Seed<String>>, results: &NonEmpty<SyncResult>) {
+    for result in results {
+        let name = seeds
+            .iter()
+            .find(|s| s.peer == result.seed.peer)
+            .map(|s| s.addrs.clone())
+            .unwrap_or_else(|| common::fmt::peer(&result.seed.peer));
+
+        term::info!("{}", term::format::tertiary(name));
+
+        if let Some(fetch) = &result.fetch {
+            match fetch {
+                Ok(success) => term::indented(&format!(
+                    "fetch: {} ref(s) updated",
+                    success.updated_refs().len()
+                )),
+                Err(err) => term::indented(&format!(
+                    "fetch: {}",
+                    term::format::negative(error_chain(err))
+                )),
+            }
+        }
+        if let Some(push) = &result.push {
+            match push {
+                Ok(success) => {
+                    term::indented(&format!("push: {} ref(s) updated", success.refs.len()))
+                }
+                Err(err) => term::indented(&format!(
+                    "push: {}",
+                    term::format::negative(error_chain(err))
+                )),
+            }
+        }
+        if result.timed_out {
+            term::indented(&term::format::negative("timed out"));
+        }
+    }
+}
+
+/// Format an error along with all of its causes.
+fn error_chain(err: &dyn std::error::Error) -> String {
+    let mut msg = err.to_string();
+    let mut source = err.source();
+
+    while let Some(cause) = source {
+        msg.push_str(&format!(": {}", cause));
+        source = cause.source();
+    }
+    msg
+}
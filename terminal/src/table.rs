@@ -1,16 +1,23 @@
+use std::borrow::Cow;
 use std::fmt::Write;
 
 use crate as term;
 
+pub use console::Alignment;
+
 #[derive(Debug, Default)]
 pub struct TableOptions {
     pub overflow: bool,
+    /// Maximum width of every column. Longer cells are truncated with an ellipsis.
+    pub max_width: Option<usize>,
 }
 
 #[derive(Debug)]
 pub struct Table<const W: usize> {
     rows: Vec<[String; W]>,
     widths: [usize; W],
+    max_widths: [Option<usize>; W],
+    alignments: [Alignment; W],
     opts: TableOptions,
 }
 
@@ -19,16 +26,26 @@ impl<const W: usize> Table<W> {
         Self {
             rows: Vec::new(),
             widths: [0; W],
+            max_widths: [None; W],
+            alignments: [Alignment::Left; W],
             opts,
         }
     }
 
     pub fn default() -> Self {
-        Self {
-            rows: Vec::new(),
-            widths: [0; W],
-            opts: TableOptions::default(),
-        }
+        Self::new(TableOptions::default())
+    }
+
+    /// Set the maximum width of a column. Longer cells are truncated with an ellipsis.
+    pub fn max_width(&mut self, column: usize, width: usize) -> &mut Self {
+        self.max_widths[column] = Some(width);
+        self
+    }
+
+    /// Set the alignment of a column.
+    pub fn align(&mut self, column: usize, alignment: Alignment) -> &mut Self {
+        self.alignments[column] = alignment;
+        self
     }
 
     pub fn push(&mut self, row: [String; W]) {
@@ -39,26 +56,39 @@ impl<const W: usize> Table<W> {
     }
 
     pub fn render(self) {
-        let width = term::width(); // Terminal width.
+        for line in self.lines(term::width()) {
+            println!("{}", line);
+        }
+    }
+
+    /// Render the rows of the table, cutting each line to the given width.
+    fn lines(&self, width: usize) -> Vec<String> {
+        // Columns that are empty in every row are left out.
+        let columns = (0..W).filter(|i| self.widths[*i] > 0).collect::<Vec<_>>();
+        let mut lines = Vec::with_capacity(self.rows.len());
 
         for row in &self.rows {
             let mut output = String::new();
-            let cells = row.len();
 
-            for (i, cell) in row.iter().enumerate() {
-                if i == cells - 1 || self.opts.overflow {
+            for (n, &i) in columns.iter().enumerate() {
+                let cell = self.truncate(i, &row[i]);
+
+                if self.opts.overflow {
                     write!(output, "{}", cell).ok();
+                } else if n == columns.len() - 1 {
+                    // Don't pad the last column with trailing whitespace, unless it's aligned.
+                    if matches!(self.alignments[i], Alignment::Left) {
+                        write!(output, "{}", cell).ok();
+                    } else {
+                        write!(output, "{}", self.pad(i, &cell)).ok();
+                    }
                 } else {
-                    write!(
-                        output,
-                        "{} ",
-                        console::pad_str(cell, self.widths[i], console::Alignment::Left, None)
-                    )
-                    .ok();
+                    write!(output, "{} ", self.pad(i, &cell)).ok();
                 }
             }
-            println!("{}", console::truncate_str(&output, width - 1, "…"));
+            lines.push(console::truncate_str(&output, width - 1, "…").into_owned());
         }
+        lines
     }
 
     pub fn render_tree(self) {
@@ -69,12 +99,93 @@ impl<const W: usize> Table<W> {
                 print!("└── ");
             }
             for (i, cell) in row.iter().enumerate() {
-                print!(
-                    "{} ",
-                    console::pad_str(cell, self.widths[i], console::Alignment::Left, None)
-                );
+                print!("{} ", self.pad(i, &self.truncate(i, cell)));
             }
             println!();
         }
     }
+
+    /// Maximum width of a column, if any.
+    fn limit(&self, column: usize) -> Option<usize> {
+        match (self.max_widths[column], self.opts.max_width) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        }
+    }
+
+    /// Width of a column, taking its maximum width into account.
+    fn width(&self, column: usize) -> usize {
+        self.limit(column)
+            .map_or(self.widths[column], |max| self.widths[column].min(max))
+    }
+
+    fn truncate<'a>(&self, column: usize, cell: &'a str) -> Cow<'a, str> {
+        match self.limit(column) {
+            Some(max) => console::truncate_str(cell, max, "…"),
+            None => Cow::Borrowed(cell),
+        }
+    }
+
+    fn pad<'a>(&self, column: usize, cell: &'a str) -> Cow<'a, str> {
+        console::pad_str(cell, self.width(column), self.alignments[column], None)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_alignment() {
+        let mut table = Table::<3>::default();
+        table.align(1, Alignment::Right).align(2, Alignment::Right);
+        table.push(["a".to_owned(), "1".to_owned(), "x".to_owned()]);
+        table.push(["bb".to_owned(), "100".to_owned(), "xyz".to_owned()]);
+
+        assert_eq!(table.lines(80), vec!["a    1   x", "bb 100 xyz"]);
+    }
+
+    #[test]
+    fn test_last_column_not_padded() {
+        let mut table = Table::<2>::default();
+        table.push(["a".to_owned(), "x".to_owned()]);
+        table.push(["bb".to_owned(), "xyz".to_owned()]);
+
+        assert_eq!(table.lines(80), vec!["a  x", "bb xyz"]);
+    }
+
+    #[test]
+    fn test_truncation() {
+        let mut table = Table::<2>::default();
+        table.max_width(0, 4);
+        table.push(["abcdefgh".to_owned(), "x".to_owned()]);
+        table.push(["ab".to_owned(), "y".to_owned()]);
+
+        assert_eq!(table.lines(80), vec!["abc… x", "ab   y"]);
+
+        let mut table = Table::<2>::new(TableOptions {
+            max_width: Some(3),
+            ..TableOptions::default()
+        });
+        table.push(["abcdefgh".to_owned(), "xyz".to_owned()]);
+
+        assert_eq!(table.lines(80), vec!["ab… xyz"]);
+    }
+
+    #[test]
+    fn test_terminal_width() {
+        let mut table = Table::<2>::default();
+        table.push(["abc".to_owned(), "defghijkl".to_owned()]);
+
+        assert_eq!(table.lines(8), vec!["abc de…"]);
+    }
+
+    #[test]
+    fn test_empty_column() {
+        let mut table = Table::<3>::default();
+        table.push(["a".to_owned(), String::new(), "b".to_owned()]);
+        table.push(["cc".to_owned(), String::new(), "d".to_owned()]);
+
+        assert_eq!(table.lines(80), vec!["a  b", "cc d"]);
+    }
 }
@@ -1,8 +1,6 @@
-use std::collections::HashMap;
 use std::convert::TryInto;
 
 use anyhow::anyhow;
-use anyhow::Context as _;
 
 use librad::crypto::BoxedSigner;
 use librad::git::storage::{ReadOnly, Storage};
@@ -10,9 +8,9 @@ use librad::git::tracking;
 use librad::profile::Profile;
 use librad::PeerId;
 
-use radicle_common::args::Help;
+use radicle_common::args::{self, Help};
 use radicle_common::nonempty::NonEmpty;
-use radicle_common::project::PeerInfo;
+use radicle_common::project::{Branch, Peer};
 use radicle_common::tokio;
 use radicle_common::Url;
 use radicle_common::{git, keys, project, seed, sync, Urn};
@@ -21,20 +19,6 @@ use radicle_terminal as term;
 mod options;
 pub use options::Options;
 
-#[derive(Debug)]
-pub struct Peer {
-    id: PeerId,
-    meta: Option<PeerInfo>,
-    branches: Vec<Branch>,
-}
-
-#[derive(Debug)]
-pub struct Branch {
-    name: String,
-    head: git::Oid,
-    message: String,
-}
-
 // TODO: Add `--upstream-prefix` to specify a branch prefix, eg. `remotes/`.
 pub const HELP: Help = Help {
     name: "track",
@@ -46,6 +30,7 @@ Usage
     rad track           [--local | --remote]
     rad track           [--seed <url>]
     rad track <peer-id> [--seed <url>] [--no-sync] [--no-upstream] [--no-fetch]
+    rad track --show-seeds
 
     If a peer id is supplied, track this peer in the context of the current project. By default,
     a remote is created in the repository and an upstream tracking branch is setup. If a seed
@@ -53,6 +38,8 @@ Usage
 
     If no peer id is supplied, show the local or remote tracking graph of the current project.
 
+    With `--show-seeds`, show the seeds associated with peers in the local git configuration.
+
 Options
 
     --local                Show the local project tracking graph
@@ -61,6 +48,7 @@ Options
     --no-upstream          Don't setup a tracking branch for the remote
     --no-sync              Don't sync the peer's refs
     --no-fetch             Don't fetch the peer's refs into the working copy
+    --show-seeds           Show the seed associated with each peer
     --verbose, -v          Verbose output
     --help                 Print help
 "#,
@@ -71,17 +59,18 @@ pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
     let signer = term::signer(&profile)?;
     let storage = keys::storage(&profile, signer.clone())?;
 
-    let (urn, repo) =
-        project::cwd().context("this command must be run in the context of a project")?;
+    let (urn, repo) = project::cwd().map_err(args::Error::from)?;
     let proj = project::get(&storage, &urn)?
         .ok_or_else(|| anyhow!("project {} not found in local storage", &urn))?;
 
-    if let Some(peer) = options.peer {
+    if options.show_seeds {
+        show_seeds(&proj, &repo, storage.read_only())?;
+    } else if let Some(peer) = options.peer {
         // Track peer.
         track(peer, proj, repo, storage, profile, signer, options)?;
     } else {
         // Show tracking graph.
-        show(proj, repo, storage.read_only(), options)?;
+        show(proj, repo, &storage, options)?;
     }
 
     Ok(())
@@ -139,6 +128,7 @@ pub fn track(
             project.urn.clone(),
             seeds,
             sync::Mode::Fetch,
+            sync::DEFAULT_TIMEOUT,
             &profile,
             signer.clone(),
             &rt,
@@ -191,7 +181,7 @@ pub fn track(
 pub fn show(
     project: project::Metadata,
     repo: git::Repository,
-    storage: &ReadOnly,
+    storage: &Storage,
     options: Options,
 ) -> anyhow::Result<()> {
     let peers = if options.local {
@@ -201,7 +191,7 @@ pub fn show(
             &project.urn,
             term::format::dim("(local)")
         );
-        show_local(&project, storage)?
+        show_local(&project, storage.read_only())?
     } else {
         let seed = if let Some(seed) = &options.seed {
             seed.clone()
@@ -232,6 +222,20 @@ pub fn show(
         return Ok(());
     }
 
+    // Local branches are compared against the default branch head agreed on by the delegates.
+    // If it can't be computed, eg. because no delegate tips are available locally, the status
+    // is omitted.
+    let canonical = if options.local {
+        let monorepo = git::Repository::open_bare(storage.as_ref().path())?;
+        project
+            .default_branch_head(storage)
+            .ok()
+            .flatten()
+            .map(|head| (monorepo, head))
+    } else {
+        None
+    };
+
     // TODO: Deterministic ordering of peers when printed.
     for (i, peer) in peers.iter().enumerate() {
         let you = &peer.id == storage.peer_id();
@@ -269,10 +273,18 @@ pub fn show(
                 format!("   {}", prefix)
             };
 
+            // The status column is empty, and thus not shown, without a canonical head.
+            let status = if let Some((monorepo, head)) = &canonical {
+                term::patch::pretty_sync_status(monorepo, branch.head, *head)?
+            } else {
+                String::new()
+            };
+
             table.push([
                 prefix,
                 term::format::tertiary(&branch.name),
                 term::format::secondary(branch.head.to_string()),
+                status,
                 term::format::italic(&branch.message),
             ]);
         }
@@ -285,6 +297,40 @@ pub fn show(
     Ok(())
 }
 
+/// Show the seeds associated with peers in the local git configuration.
+pub fn show_seeds(
+    project: &project::Metadata,
+    repo: &git::Repository,
+    storage: &ReadOnly,
+) -> anyhow::Result<()> {
+    let seeds = seed::get_peer_seeds(repo)?;
+    if seeds.is_empty() {
+        term::info!(
+            "{}",
+            term::format::dim("No peer seeds configured for project")
+        );
+        return Ok(());
+    }
+
+    let mut table = term::Table::default();
+    for (peer, seed) in seeds {
+        let name = project::person(storage, project.urn.clone(), &peer)
+            .ok()
+            .flatten()
+            .map(|p| p.subject().name.to_string())
+            .unwrap_or_default();
+
+        table.push([
+            term::format::bold(peer),
+            term::format::tertiary(name),
+            term::format::secondary(seed),
+        ]);
+    }
+    table.render();
+
+    Ok(())
+}
+
 /// Return the Peer's branches with their Oid's by parsing references in the storage's git
 /// repository.
 fn get_peer_branches(
@@ -345,46 +391,5 @@ pub fn show_remote(
     repo: &git::Repository,
     seed: &Url,
 ) -> anyhow::Result<Vec<Peer>> {
-    let urn = &project.urn;
-    let remotes = project::list_seed_heads(repo, seed, urn)?;
-    let mut commits: HashMap<_, String> = HashMap::new();
-
-    let remote_metadata = if let Ok(meta) = seed::get_remotes(seed.clone(), urn) {
-        meta.into_iter().map(|r| (r.id, r)).collect()
-    } else {
-        HashMap::new() // Support old seeds that don't have metadata.
-    };
-
-    if remotes.is_empty() {
-        return Ok(Vec::new());
-    }
-
-    let mut peers = Vec::new();
-
-    for (id, branches) in remotes {
-        let mut peer = Peer {
-            id,
-            meta: remote_metadata.get(&id).cloned(),
-            branches: Vec::new(),
-        };
-
-        for (branch, oid) in branches {
-            let message: String = if let Some(m) = commits.get(&oid) {
-                m.to_owned()
-            } else if let Ok(commit) = seed::get_commit(seed.clone(), urn, &oid) {
-                commits.insert(oid, commit.header.summary.clone());
-                commit.header.summary
-            } else {
-                String::new()
-            };
-
-            peer.branches.push(Branch {
-                name: branch,
-                head: oid,
-                message,
-            });
-        }
-        peers.push(peer);
-    }
-    Ok(peers)
+    project::list_seed_peers(&project.urn, repo, seed)
 }
@@ -7,7 +7,7 @@ use anyhow::Context as _;
 use librad::PeerId;
 
 use radicle_common::args::{Args, Error};
-use radicle_common::seed;
+use radicle_common::{seed, sync};
 
 /// Tool options.
 #[derive(Debug)]
@@ -19,6 +19,7 @@ pub struct Options {
     pub local: bool,
     pub seed: Option<seed::Address>,
     pub verbose: bool,
+    pub show_seeds: bool,
 }
 
 impl Args for Options {
@@ -33,6 +34,7 @@ impl Args for Options {
         let mut fetch = true;
         let mut verbose = false;
         let mut seed = None;
+        let mut show_seeds = false;
 
         while let Some(arg) = parser.next()? {
             match arg {
@@ -57,6 +59,7 @@ impl Args for Options {
                 Long("no-upstream") => upstream = false,
                 Long("no-sync") => sync = false,
                 Long("no-fetch") => fetch = false,
+                Long("show-seeds") => show_seeds = true,
 
                 Long("verbose") | Short('v') => verbose = true,
                 Long("help") => {
@@ -88,12 +91,13 @@ impl Args for Options {
         Ok((
             Options {
                 peer,
-                sync,
+                sync: sync && !sync::is_offline(),
                 fetch,
                 upstream,
                 local,
                 seed,
                 verbose,
+                show_seeds,
             },
             vec![],
         ))
@@ -2,7 +2,6 @@ use std::ffi::OsString;
 use std::str::FromStr;
 
 use anyhow::anyhow;
-use anyhow::Context as _;
 
 use librad::git::storage::Storage;
 use librad::git::tracking::git::tracking;
@@ -72,8 +71,7 @@ impl Args for Options {
 }
 
 pub fn run(options: Options, ctx: impl term::Context) -> anyhow::Result<()> {
-    let (urn, repo) =
-        project::cwd().context("this command must be run in the context of a project")?;
+    let (urn, repo) = project::cwd().map_err(Error::from)?;
     let profile = ctx.profile()?;
 
     execute(&urn, Some(&repo), options, &profile)